@@ -1,19 +1,18 @@
-use std::{fmt::Write, sync::Arc};
+use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use clomonitor_core::score::Score;
+use clomonitor_core::{linter::Report, report_md::RepositoryReportMDTemplate, score::Score};
 use deadpool_postgres::Pool;
 #[cfg(test)]
 use mockall::automock;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use time::Date;
 use tokio_postgres::types::Json;
+use tracing::warn;
 
-use crate::{
-    handlers::RepositoryReportMDTemplate,
-    views::{Day, ProjectId, Total},
-};
+use crate::views::{Day, ProjectId, Total};
 
 // Lock key used when updating the projects views in the database.
 const LOCK_KEY_UPDATE_PROJECTS_VIEWS: i64 = 1;
@@ -54,7 +53,7 @@ pub(crate) trait DB {
     ) -> Result<Option<JsonString>>;
 
     /// Get all repositories including checks details.
-    async fn repositories_with_checks(&self) -> Result<String>;
+    async fn repositories_with_checks(&self) -> Result<Vec<RepositoryChecks>>;
 
     /// Get some repository info to prepare report in markdown format.
     async fn repository_report_md(
@@ -172,16 +171,14 @@ impl DB for PgDB {
         Ok(snapshot)
     }
 
-    async fn repositories_with_checks(&self) -> Result<String> {
+    async fn repositories_with_checks(&self) -> Result<Vec<RepositoryChecks>> {
         let db = self.pool.get().await?;
         let repos = db
             .query("select get_repositories_with_checks()", &[])
             .await?
             .iter()
-            .fold(String::new(), |mut output, row| {
-                let _ = writeln!(output, "{}", row.get::<_, String>(0));
-                output
-            });
+            .map(|row| Ok(serde_json::from_str(row.get(0))?))
+            .collect::<Result<_>>()?;
         Ok(repos)
     }
 
@@ -259,6 +256,36 @@ impl DB for PgDB {
     }
 }
 
+/// Repository details, including its report, used when exporting the checks
+/// of all repositories.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(crate) struct RepositoryChecks {
+    pub foundation: String,
+    pub project: String,
+    pub repository_url: String,
+    pub check_sets: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_report_lenient")]
+    pub report: Option<Report>,
+}
+
+/// Deserialize the report provided, ignoring it when it is not valid so that
+/// a single invalid report does not prevent the rest from being exported.
+fn deserialize_report_lenient<'de, D>(deserializer: D) -> Result<Option<Report>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(data) = Option::<Value>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    match Report::try_from(data) {
+        Ok(report) => Ok(Some(report)),
+        Err(err) => {
+            warn!(?err, "error deserializing report, ignoring it");
+            Ok(None)
+        }
+    }
+}
+
 /// Query input used when searching for projects.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub(crate) struct SearchProjectsInput {
@@ -275,3 +302,47 @@ pub(crate) struct SearchProjectsInput {
     pub passing_check: Option<Vec<String>>,
     pub not_passing_check: Option<Vec<String>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn repository_checks_invalid_report_is_ignored() {
+        let repo: RepositoryChecks = serde_json::from_value(json!({
+            "foundation": "cncf",
+            "project": "artifact-hub",
+            "repository_url": "https://repo1.url",
+            "check_sets": ["code"],
+            "report": {"schema_version": "invalid"},
+        }))
+        .unwrap();
+
+        assert_eq!(
+            repo,
+            RepositoryChecks {
+                foundation: "cncf".to_string(),
+                project: "artifact-hub".to_string(),
+                repository_url: "https://repo1.url".to_string(),
+                check_sets: Some(vec!["code".to_string()]),
+                report: None,
+            }
+        );
+    }
+
+    #[test]
+    fn repository_checks_valid_report_is_loaded() {
+        let repo: RepositoryChecks = serde_json::from_value(json!({
+            "foundation": "cncf",
+            "project": "artifact-hub",
+            "repository_url": "https://repo1.url",
+            "check_sets": null,
+            "report": serde_json::to_value(Report::default()).unwrap(),
+        }))
+        .unwrap();
+
+        assert_eq!(repo.report, Some(Report::default()));
+    }
+}
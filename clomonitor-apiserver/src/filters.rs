@@ -9,14 +9,7 @@
 )]
 
 /// Template filter that returns the rating letter corresponding to the score
-/// value provided.
-#[askama::filter_fn]
-pub(crate) fn rating(score: &f64, _: &dyn askama::Values) -> askama::Result<char> {
-    Ok(clomonitor_core::score::rating(*score))
-}
-
-/// Template filter that returns the rating letter corresponding to the score
-/// value provided.
+/// value provided (using the default rating thresholds).
 #[askama::filter_fn]
 pub(crate) fn rating_opt(score: &Option<f64>, _: &dyn askama::Values) -> askama::Result<String> {
     Ok(match score {
@@ -69,12 +62,6 @@ mod tests {
 
     use super::*;
 
-    #[test]
-    fn rating_works() {
-        assert_eq!(rating::default().execute(&80.0, NO_VALUES).unwrap(), 'a');
-        assert_eq!(rating::default().execute(&74.0, NO_VALUES).unwrap(), 'b');
-    }
-
     #[test]
     fn rating_opt_some() {
         assert_eq!(
@@ -16,13 +16,12 @@ use axum::{
     response::{self, IntoResponse},
 };
 use clomonitor_core::{
-    linter::{CheckSet, Report},
-    score::Score,
+    linter::{CheckOutput, Report, upgrade_report_data},
+    score::{self, Score},
 };
 use config::Config;
 use mime::{APPLICATION_JSON, CSV, HTML, PNG};
-use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{Value, json};
 use tera::{Context, Tera};
 use time::{
     Date,
@@ -32,7 +31,7 @@ use tracing::error;
 use uuid::Uuid;
 
 use crate::{
-    db::{DynDB, SearchProjectsInput},
+    db::{DynDB, RepositoryChecks, SearchProjectsInput},
     views::DynVT,
 };
 
@@ -209,9 +208,11 @@ pub(crate) async fn project_snapshot(
         .await
         .map_err(internal_error)?;
 
-    // Return project snapshot data if found
+    // Return project snapshot data if found, upgrading the reports included
+    // in it to the current schema version
     match project {
         Some(project) => {
+            let project = upgrade_snapshot_reports(&project).map_err(internal_error)?;
             let headers = [
                 (CACHE_CONTROL, format!("max-age={}", 24 * 60 * 60)),
                 (CONTENT_TYPE, APPLICATION_JSON.to_string()),
@@ -227,13 +228,25 @@ pub(crate) async fn project_snapshot(
 #[template(path = "report-summary.svg")]
 pub(crate) struct ReportSummaryTemplate {
     pub score: Score,
+    pub rating: String,
     pub theme: String,
 }
 
 impl ReportSummaryTemplate {
-    fn new(score: Score, theme: Option<String>) -> Self {
+    /// Create a new report summary template. The project's rating persisted
+    /// by the tracker (calculated using the foundation's scoring model) is
+    /// used when available, as it may differ from the one corresponding to
+    /// the global score using the default thresholds. Scoring models only
+    /// rate the global score, so sections are always rated using the default
+    /// thresholds.
+    fn new(score: Score, rating: Option<String>, theme: Option<String>) -> Self {
+        let rating = rating.unwrap_or_else(|| score.rating().to_string());
         let theme = theme.unwrap_or_else(|| "light".to_string());
-        Self { score, theme }
+        Self {
+            score,
+            rating,
+            theme,
+        }
     }
 }
 
@@ -247,12 +260,18 @@ pub(crate) async fn report_summary_png(
         .project_score(&foundation, &project)
         .await
         .map_err(internal_error)?;
-    if score.is_none() {
+    let Some(score) = score else {
         return Err(StatusCode::NOT_FOUND);
-    }
+    };
+
+    // Get project rating from database
+    let rating = db
+        .project_rating(&foundation, &project)
+        .await
+        .map_err(internal_error)?;
 
     // Render report summary SVG
-    let svg = ReportSummaryTemplate::new(score.expect("checked if is some above"), None)
+    let svg = ReportSummaryTemplate::new(score, rating, None)
         .render()
         .map_err(internal_error)?;
 
@@ -293,23 +312,28 @@ pub(crate) async fn report_summary_svg(
         .await
         .map_err(internal_error)?;
 
-    // Render report summary SVG and return it if the score was found
-    match score {
-        Some(score) => {
-            let headers = [
-                (CACHE_CONTROL, format!("max-age={DEFAULT_API_MAX_AGE}")),
-                (CONTENT_TYPE, "image/svg+xml".to_string()),
-            ];
-            let theme = params.get("theme").cloned();
-            Ok((
-                headers,
-                ReportSummaryTemplate::new(score, theme)
-                    .render()
-                    .map_err(internal_error)?,
-            ))
-        }
-        None => Err(StatusCode::NOT_FOUND),
-    }
+    let Some(score) = score else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+
+    // Get project rating from database
+    let rating = db
+        .project_rating(&foundation, &project)
+        .await
+        .map_err(internal_error)?;
+
+    // Render report summary SVG and return it
+    let headers = [
+        (CACHE_CONTROL, format!("max-age={DEFAULT_API_MAX_AGE}")),
+        (CONTENT_TYPE, "image/svg+xml".to_string()),
+    ];
+    let theme = params.get("theme").cloned();
+    Ok((
+        headers,
+        ReportSummaryTemplate::new(score, rating, theme)
+            .render()
+            .map_err(internal_error)?,
+    ))
 }
 
 /// Handler that returns all repositories with checks details in CSV format.
@@ -323,19 +347,144 @@ pub(crate) async fn repositories_checks(State(db): State<DynDB>) -> impl IntoRes
     Response::builder()
         .header(CACHE_CONTROL, "max-age=3600")
         .header(CONTENT_TYPE, CSV.as_ref())
-        .body(Body::from(repos))
+        .body(Body::from(repositories_checks_csv(&repos)))
         .map_err(internal_error)
 }
 
-/// Template for the repository report in markdown format.
-#[derive(Debug, Clone, Template, Serialize, Deserialize)]
-#[template(path = "repository-report.md")]
-pub(crate) struct RepositoryReportMDTemplate {
-    pub name: String,
-    pub url: String,
-    pub check_sets: Vec<CheckSet>,
-    pub score: Option<Score>,
-    pub report: Option<Report>,
+/// Build a CSV document with the checks of the repositories provided. A
+/// column is added for each of the checks available in the report, in the
+/// order they appear in it, containing whether the check passed or not (t or
+/// f, empty when the check was not run). Some checks export their value
+/// instead.
+fn repositories_checks_csv(repos: &[RepositoryChecks]) -> String {
+    let checks = Report::default().checks();
+
+    // Header
+    let mut csv = ["Foundation", "Project", "Repository URL", "Check Sets"]
+        .into_iter()
+        .chain(
+            checks
+                .iter()
+                .map(|(check_id, check)| check_csv_column(check_id, check.name)),
+        )
+        .map(csv_field)
+        .collect::<Vec<_>>()
+        .join(",");
+    csv.push('\n');
+
+    // Repositories
+    for repo in repos {
+        let repo_checks = repo.report.as_ref().map(Report::checks);
+        let check_sets = format!(
+            "{{{}}}",
+            repo.check_sets.clone().unwrap_or_default().join(",")
+        );
+        let row: Vec<String> = [
+            repo.foundation.clone(),
+            repo.project.clone(),
+            repo.repository_url.clone(),
+            check_sets,
+        ]
+        .into_iter()
+        .chain(checks.keys().map(|check_id| {
+            let output = repo_checks
+                .as_ref()
+                .and_then(|repo_checks| repo_checks.get(check_id))
+                .and_then(|check| check.output.as_ref());
+            check_csv_value(check_id, output)
+        }))
+        .map(|field| csv_field(&field))
+        .collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// Return the name of the column used for the check provided in the CSV
+/// document. Existing consumers rely on these names, so they must not change.
+fn check_csv_column<'a>(check_id: &str, check_name: &'a str) -> &'a str {
+    match check_id {
+        "adopters" => "Adopters",
+        "broken_links" => "Broken Links",
+        "changelog" => "Changelog",
+        "code_of_conduct" => "Code of Conduct",
+        "contributing" => "Contributing",
+        "governance" => "Governance",
+        "maintainers" => "Maintainers",
+        "readme" => "Readme",
+        "roadmap" => "Roadmap",
+        "summary_table" => "Summary Table",
+        "website" => "Website",
+        "website_hygiene" => "Website Hygiene",
+        "license_approved" => "License Approved",
+        "license_scanning" => "License Scanning",
+        "license_spdx_id" => "License SPDX ID",
+        "analytics" => "Analytics",
+        "artifacthub_badge" => "ArtifactHub Badge",
+        "cla" => "CLA",
+        "community_chat" => "Community Chat",
+        "community_meeting" => "Community Meeting",
+        "contributor_diversity" => "Contributor Diversity",
+        "dco" => "DCO",
+        "github_discussions" => "GitHub discussions",
+        "issue_pr_templates" => "Issue and PR Templates",
+        "openssf_badge" => "OpenSSF best practices badge",
+        "openssf_scorecard_badge" => "OpenSSF Scorecard badge",
+        "recent_release" => "Recent Release",
+        "responsiveness" => "Responsiveness",
+        "slack_presence" => "Slack Presence",
+        "binary_artifacts" => "Binary Artifacts",
+        "code_review" => "Code Review",
+        "dangerous_workflow" => "Dangerous Workflow",
+        "dependencies_policy" => "Dependencies Policy",
+        "dependency_update_tool" => "Dependency Update Tool",
+        "maintained" => "Maintained",
+        "sbom" => "SBOM",
+        "security_insights" => "Security Insights",
+        "security_policy" => "Security Policy",
+        "signed_releases" => "Signed Releases",
+        "token_permissions" => "Token Permissions",
+        "trademark_disclaimer" => "Trademark Disclaimer",
+        _ => check_name,
+    }
+}
+
+/// Return the value exported in the CSV document for the check output
+/// provided.
+fn check_csv_value(check_id: &str, output: Option<&CheckOutput<Value>>) -> String {
+    let value = output.and_then(|output| output.value.as_ref());
+    match check_id {
+        "analytics" => value
+            .and_then(Value::as_array)
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .unwrap_or_default(),
+        "license_spdx_id" => value
+            .and_then(Value::as_str)
+            .unwrap_or("Not detected")
+            .to_string(),
+        _ => match output {
+            Some(output) if output.passed => "t".to_string(),
+            Some(_) => "f".to_string(),
+            None => String::new(),
+        },
+    }
+}
+
+/// Return the CSV field provided, quoted when needed.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
 }
 
 /// Handler that returns the repository's report in markdown format.
@@ -359,6 +508,39 @@ pub(crate) async fn repository_report_md(
     }
 }
 
+/// Handler that returns the explanation of the repository's score, including
+/// the contribution of each of the checks and the biggest wins (checks that
+/// would increase the score the most if they passed).
+pub(crate) async fn repository_score_explanation(
+    State(db): State<DynDB>,
+    Path((foundation, project, repository)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    // Get repository report from database
+    let report = db
+        .repository_report_md(&foundation, &project, &repository)
+        .await
+        .map_err(internal_error)?
+        .and_then(|report_md| report_md.report);
+
+    // Explain repository score and return it as json if found
+    match report {
+        Some(report) => {
+            let explanation = score::explain(&report);
+            let body = json!({
+                "score": explanation.score,
+                "checks": explanation.checks,
+                "biggest_wins": explanation.biggest_wins(),
+            });
+            let headers = [
+                (CACHE_CONTROL, format!("max-age={DEFAULT_API_MAX_AGE}")),
+                (CONTENT_TYPE, APPLICATION_JSON.to_string()),
+            ];
+            Ok((headers, body.to_string()))
+        }
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
 /// Handler that allows searching for projects.
 pub(crate) async fn search_projects(
     State(db): State<DynDB>,
@@ -437,6 +619,26 @@ pub(crate) async fn track_view(
     }
 }
 
+/// Upgrade the reports of the repositories included in the project snapshot
+/// provided to the current report schema version.
+fn upgrade_snapshot_reports(snapshot: &str) -> Result<String, Error> {
+    let mut snapshot: Value = serde_json::from_str(snapshot)?;
+    if let Some(repositories) = snapshot
+        .get_mut("repositories")
+        .and_then(Value::as_array_mut)
+    {
+        for repository in repositories {
+            if let Some(data) = repository
+                .pointer_mut("/report/data")
+                .filter(|data| !data.is_null())
+            {
+                upgrade_report_data(data)?;
+            }
+        }
+    }
+    Ok(serde_json::to_string(&snapshot)?)
+}
+
 /// Helper for mapping any error into a `500 Internal Server Error` response.
 #[allow(clippy::needless_pass_by_value)]
 fn internal_error<E>(err: E) -> StatusCode
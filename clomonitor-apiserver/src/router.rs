@@ -69,6 +69,10 @@ pub(crate) fn setup(cfg: &Arc<Config>, db: DynDB, vt: DynVT) -> Result<Router> {
             "/projects/{foundation}/{project}/{repository}/report.md",
             get(repository_report_md),
         )
+        .route(
+            "/projects/{foundation}/{project}/{repository}/score-explanation",
+            get(repository_score_explanation),
+        )
         .route(
             "/projects/{foundation}/{project}/snapshots/{date}",
             get(project_snapshot),
@@ -209,7 +213,7 @@ mod tests {
             header::{AUTHORIZATION, CACHE_CONTROL, CONTENT_TYPE},
         },
     };
-    use clomonitor_core::{linter::*, score::Score};
+    use clomonitor_core::{linter::*, report_md::RepositoryReportMDTemplate, score::Score};
     use mime::{APPLICATION_JSON, CSV, HTML};
     use mockall::predicate::*;
     use serde_json::json;
@@ -220,7 +224,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        db::{MockDB, SearchProjectsInput},
+        db::{MockDB, RepositoryChecks, SearchProjectsInput},
         views::MockViewsTracker,
     };
 
@@ -561,7 +565,85 @@ mod tests {
         assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
         assert_eq!(
             to_bytes(response.into_body(), usize::MAX).await.unwrap(),
-            r#"{"snapshot": "data"}"#.to_string(),
+            r#"{"snapshot":"data"}"#.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn project_snapshot_reports_are_upgraded() {
+        let mut db = MockDB::new();
+        db.expect_project_snapshot()
+            .with(
+                eq(FOUNDATION),
+                eq(PROJECT),
+                eq(Date::parse(DATE, &SNAPSHOT_DATE_FORMAT).unwrap()),
+            )
+            .times(1)
+            .returning(|_, _, _| {
+                Box::pin(future::ready(Ok(Some(
+                    json!({
+                        "name": PROJECT,
+                        "repositories": [
+                            {
+                                "name": "repo1",
+                                "report": {
+                                    "data": {
+                                        "documentation": {
+                                            "annual_review": {"passed": false},
+                                            "readme": {"passed": true}
+                                        }
+                                    }
+                                }
+                            },
+                            {
+                                "name": "repo2",
+                                "report": {"errors": "error"}
+                            }
+                        ]
+                    })
+                    .to_string(),
+                ))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/snapshots/{DATE}"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let snapshot: serde_json::Value =
+            serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap())
+                .unwrap();
+        assert_eq!(
+            snapshot,
+            json!({
+                "name": PROJECT,
+                "repositories": [
+                    {
+                        "name": "repo1",
+                        "report": {
+                            "data": {
+                                "schema_version": REPORT_SCHEMA_VERSION,
+                                "documentation": {
+                                    "readme": {"passed": true}
+                                }
+                            }
+                        }
+                    },
+                    {
+                        "name": "repo2",
+                        "report": {"errors": "error"}
+                    }
+                ]
+            })
         );
     }
 
@@ -632,6 +714,10 @@ mod tests {
                 };
                 Box::pin(future::ready(Ok(Some(score))))
             });
+        db.expect_project_rating()
+            .with(eq(FOUNDATION), eq(PROJECT))
+            .times(1)
+            .returning(|_: &str, _: &str| Box::pin(future::ready(Ok(Some("b".to_string())))));
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
@@ -685,9 +771,45 @@ mod tests {
     #[tokio::test]
     async fn repositories_checks() {
         let mut db = MockDB::new();
-        db.expect_repositories_with_checks()
-            .times(1)
-            .returning(|| Box::pin(future::ready(Ok("CSV data".to_string()))));
+        db.expect_repositories_with_checks().times(1).returning(|| {
+            let repos = vec![
+                RepositoryChecks {
+                    foundation: FOUNDATION.to_string(),
+                    project: PROJECT.to_string(),
+                    repository_url: "https://repo1.url".to_string(),
+                    check_sets: Some(vec!["code".to_string(), "community".to_string()]),
+                    report: Some(Report {
+                        documentation: Documentation {
+                            adopters: Some(CheckOutput::passed()),
+                            changelog: Some(CheckOutput::not_passed()),
+                            ..Documentation::default()
+                        },
+                        license: License {
+                            license_spdx_id: Some(
+                                CheckOutput::passed().value(Some("Apache-2.0".to_string())),
+                            ),
+                            ..License::default()
+                        },
+                        best_practices: BestPractices {
+                            analytics: Some(
+                                CheckOutput::passed()
+                                    .value(Some(vec!["GA4".to_string(), "HubSpot".to_string()])),
+                            ),
+                            ..BestPractices::default()
+                        },
+                        ..Report::default()
+                    }),
+                },
+                RepositoryChecks {
+                    foundation: FOUNDATION.to_string(),
+                    project: PROJECT.to_string(),
+                    repository_url: "https://repo2.url".to_string(),
+                    check_sets: Some(vec!["docs".to_string()]),
+                    report: None,
+                },
+            ];
+            Box::pin(future::ready(Ok(repos)))
+        });
 
         let response = setup_test_router(db, MockViewsTracker::new())
             .oneshot(
@@ -703,10 +825,11 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
         assert_eq!(response.headers()[CACHE_CONTROL], "max-age=3600");
         assert_eq!(response.headers()[CONTENT_TYPE], CSV.as_ref());
-        assert_eq!(
-            to_bytes(response.into_body(), usize::MAX).await.unwrap(),
-            "CSV data".to_string(),
-        );
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let golden_path = "src/testdata/repositories-checks.golden.csv";
+        // fs::write(golden_path, &body).unwrap(); // Uncomment to update golden file
+        let golden = fs::read(golden_path).unwrap();
+        assert_eq!(body, golden);
     }
 
     #[tokio::test]
@@ -736,8 +859,10 @@ mod tests {
                         legal_weight: Some(1),
                     }),
                     report: Some(Report {
+                        schema_version: REPORT_SCHEMA_VERSION,
                         documentation: Documentation {
                             adopters: Some(CheckOutput::passed()),
+                            broken_links: Some(CheckOutput::passed()),
                             code_of_conduct: Some(CheckOutput::passed()),
                             contributing: Some(CheckOutput::passed()),
                             changelog: Some(CheckOutput::passed()),
@@ -747,6 +872,7 @@ mod tests {
                             roadmap: Some(CheckOutput::passed()),
                             summary_table: Some(CheckOutput::passed()),
                             website: Some(CheckOutput::passed()),
+                            website_hygiene: Some(CheckOutput::passed()),
                         },
                         license: License {
                             license_approved: Some(CheckOutput::passed()),
@@ -762,12 +888,29 @@ mod tests {
                             analytics: Some(CheckOutput::passed()),
                             artifacthub_badge: Some(CheckOutput::exempt()),
                             cla: Some(CheckOutput::passed()),
+                            community_chat: Some(CheckOutput::passed()),
                             community_meeting: Some(CheckOutput::passed()),
+                            contributor_diversity: Some(CheckOutput::passed()),
                             dco: Some(CheckOutput::passed()),
                             github_discussions: Some(CheckOutput::passed()),
+                            issue_pr_templates: Some(CheckOutput::passed()),
                             openssf_badge: Some(CheckOutput::passed()),
                             openssf_scorecard_badge: Some(CheckOutput::passed()),
                             recent_release: Some(CheckOutput::passed()),
+                            responsiveness: Some(CheckOutput::passed().value(Some(
+                                Responsiveness {
+                                    issues: ResponsivenessStats {
+                                        analyzed: 30,
+                                        first_response_median_hours: Some(5),
+                                        close_median_hours: Some(48),
+                                    },
+                                    pull_requests: ResponsivenessStats {
+                                        analyzed: 12,
+                                        first_response_median_hours: Some(3),
+                                        close_median_hours: None,
+                                    },
+                                },
+                            ))),
                             slack_presence: Some(CheckOutput::passed()),
                         },
                         security: Security {
@@ -840,6 +983,106 @@ mod tests {
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
+    #[tokio::test]
+    async fn repository_score_explanation_found() {
+        let mut db = MockDB::new();
+        db.expect_repository_report_md()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+            .times(1)
+            .returning(|_: &str, _: &str, _: &str| {
+                let report_md = RepositoryReportMDTemplate {
+                    name: "artifact-hub".to_string(),
+                    url: "https://github.com/artifacthub/hub".to_string(),
+                    check_sets: vec![CheckSet::Code],
+                    score: None,
+                    report: Some(Report {
+                        documentation: Documentation {
+                            readme: Some(CheckOutput::not_passed()),
+                            ..Documentation::default()
+                        },
+                        legal: Legal {
+                            trademark_disclaimer: Some(CheckOutput::passed()),
+                        },
+                        ..Report::default()
+                    }),
+                };
+                Box::pin(future::ready(Ok(Some(report_md))))
+            });
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/score-explanation"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CACHE_CONTROL],
+            format!("max-age={DEFAULT_API_MAX_AGE}")
+        );
+        assert_eq!(response.headers()[CONTENT_TYPE], APPLICATION_JSON.as_ref());
+        let body: serde_json::Value =
+            serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap())
+                .unwrap();
+        let readme = json!({
+            "check_id": "readme",
+            "section": "documentation",
+            "weight": 10,
+            "section_share": 100.0,
+            "global_share": 10.0 / 15.0 * 100.0,
+            "passed": false,
+            "potential_gain": 10.0 / 15.0 * 100.0,
+        });
+        assert_eq!(body["score"]["global_weight"], 15);
+        assert_eq!(
+            body["checks"],
+            json!([
+                readme,
+                {
+                    "check_id": "trademark_disclaimer",
+                    "section": "legal",
+                    "weight": 5,
+                    "section_share": 100.0,
+                    "global_share": 5.0 / 15.0 * 100.0,
+                    "passed": true,
+                    "potential_gain": 0.0,
+                },
+            ])
+        );
+        assert_eq!(body["biggest_wins"], json!([readme]));
+    }
+
+    #[tokio::test]
+    async fn repository_score_explanation_not_found() {
+        let mut db = MockDB::new();
+        db.expect_repository_report_md()
+            .with(eq(FOUNDATION), eq(PROJECT), eq(REPOSITORY))
+            .times(1)
+            .returning(|_: &str, _: &str, _: &str| Box::pin(future::ready(Ok(None))));
+
+        let response = setup_test_router(db, MockViewsTracker::new())
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri(format!(
+                        "/api/projects/{FOUNDATION}/{PROJECT}/{REPOSITORY}/score-explanation"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn search_projects() {
         let mut db = MockDB::new();
@@ -0,0 +1,217 @@
+use anyhow::{Result, format_err};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use time::{Date, format_description::BorrowedFormatItem, macros::format_description};
+
+use crate::linter::{self, CheckSet, Foundation};
+
+/// Value in the repository's exclude list that prevents CLOMonitor from
+/// processing it.
+pub const EXCLUDE_CLOMONITOR: &str = "clomonitor";
+
+/// Format used for the dates in the data file.
+const DATE_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+/// Project defined in a foundation data file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    pub description: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_dark_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub devstats_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maturity: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+
+    pub repositories: Vec<Repository>,
+}
+
+impl Project {
+    /// Remove the repositories that have been excluded from CLOMonitor.
+    pub fn remove_excluded_repositories(&mut self) {
+        self.repositories.retain(|r| !r.is_excluded());
+    }
+
+    /// Return the project's details used by the linter, as part of the
+    /// foundation provided.
+    #[must_use]
+    pub fn linter_project(&self, foundation: Foundation) -> linter::Project {
+        linter::Project {
+            name: self.name.clone(),
+            accepted_at: self
+                .accepted_at
+                .as_ref()
+                .and_then(|value| Date::parse(value, DATE_FORMAT).ok()),
+            maturity: self.maturity.clone(),
+            foundation,
+        }
+    }
+}
+
+/// Project's repository defined in a foundation data file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Repository {
+    pub name: String,
+    pub url: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_sets: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<Vec<String>>,
+}
+
+impl Repository {
+    /// Check if the repository has been excluded from CLOMonitor.
+    #[must_use]
+    pub fn is_excluded(&self) -> bool {
+        self.exclude
+            .as_ref()
+            .is_some_and(|e| e.iter().any(|v| v == EXCLUDE_CLOMONITOR))
+    }
+
+    /// Return the repository's check sets, failing if any of them is unknown.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn linter_check_sets(&self) -> Result<Vec<CheckSet>> {
+        self.check_sets
+            .iter()
+            .flatten()
+            .map(|check_set| {
+                CheckSet::from_str(check_set, false)
+                    .map_err(|_| format_err!("invalid check set: {check_set}"))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    fn repository(check_sets: Option<Vec<&str>>, exclude: Option<Vec<&str>>) -> Repository {
+        Repository {
+            name: "repo".to_string(),
+            url: "https://github.com/org/repo".to_string(),
+            path: None,
+            check_sets: check_sets.map(|c| c.into_iter().map(ToString::to_string).collect()),
+            exclude: exclude.map(|e| e.into_iter().map(ToString::to_string).collect()),
+        }
+    }
+
+    #[test]
+    fn project_remove_excluded_repositories() {
+        let mut project: Project = serde_yaml::from_str(
+            r"
+name: project
+description: Project description
+repositories:
+  - name: repo1
+    url: https://github.com/org/repo1
+    exclude:
+      - clomonitor
+  - name: repo2
+    url: https://github.com/org/repo2
+    exclude:
+      - other
+  - name: repo3
+    url: https://github.com/org/repo3
+",
+        )
+        .unwrap();
+        project.remove_excluded_repositories();
+
+        assert_eq!(
+            project
+                .repositories
+                .iter()
+                .map(|r| r.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["repo2", "repo3"]
+        );
+    }
+
+    #[test]
+    fn project_linter_project() {
+        let project = Project {
+            name: "project".to_string(),
+            display_name: None,
+            description: "Project description".to_string(),
+            category: None,
+            home_url: None,
+            logo_url: None,
+            logo_dark_url: None,
+            devstats_url: None,
+            accepted_at: Some("2021-09-14".to_string()),
+            maturity: Some("sandbox".to_string()),
+            digest: None,
+            repositories: vec![],
+        };
+        let linter_project = project.linter_project(Foundation {
+            foundation_id: "cncf".to_string(),
+            landscape_url: None,
+        });
+
+        assert_eq!(linter_project.name, "project");
+        assert_eq!(linter_project.accepted_at, Some(date!(2021 - 09 - 14)));
+        assert_eq!(linter_project.maturity, Some("sandbox".to_string()));
+        assert_eq!(linter_project.foundation.foundation_id, "cncf");
+    }
+
+    #[test]
+    fn repository_is_excluded() {
+        assert!(repository(None, Some(vec!["clomonitor"])).is_excluded());
+        assert!(!repository(None, Some(vec!["other"])).is_excluded());
+        assert!(!repository(None, None).is_excluded());
+    }
+
+    #[test]
+    fn repository_linter_check_sets() {
+        assert_eq!(
+            repository(Some(vec!["community", "code-lite"]), None)
+                .linter_check_sets()
+                .unwrap(),
+            vec![CheckSet::Community, CheckSet::CodeLite]
+        );
+        assert!(
+            repository(None, None)
+                .linter_check_sets()
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            repository(Some(vec!["unknown"]), None)
+                .linter_check_sets()
+                .unwrap_err()
+                .to_string(),
+            "invalid check set: unknown"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::{path::Path, sync::Arc};
+
+use anyhow::{Result, format_err};
+use async_trait::async_trait;
+#[cfg(feature = "mocks")]
+use mockall::automock;
+use tokio::process::Command;
+use which::which;
+
+/// History period fetched when cloning a repository.
+const CLONE_SHALLOW_SINCE: &str = "1.year.ago";
+
+/// Number of commits fetched when cloning a repository without any commits
+/// in the history period.
+const CLONE_DEPTH: usize = 10;
+
+/// Errors reported by git when no commits were made in the history period
+/// requested (the message depends on the git version used by the server).
+const NO_COMMITS_IN_PERIOD_ERRORS: [&str; 2] = [
+    "no commits selected for shallow requests",
+    "error processing shallow info",
+];
+
+/// Type alias to represent a Git trait object.
+pub type DynGit = Arc<dyn Git + Send + Sync>;
+
+/// Trait that defines some operations a Git implementation must support.
+#[async_trait]
+#[cfg_attr(feature = "mocks", automock)]
+pub trait Git {
+    /// Clone (shallow) the repository in the destination path provided.
+    async fn clone_repository(&self, url: &str, dst: &Path) -> Result<()>;
+
+    /// Get the remote digest of a repository.
+    async fn remote_digest(&self, url: &str) -> Result<String>;
+}
+
+/// Git implementation backed by the git cli tool.
+pub struct GitCLI;
+
+impl GitCLI {
+    /// Create a new GitCLI instance.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn new() -> Result<Self> {
+        if which("git").is_err() {
+            return Err(format_err!("git not found in PATH"));
+        }
+        Ok(Self {})
+    }
+}
+
+#[async_trait]
+impl Git for GitCLI {
+    async fn clone_repository(&self, url: &str, dst: &Path) -> Result<()> {
+        // The last year of history is needed by some checks (i.e. contributor
+        // diversity). The clone fails when no commits were made in that period,
+        // so we fall back to the latest commits in that case.
+        let output = Command::new("git")
+            .arg("clone")
+            .arg(format!("--shallow-since={CLONE_SHALLOW_SINCE}"))
+            .arg(url)
+            .arg(dst)
+            .output()
+            .await?;
+        if output.status.success() {
+            return Ok(());
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !NO_COMMITS_IN_PERIOD_ERRORS
+            .iter()
+            .any(|err| stderr.contains(err))
+        {
+            return Err(format_err!("{stderr}"));
+        }
+        let output = Command::new("git")
+            .arg("clone")
+            .arg(format!("--depth={CLONE_DEPTH}"))
+            .arg(url)
+            .arg(dst)
+            .output()
+            .await?;
+        if !output.status.success() {
+            return Err(format_err!("{}", String::from_utf8_lossy(&output.stderr)));
+        }
+        Ok(())
+    }
+
+    async fn remote_digest(&self, url: &str) -> Result<String> {
+        let output = Command::new("git")
+            .arg("ls-remote")
+            .arg(url)
+            .arg("HEAD")
+            .output()
+            .await?;
+        if !output.status.success() {
+            return Err(format_err!("{}", String::from_utf8_lossy(&output.stderr)));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .split_whitespace()
+            .next()
+            .expect("value present, status checked above")
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_bare_repository(path: &Path) -> String {
+        setup_bare_repository_with_signature(
+            path,
+            &git2::Signature::now("user", "user@project.io").unwrap(),
+        )
+    }
+
+    fn setup_bare_repository_with_signature(path: &Path, signature: &git2::Signature) -> String {
+        let repo = git2::Repository::init_bare(path).unwrap();
+        let tree_id = repo.treebuilder(None).unwrap().write().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let oid = repo
+            .commit(Some("HEAD"), signature, signature, "commit", &tree, &[])
+            .unwrap();
+        oid.to_string()
+    }
+
+    #[tokio::test]
+    async fn clone_repository_from_local_url() {
+        let remote = tempfile::tempdir().unwrap();
+        let commit_id = setup_bare_repository(remote.path());
+        let dst = tempfile::tempdir().unwrap();
+
+        let git = GitCLI::new().unwrap();
+        git.clone_repository(&format!("file://{}", remote.path().display()), dst.path())
+            .await
+            .unwrap();
+
+        let repo = git2::Repository::open(dst.path()).unwrap();
+        assert_eq!(
+            repo.head()
+                .unwrap()
+                .peel_to_commit()
+                .unwrap()
+                .id()
+                .to_string(),
+            commit_id
+        );
+    }
+
+    #[tokio::test]
+    async fn clone_repository_without_recent_commits() {
+        let remote = tempfile::tempdir().unwrap();
+        let signature = git2::Signature::new(
+            "user",
+            "user@project.io",
+            &git2::Time::new(1_420_070_400, 0),
+        )
+        .unwrap();
+        let commit_id = setup_bare_repository_with_signature(remote.path(), &signature);
+        let dst = tempfile::tempdir().unwrap();
+
+        let git = GitCLI::new().unwrap();
+        git.clone_repository(&format!("file://{}", remote.path().display()), dst.path())
+            .await
+            .unwrap();
+
+        let repo = git2::Repository::open(dst.path()).unwrap();
+        assert_eq!(
+            repo.head()
+                .unwrap()
+                .peel_to_commit()
+                .unwrap()
+                .id()
+                .to_string(),
+            commit_id
+        );
+    }
+
+    #[tokio::test]
+    async fn clone_repository_not_found() {
+        let remote = tempfile::tempdir().unwrap();
+        let dst = tempfile::tempdir().unwrap();
+
+        let git = GitCLI::new().unwrap();
+        let err = git
+            .clone_repository(
+                &format!("file://{}", remote.path().join("missing").display()),
+                dst.path(),
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("does not appear to be a git repository")
+        );
+    }
+
+    #[tokio::test]
+    async fn remote_digest_from_local_url() {
+        let remote = tempfile::tempdir().unwrap();
+        let commit_id = setup_bare_repository(remote.path());
+
+        let git = GitCLI::new().unwrap();
+        assert_eq!(
+            git.remote_digest(&format!("file://{}", remote.path().display()))
+                .await
+                .unwrap(),
+            commit_id
+        );
+    }
+}
@@ -1,9 +1,15 @@
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::doc_markdown, clippy::wildcard_imports)]
 
+pub mod data;
+
+pub mod git;
+
 #[allow(clippy::module_name_repetitions)]
 pub mod linter;
 
+pub mod report_md;
+
 #[allow(
     clippy::cast_precision_loss,
     clippy::cast_possible_truncation,
@@ -1,13 +1,22 @@
+use std::{collections::HashMap, fmt};
+
 use anyhow::{Context, Error, Result, format_err};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use which::which;
 
 use super::{
     CheckSet, LinterInput,
-    checks::{CHECKS, signed_releases},
+    checks::{
+        CHECKS, signed_releases,
+        util::{
+            helpers::{project_dir, should_skip_check},
+            links,
+        },
+    },
     datasource::{
         github,
-        scorecard::{Scorecard, ScorecardCheck, scorecard},
+        scorecard::{Scorecard, ScorecardCheck, get_check, scorecard},
         security_insights::SecurityInsights,
     },
     metadata::{Exemption, METADATA_FILE, Metadata},
@@ -18,9 +27,13 @@ pub type CheckId = &'static str;
 
 /// Check configuration.
 pub(crate) struct CheckConfig {
+    pub name: &'static str,
+    pub docs_anchor: &'static str,
     pub weight: usize,
     pub check_sets: Vec<CheckSet>,
     pub scorecard_name: Option<String>,
+    pub needs_github_md: bool,
+    pub needs_security_insights: bool,
 }
 
 /// Input used by checks to perform their operations.
@@ -31,34 +44,67 @@ pub(crate) struct CheckInput<'a> {
     pub gh_md: github::md::MdRepository,
     pub scorecard: Result<Scorecard>,
     pub security_insights: Result<Option<SecurityInsights>>,
+    pub evidence: HashMap<String, Result<String>>,
 }
 
 impl CheckInput<'_> {
     pub(crate) async fn new(li: &LinterInput) -> Result<CheckInput<'_>> {
+        // Datasources are only initialized when any of the checks that will be
+        // run needs them
+        let selected_checks: Vec<CheckId> = CHECKS
+            .keys()
+            .filter(|check_id| !should_skip_check(check_id, li))
+            .copied()
+            .collect();
+        let scorecard_needed = selected_checks
+            .iter()
+            .any(|check_id| CHECKS[check_id].scorecard_name.is_some());
+        let gh_md_needed = selected_checks
+            .iter()
+            .any(|check_id| CHECKS[check_id].needs_github_md);
+        let security_insights_needed = selected_checks
+            .iter()
+            .any(|check_id| CHECKS[check_id].needs_security_insights);
+
         // Check if required external tools are available
-        if which("scorecard").is_err() {
+        if scorecard_needed && which("scorecard").is_err() {
             return Err(format_err!(
                 "scorecard not found in PATH (https://github.com/ossf/scorecard#installation)"
             ));
         }
 
         // Get CLOMonitor metadata
-        let cm_md = Metadata::from(li.root.join(METADATA_FILE))?;
+        let cm_md = Metadata::load(&project_dir(li, &[METADATA_FILE], true)?, &li.url).await?;
 
         // The next both actions (get GitHub metadata and get scorecard) make use
         // of the GitHub token, which when used concurrently, may trigger some
         // GitHub secondary rate limits. So they should not be run concurrently.
 
         // Get GitHub metadata
-        let gh_md = github::metadata(&li.url, &li.github_token).await?;
+        let gh_md = if gh_md_needed {
+            github::metadata(&li.url, &li.github_token).await?
+        } else {
+            github::md::MdRepository::empty()
+        };
 
         // Get OpenSSF scorecard
-        let scorecard = scorecard(&li.url, &li.github_token)
-            .await
-            .context("error running scorecard command");
+        let scorecard = if scorecard_needed {
+            scorecard(&li.url, &li.github_token)
+                .await
+                .context("error running scorecard command")
+        } else {
+            Err(format_err!("scorecard not run: no selected check needs it"))
+        };
+
+        // Verify the evidence urls declared in the metadata file
+        let evidence = verify_evidence(cm_md.as_ref(), &selected_checks).await?;
 
         // Get OpenSSF security insights.
-        let security_insights = SecurityInsights::new(&li.root);
+        let security_insights = if security_insights_needed {
+            SecurityInsights::new(&li.root)
+        } else {
+            Ok(None)
+        };
 
         // Prepare and return check input
         let ci = CheckInput {
@@ -67,12 +113,52 @@ impl CheckInput<'_> {
             gh_md,
             scorecard,
             security_insights,
+            evidence,
         };
         Ok(ci)
     }
 }
 
+/// Verify that the evidence urls declared in the metadata file for the checks
+/// provided are reachable. Unreachable urls are kept as an error, so that the
+/// reason why the evidence was not used can be reported.
+async fn verify_evidence(
+    cm_md: Option<&Metadata>,
+    checks: &[CheckId],
+) -> Result<HashMap<String, Result<String>>> {
+    let declared: Vec<(String, String)> = cm_md
+        .and_then(|md| md.evidence.as_ref())
+        .map(|evidence| {
+            evidence
+                .iter()
+                .filter(|(check_id, _)| checks.contains(&check_id.as_str()))
+                .map(|(check_id, url)| (check_id.clone(), url.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    if declared.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let urls: Vec<String> = declared.iter().map(|(_, url)| url.clone()).collect();
+    let broken = links::find_broken(&urls).await?;
+    Ok(declared
+        .into_iter()
+        .map(|(check_id, url)| {
+            let result = if broken.contains(&url) {
+                Err(format_err!(
+                    "evidence url declared in the metadata file is not reachable: {url}"
+                ))
+            } else {
+                Ok(url)
+            };
+            (check_id, result)
+        })
+        .collect())
+}
+
 /// Check output information.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CheckOutput<T = ()> {
     pub passed: bool,
@@ -91,10 +177,58 @@ pub struct CheckOutput<T = ()> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exemption_reason: Option<String>,
 
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub exemption_expired: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exemption_approved_by: Option<String>,
+
     pub failed: bool,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fail_reason: Option<String>,
+
+    /// What the check looked for to decide its result. It is not part of the
+    /// report, it's only used to explain the check's result.
+    #[serde(skip)]
+    pub evidence: Vec<Evidence>,
+}
+
+/// Something a check looked for, and whether it was found or not.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Evidence {
+    pub source: EvidenceSource,
+    pub target: String,
+    pub found: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Source a piece of evidence was looked for in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceSource {
+    File,
+    Readme,
+    GitHub,
+    Git,
+    Scorecard,
+    Metadata,
+}
+
+impl fmt::Display for EvidenceSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::File => "File",
+            Self::Readme => "README",
+            Self::GitHub => "GitHub",
+            Self::Git => "Git",
+            Self::Scorecard => "Scorecard",
+            Self::Metadata => "Metadata",
+        };
+        write!(f, "{output}")
+    }
 }
 
 impl<T> CheckOutput<T> {
@@ -125,6 +259,16 @@ impl<T> CheckOutput<T> {
         }
     }
 
+    /// Create a new CheckOutput instance from the evidence provided. The check
+    /// passes when any of the things it looked for was found.
+    pub(crate) fn from_evidence(evidence: Vec<Evidence>) -> Self {
+        Self {
+            passed: evidence.iter().any(|e| e.found),
+            evidence,
+            ..Default::default()
+        }
+    }
+
     /// Create a new CheckOutput instance with the failed field set to true.
     #[must_use]
     pub fn failed() -> Self {
@@ -162,12 +306,80 @@ impl<T> CheckOutput<T> {
         self
     }
 
+    /// Exemption expired field setter.
+    #[must_use]
+    pub fn exemption_expired(mut self, expired: bool) -> CheckOutput<T> {
+        self.exemption_expired = expired;
+        self
+    }
+
+    /// Exemption approved by field setter.
+    #[must_use]
+    pub fn exemption_approved_by(mut self, approved_by: Option<String>) -> CheckOutput<T> {
+        self.exemption_approved_by = approved_by;
+        self
+    }
+
+    /// Flag the output when the exemption provided (the expired one declared
+    /// for its check, if any) has expired, keeping who approved it.
+    #[must_use]
+    pub(crate) fn expired_exemption(self, exemption: Option<Exemption>) -> CheckOutput<T> {
+        match exemption {
+            Some(exemption) => self
+                .exemption_expired(true)
+                .exemption_approved_by(exemption.approved_by),
+            None => self,
+        }
+    }
+
     /// Fail reason field setter.
     #[must_use]
     pub fn fail_reason(mut self, reason: Option<String>) -> CheckOutput<T> {
         self.fail_reason = reason;
         self
     }
+
+    /// Evidence field setter.
+    #[must_use]
+    pub fn evidence(mut self, evidence: Vec<Evidence>) -> CheckOutput<T> {
+        self.evidence = evidence;
+        self
+    }
+
+    /// Return a copy of the check output without its value.
+    #[must_use]
+    pub fn without_value(&self) -> CheckOutput {
+        self.with_value(None)
+    }
+
+    /// Return a copy of the check output with the value provided.
+    fn with_value<U>(&self, value: Option<U>) -> CheckOutput<U> {
+        CheckOutput {
+            passed: self.passed,
+            url: self.url.clone(),
+            value,
+            details: self.details.clone(),
+            exempt: self.exempt,
+            exemption_reason: self.exemption_reason.clone(),
+            exemption_expired: self.exemption_expired,
+            exemption_approved_by: self.exemption_approved_by.clone(),
+            failed: self.failed,
+            fail_reason: self.fail_reason.clone(),
+            evidence: self.evidence.clone(),
+        }
+    }
+}
+
+impl<T: Serialize> CheckOutput<T> {
+    /// Return a copy of the check output with its value serialized as json.
+    #[must_use]
+    pub fn with_json_value(&self) -> CheckOutput<Value> {
+        self.with_value(
+            self.value
+                .as_ref()
+                .and_then(|value| serde_json::to_value(value).ok()),
+        )
+    }
 }
 
 impl<T> Default for CheckOutput<T> {
@@ -179,15 +391,43 @@ impl<T> Default for CheckOutput<T> {
             details: None,
             exempt: false,
             exemption_reason: None,
+            exemption_expired: false,
+            exemption_approved_by: None,
             failed: false,
             fail_reason: None,
+            evidence: vec![],
         }
     }
 }
 
 impl<T> From<Exemption> for CheckOutput<T> {
     fn from(exemption: Exemption) -> Self {
-        Self::exempt().exemption_reason(Some(exemption.reason))
+        Self::exempt()
+            .exemption_reason(Some(exemption.reason))
+            .exemption_approved_by(exemption.approved_by)
+    }
+}
+
+impl<T> CheckOutput<T> {
+    /// Create a new CheckOutput instance from the OpenSSF Scorecard check that
+    /// corresponds to the check provided, recording the Scorecard check used.
+    pub(crate) fn from_scorecard(scorecard: &Result<Scorecard>, check_id: CheckId) -> Self {
+        let sc_check = get_check(scorecard, check_id);
+        let (found, detail) = match sc_check {
+            Ok(Some(sc_check)) => (
+                true,
+                format!("score: {}, reason: {}", sc_check.score, sc_check.reason),
+            ),
+            Ok(None) => (false, "check not found in scorecard".to_string()),
+            Err(err) => (false, format!("{err:#}")),
+        };
+        let evidence = Evidence {
+            source: EvidenceSource::Scorecard,
+            target: CHECKS[check_id].scorecard_name.clone().unwrap_or_default(),
+            found,
+            detail: Some(detail),
+        };
+        CheckOutput::from(sc_check).evidence(vec![evidence])
     }
 }
 
@@ -241,12 +481,13 @@ macro_rules! run {
     ($check:ident, $input:expr) => {
         (|| {
             // Check if this check should be skipped
-            if should_skip_check($check::ID, &$input.li.check_sets) {
+            if should_skip_check($check::ID, $input.li) {
                 return None;
             }
 
             // Check if an exemption has been declared for this check
-            if let Some(exemption) = find_exemption($check::ID, $input.cm_md.as_ref()) {
+            let check_sets = &$input.li.check_sets;
+            if let Some(exemption) = find_exemption($check::ID, $input.cm_md.as_ref(), check_sets) {
                 return Some(CheckOutput::from(exemption));
             }
 
@@ -255,7 +496,13 @@ macro_rules! run {
                 Ok(output) => output,
                 Err(err) => CheckOutput::failed().fail_reason(Some(format!("{:#}", err))),
             };
-            Some(output)
+
+            // Use the evidence declared in the metadata file if the check did not pass
+            let output = apply_evidence($check::ID, $input, output);
+
+            // Flag the output when the exemption declared for this check has expired
+            let expired = find_expired_exemption($check::ID, $input.cm_md.as_ref(), check_sets);
+            Some(output.expired_exemption(expired))
         })()
     };
 }
@@ -267,12 +514,13 @@ macro_rules! run_async {
     ($check:ident, $input:expr) => {
         async {
             // Check if this check should be skipped
-            if should_skip_check($check::ID, &$input.li.check_sets) {
+            if should_skip_check($check::ID, $input.li) {
                 return None;
             }
 
             // Check if an exemption has been declared for this check
-            if let Some(exemption) = find_exemption($check::ID, $input.cm_md.as_ref()) {
+            let check_sets = &$input.li.check_sets;
+            if let Some(exemption) = find_exemption($check::ID, $input.cm_md.as_ref(), check_sets) {
                 return Some(CheckOutput::from(exemption));
             }
 
@@ -281,7 +529,13 @@ macro_rules! run_async {
                 Ok(output) => output,
                 Err(err) => CheckOutput::failed().fail_reason(Some(format!("{:#}", err))),
             };
-            Some(output)
+
+            // Use the evidence declared in the metadata file if the check did not pass
+            let output = apply_evidence($check::ID, $input, output);
+
+            // Flag the output when the exemption declared for this check has expired
+            let expired = find_expired_exemption($check::ID, $input.cm_md.as_ref(), check_sets);
+            Some(output.expired_exemption(expired))
         }
     };
 }
@@ -289,15 +543,138 @@ pub(crate) use run_async;
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
+    use time::macros::date;
+
     use super::*;
-    use crate::linter::datasource::scorecard::ScorecardCheckDocs;
+    use crate::linter::{
+        checks::{
+            community_meeting,
+            util::helpers::{apply_evidence, find_exemption, find_expired_exemption},
+        },
+        datasource::scorecard::ScorecardCheckDocs,
+    };
     use anyhow::{Result, format_err};
 
+    #[tokio::test]
+    async fn check_input_new_skips_datasources_not_needed() {
+        let li = LinterInput {
+            root: PathBuf::from("src/testdata"),
+            check_sets: vec![CheckSet::Community],
+            checks: vec![community_meeting::ID.to_string()],
+            ..LinterInput::default()
+        };
+        let ci = CheckInput::new(&li).await.unwrap();
+
+        assert!(ci.scorecard.is_err());
+        assert!(matches!(ci.security_insights, Ok(None)));
+        assert_eq!(ci.gh_md, github::md::MdRepository::empty());
+    }
+
+    #[test]
+    fn run_keeps_approver_of_expired_exemption() {
+        let li = LinterInput {
+            root: PathBuf::from("src/testdata"),
+            check_sets: vec![CheckSet::Community],
+            ..LinterInput::default()
+        };
+        let ci = CheckInput {
+            li: &li,
+            cm_md: Some(Metadata {
+                evidence: None,
+                exemptions: Some(vec![Exemption {
+                    check: community_meeting::ID.to_string(),
+                    reason: "sample reason".to_string(),
+                    expires: Some(date!(2000 - 01 - 01)),
+                    approved_by: Some("https://github.com/cncf/toc/issues/1".to_string()),
+                    ..Exemption::default()
+                }]),
+                gates: None,
+                license_scanning: None,
+            }),
+            gh_md: github::md::MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            evidence: HashMap::new(),
+        };
+
+        let output = run!(community_meeting, &ci).unwrap();
+        assert!(output.exemption_expired);
+        assert_eq!(
+            output.exemption_approved_by.as_deref(),
+            Some("https://github.com/cncf/toc/issues/1")
+        );
+    }
+
+    #[test]
+    fn check_output_from_exemption_keeps_approver() {
+        let exemption = Exemption {
+            check: community_meeting::ID.to_string(),
+            reason: "sample reason".to_string(),
+            approved_by: Some("https://github.com/cncf/toc/issues/1".to_string()),
+            ..Exemption::default()
+        };
+
+        assert_eq!(
+            CheckOutput::<()>::from(exemption),
+            CheckOutput::exempt()
+                .exemption_reason(Some("sample reason".to_string()))
+                .exemption_approved_by(Some("https://github.com/cncf/toc/issues/1".to_string()))
+        );
+    }
+
+    #[test]
+    fn run_flags_output_when_exemption_expired() {
+        let li = LinterInput {
+            root: PathBuf::from("src/testdata"),
+            check_sets: vec![CheckSet::Community],
+            ..LinterInput::default()
+        };
+        let ci = CheckInput {
+            li: &li,
+            cm_md: Some(Metadata {
+                evidence: None,
+                exemptions: Some(vec![Exemption {
+                    check: community_meeting::ID.to_string(),
+                    reason: "sample reason".to_string(),
+                    expires: Some(date!(2000 - 01 - 01)),
+                    ..Exemption::default()
+                }]),
+                gates: None,
+                license_scanning: None,
+            }),
+            gh_md: github::md::MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            evidence: HashMap::new(),
+        };
+
+        assert_eq!(
+            run!(community_meeting, &ci),
+            Some(
+                CheckOutput::not_passed().exemption_expired(true).evidence(
+                    community_meeting::README_REF
+                        .patterns()
+                        .iter()
+                        .map(|pattern| Evidence {
+                            source: EvidenceSource::Readme,
+                            target: pattern.clone(),
+                            found: false,
+                            detail: None,
+                        })
+                        .collect()
+                )
+            )
+        );
+    }
+
     #[test]
     fn check_output_from_exemption() {
         let exemption = Exemption {
             check: "test".to_string(),
             reason: "test".to_string(),
+            ..Exemption::default()
         };
 
         assert_eq!(
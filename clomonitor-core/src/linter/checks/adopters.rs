@@ -13,14 +13,26 @@ use super::util::helpers::find_file_or_readme_ref;
 /// Check identifier.
 pub(crate) const ID: CheckId = "adopters";
 
+/// Check name.
+pub(crate) const NAME: &str = "Adopters";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "adopters";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Patterns used to locate a file in the repository.
-const FILE_PATTERNS: [&str; 2] = ["adopters*", "users*"];
+pub(crate) const FILE_PATTERNS: [&str; 2] = ["adopters*", "users*"];
 
 pub(crate) static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
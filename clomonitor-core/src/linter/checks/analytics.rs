@@ -11,12 +11,24 @@ use crate::linter::{
 /// Check identifier.
 pub(crate) const ID: CheckId = "analytics";
 
+/// Check name.
+pub(crate) const NAME: &str = "Analytics";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "analytics";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 0;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Google Analytics 4 regular expressions.
 static GA4: LazyLock<RegexSet> =
     LazyLock::new(|| RegexSet::new([r"\bG-[A-Z0-9]{10}\b"]).expect("exprs in GA4 to be valid"));
@@ -8,17 +8,29 @@ use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
 };
 
-use super::util::helpers::readme_capture;
+use super::util::helpers::{readme_capture_evidence, readme_root};
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "artifacthub_badge";
 
+/// Check name.
+pub(crate) const NAME: &str = "Artifact Hub badge";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "artifact-hub-badge";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = false;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 static ARTIFACTHUB_URL: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"(https://artifacthub.io/packages/[^"'\)\s]+)"#)
         .expect("exprs in ARTIFACTHUB_URL to be valid")
@@ -27,12 +39,12 @@ static ARTIFACTHUB_URL: LazyLock<Regex> = LazyLock::new(|| {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Reference in README file
-    let url = readme_capture(&input.li.root, &[&ARTIFACTHUB_URL])?;
+    let (url, evidence) = readme_capture_evidence(&readme_root(input.li)?, &[&ARTIFACTHUB_URL])?;
     if url.is_some() {
-        return Ok(CheckOutput::passed().url(url));
+        return Ok(CheckOutput::passed().url(url).evidence(vec![evidence]));
     }
 
-    Ok(CheckOutput::not_passed())
+    Ok(CheckOutput::not_passed().evidence(vec![evidence]))
 }
 
 #[cfg(test)]
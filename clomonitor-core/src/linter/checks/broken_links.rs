@@ -0,0 +1,178 @@
+use anyhow::Result;
+
+use crate::linter::{
+    CheckSet,
+    check::{CheckId, CheckInput, CheckOutput},
+};
+
+use super::{
+    contributing, governance, readme, security_policy,
+    util::{fs, helpers::find_project_file, links},
+};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "broken_links";
+
+/// Check name.
+pub(crate) const NAME: &str = "Broken links";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "broken-links";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 0;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 3] = [CheckSet::Code, CheckSet::Community, CheckSet::Docs];
+
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = false;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    let root = input.li.root.as_path();
+
+    // Files whose links will be verified (patterns and case sensitivity)
+    let files: [(&[&str], bool); 4] = [
+        (&readme::FILE_PATTERNS, true),
+        (&contributing::FILE_PATTERNS, false),
+        (&governance::FILE_PATTERNS, false),
+        (&security_policy::FILE_PATTERNS, false),
+    ];
+
+    // Extract links from the files found, verifying local ones
+    let mut broken_links: Vec<String> = Vec::new();
+    let mut remote_links: Vec<(String, String)> = Vec::new();
+    for (patterns, case_sensitive) in files {
+        let Some(path) = find_project_file(input.li, patterns, case_sensitive)? else {
+            continue;
+        };
+        let Ok(content) = fs::read_to_string(root.join(&path)) else {
+            continue;
+        };
+        let file = path.to_string_lossy().to_string();
+        let dir = root.join(&path).parent().unwrap_or(root).to_owned();
+        for link in links::extract_from_markdown(&content) {
+            if links::is_local(&link) {
+                if !links::local_target_exists(root, &dir, &link) {
+                    broken_links.push(format!("{file}: {link}"));
+                }
+            } else if links::is_remote(&link) {
+                remote_links.push((file.clone(), link));
+            }
+        }
+    }
+
+    // Verify remote links
+    let mut urls: Vec<String> = remote_links.iter().map(|(_, link)| link.clone()).collect();
+    urls.sort();
+    urls.dedup();
+    let broken_urls = links::find_broken(&urls).await?;
+    for (file, link) in remote_links {
+        if broken_urls.contains(&link) {
+            broken_links.push(format!("{file}: {link}"));
+        }
+    }
+
+    // Return check output
+    if broken_links.is_empty() {
+        return Ok(CheckOutput::passed());
+    }
+    let details = broken_links
+        .iter()
+        .fold("# Broken links found\n\n".to_string(), |details, link| {
+            details + "· " + link + "\n"
+        });
+    Ok(CheckOutput::not_passed().details(Some(details)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::format_err;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use crate::linter::{LinterInput, datasource::github::md::MdRepository};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn passed_no_files_found() {
+        let root = tempfile::tempdir().unwrap();
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::passed(),
+        );
+    }
+
+    #[tokio::test]
+    async fn not_passed_broken_links_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .and(path("/ok"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(root.path().join("docs")).unwrap();
+        std::fs::write(
+            root.path().join("README.md"),
+            format!(
+                "[Contributing](docs/CONTRIBUTING.md) [Roadmap](ROADMAP.md) [Ok]({uri}/ok) [Broken]({uri}/broken)",
+                uri = mock_server.uri()
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            root.path().join("docs/CONTRIBUTING.md"),
+            "[Readme](../README.md#intro) [Setup](setup.md) [Contact](mailto:info@project.io)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::not_passed().details(Some(format!(
+                "# Broken links found
+
+· README.md: ROADMAP.md
+· docs/CONTRIBUTING.md: setup.md
+· README.md: {}/broken
+",
+                mock_server.uri()
+            ))),
+        );
+    }
+}
@@ -5,7 +5,7 @@ use regex::RegexSet;
 
 use crate::linter::{
     CheckSet,
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
 };
 
 use super::{datasource::github, util::helpers::find_file_or_readme_ref};
@@ -13,16 +13,28 @@ use super::{datasource::github, util::helpers::find_file_or_readme_ref};
 /// Check identifier.
 pub(crate) const ID: CheckId = "changelog";
 
+/// Check name.
+pub(crate) const NAME: &str = "Changelog";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "changelog";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Patterns used to locate a file in the repository.
 pub(crate) static FILE_PATTERNS: [&str; 1] = ["changelog*"];
 
-static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
+pub(crate) static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?im)^#+.*changelog.*$",
         r"(?im)^changelog$",
@@ -38,17 +50,19 @@ static RELEASE_REF: LazyLock<RegexSet> = LazyLock::new(|| {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo or reference in README file
-    let output = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
+    let mut output = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
     if output.passed {
         return Ok(output);
     }
 
     // Reference in last release
-    if github::latest_release_description_matches(&input.gh_md, &RELEASE_REF) {
-        return Ok(CheckOutput::passed());
-    }
-
-    Ok(CheckOutput::not_passed())
+    output.evidence.push(Evidence {
+        source: EvidenceSource::GitHub,
+        target: "changelog reference in latest release description".to_string(),
+        found: github::latest_release_description_matches(&input.gh_md, &RELEASE_REF),
+        detail: None,
+    });
+    Ok(CheckOutput::from_evidence(output.evidence))
 }
 
 #[cfg(test)]
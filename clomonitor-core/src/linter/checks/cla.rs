@@ -5,7 +5,7 @@ use regex::RegexSet;
 
 use crate::linter::{
     CheckSet,
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
 };
 
 use super::datasource::github;
@@ -13,12 +13,24 @@ use super::datasource::github;
 /// Check identifier.
 pub(crate) const ID: CheckId = "cla";
 
+/// Check name.
+pub(crate) const NAME: &str = "CLA";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "contributor-license-agreement";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 static CHECK_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?i)cncf-cla",
@@ -34,11 +46,13 @@ static CHECK_REF: LazyLock<RegexSet> = LazyLock::new(|| {
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // CLA check in Github
-    if github::has_check(&input.gh_md, &CHECK_REF) {
-        return Ok(CheckOutput::passed());
-    }
-
-    Ok(CheckOutput::not_passed())
+    let check = github::find_check(&input.gh_md, &CHECK_REF);
+    Ok(CheckOutput::from_evidence(vec![Evidence {
+        source: EvidenceSource::GitHub,
+        target: "CLA check in latest merged PR".to_string(),
+        found: check.is_some(),
+        detail: check,
+    }]))
 }
 
 #[cfg(test)]
@@ -5,7 +5,7 @@ use regex::RegexSet;
 
 use crate::linter::{
     CheckSet,
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
 };
 
 use super::util::helpers::find_file_or_readme_ref;
@@ -13,12 +13,24 @@ use super::util::helpers::find_file_or_readme_ref;
 /// Check identifier.
 pub(crate) const ID: CheckId = "code_of_conduct";
 
+/// Check name.
+pub(crate) const NAME: &str = "Code of conduct";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "code-of-conduct";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 2;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Patterns used to locate a file in the repository.
 pub(crate) static FILE_PATTERNS: [&str; 3] = [
     "code*of*conduct*",
@@ -26,7 +38,7 @@ pub(crate) static FILE_PATTERNS: [&str; 3] = [
     "docs/code*of*conduct*",
 ];
 
-static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
+pub(crate) static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?im)^#+.*code of conduct.*$",
         r"(?im)^code of conduct$",
@@ -38,19 +50,24 @@ static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo or reference in README file
-    let output = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
+    let mut output = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
     if output.passed {
         return Ok(output);
     }
 
     // File in Github (default community health file, for example)
-    if let Some(coc) = &input.gh_md.code_of_conduct
-        && coc.url.is_some()
-    {
-        return Ok(CheckOutput::passed().url(coc.url.clone()));
-    }
-
-    Ok(CheckOutput::not_passed())
+    let url = input
+        .gh_md
+        .code_of_conduct
+        .as_ref()
+        .and_then(|coc| coc.url.clone());
+    output.evidence.push(Evidence {
+        source: EvidenceSource::GitHub,
+        target: "code of conduct".to_string(),
+        found: url.is_some(),
+        detail: url.clone(),
+    });
+    Ok(CheckOutput::from_evidence(output.evidence).url(url))
 }
 
 #[cfg(test)]
@@ -0,0 +1,442 @@
+use std::{fmt::Write, sync::LazyLock};
+
+use anyhow::Result;
+use regex::Regex;
+
+use crate::linter::{
+    ChatPlatform, CheckSet,
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
+};
+
+use super::util::{
+    fs,
+    helpers::{readme_globs, readme_root},
+    links::HTTP_CLIENT,
+    path,
+};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "community_chat";
+
+/// Check name.
+pub(crate) const NAME: &str = "Community chat";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "community-chat";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 0;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
+
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
+/// Regular expressions used to detect the channels of each chat platform.
+const CHANNEL_PATTERNS: [(ChatPlatform, &str); 7] = [
+    (
+        ChatPlatform::Discord,
+        r"(?i)https?://(?:www\.)?(?:discord\.gg|discord(?:app)?\.com/invite)/[a-z0-9-]+",
+    ),
+    (
+        ChatPlatform::Gitter,
+        r#"(?i)https?://(?:app\.)?gitter\.im/[^\s)\]"'<>]+"#,
+    ),
+    (
+        ChatPlatform::MailingList,
+        r#"(?i)https?://(?:groups\.google\.com/(?:a/[^/\s]+/)?(?:g|forum)/|(?:[a-z0-9-]+\.)?groups\.io/g/|lists\.[a-z0-9-]+(?:\.[a-z0-9-]+)+/)[^\s)\]"'<>]+"#,
+    ),
+    (
+        ChatPlatform::MailingList,
+        r"(?i)mailto:[a-z0-9._%+-]+@(?:lists\.[a-z0-9-]+(?:\.[a-z0-9-]+)+|googlegroups\.com)",
+    ),
+    (
+        ChatPlatform::Matrix,
+        r#"(?i)https?://(?:matrix\.to/#/|app\.element\.io/#/room/)[#!][^\s)\]"'<>]+"#,
+    ),
+    (
+        ChatPlatform::Slack,
+        r#"(?i)https?://(?:[a-z0-9-]+\.slack\.com|slack\.[a-z0-9-]+(?:\.[a-z0-9-]+)+)(?:/[^\s)\]"'<>]*)?"#,
+    ),
+    (
+        ChatPlatform::Zulip,
+        r#"(?i)https?://[a-z0-9-]+\.zulipchat\.com(?:/[^\s)\]"'<>]*)?"#,
+    ),
+];
+
+static CHANNELS: LazyLock<Vec<(ChatPlatform, Regex)>> = LazyLock::new(|| {
+    CHANNEL_PATTERNS
+        .iter()
+        .map(|(platform, pattern)| {
+            let re = Regex::new(pattern).expect("exprs in CHANNEL_PATTERNS to be valid");
+            (*platform, re)
+        })
+        .collect()
+});
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput<Vec<String>>> {
+    let mut channels: Vec<(ChatPlatform, String)> = Vec::new();
+
+    // Channels referenced in README file
+    for path in path::matches(&readme_globs(&readme_root(input.li)?))? {
+        if let Ok(content) = fs::read_to_string(&path) {
+            find_channels(&content, &mut channels);
+        }
+    }
+    let mut evidence = vec![Evidence {
+        source: EvidenceSource::Readme,
+        target: "chat channel links".to_string(),
+        found: !channels.is_empty(),
+        detail: None,
+    }];
+
+    // Channels referenced in website setup in Github (the website is
+    // ignored when it cannot be fetched)
+    if let Some(url) = &input.gh_md.homepage_url
+        && !url.is_empty()
+    {
+        let readme_channels = channels.len();
+        if let Some(content) = fetch(url).await {
+            find_channels(&content, &mut channels);
+        }
+        evidence.push(Evidence {
+            source: EvidenceSource::GitHub,
+            target: "chat channel links in homepage".to_string(),
+            found: channels.len() > readme_channels,
+            detail: Some(url.clone()),
+        });
+    }
+
+    if channels.is_empty() {
+        return Ok(CheckOutput::not_passed().evidence(evidence));
+    }
+
+    // Channels found must belong to any of the preferred platforms (if any)
+    let preferred = &input.li.preferred_chat_platforms;
+    let preferred_found = preferred.is_empty()
+        || channels
+            .iter()
+            .any(|(platform, _)| preferred.contains(platform));
+    let mut details = channels.iter().fold(
+        "# Community chat channels detected\n\n".to_string(),
+        |details, (platform, url)| details + &format!("· {platform}: {url}\n"),
+    );
+    if !preferred_found {
+        let preferred = preferred
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            details,
+            "\nNo channels found in the foundation's preferred platforms: {preferred}"
+        )?;
+    }
+
+    let output = if preferred_found {
+        CheckOutput::passed()
+    } else {
+        CheckOutput::not_passed()
+    };
+    Ok(output
+        .value(Some(channels.into_iter().map(|(_, url)| url).collect()))
+        .details(Some(details))
+        .evidence(evidence))
+}
+
+/// Fetch the content of the page at the url provided, returning None if it
+/// cannot be fetched.
+async fn fetch(url: &str) -> Option<String> {
+    let resp = HTTP_CLIENT.get(url).send().await.ok()?;
+    resp.error_for_status().ok()?.text().await.ok()
+}
+
+/// Find the chat channels referenced in the content provided, adding them to
+/// the channels list when they are not already in it.
+fn find_channels(content: &str, channels: &mut Vec<(ChatPlatform, String)>) {
+    for (platform, re) in CHANNELS.iter() {
+        for m in re.find_iter(content) {
+            let url = m.as_str().trim_end_matches(['.', ',', ';', ':', '/']);
+            if !channels.iter().any(|(_, channel_url)| channel_url == url) {
+                channels.push((*platform, url.to_string()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::format_err;
+    use regex::RegexSet;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use crate::linter::{LinterInput, datasource::github::md::MdRepository};
+
+    use super::*;
+
+    static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
+        RegexSet::new(CHANNEL_PATTERNS.map(|(_, pattern)| pattern))
+            .expect("exprs in README_REF to be valid")
+    });
+
+    #[test]
+    fn readme_ref_match() {
+        assert!(README_REF.is_match("Joining [CNCF slack](https://cloud-native.slack.com)"));
+        assert!(README_REF.is_match("Visit [http://slack.cncf.io/](http://slack.cncf.io/)"));
+        assert!(README_REF.is_match("[Slack](https://slack.lfenergy.org)"));
+        assert!(README_REF.is_match("[Discord](https://discord.gg/AbCd123)"));
+        assert!(README_REF.is_match("[Discord](https://discord.com/invite/AbCd123)"));
+        assert!(README_REF.is_match("[Matrix](https://matrix.to/#/#project:matrix.org)"));
+        assert!(
+            README_REF.is_match("[Element](https://app.element.io/#/room/#project:matrix.org)")
+        );
+        assert!(README_REF.is_match("[Zulip](https://project.zulipchat.com)"));
+        assert!(README_REF.is_match("[Gitter](https://gitter.im/org/project)"));
+        assert!(README_REF.is_match("[Mailing list](https://lists.aswf.io/g/project-discuss)"));
+        assert!(README_REF.is_match("[Mailing list](https://groups.google.com/g/project-dev)"));
+        assert!(README_REF.is_match("Email [us](mailto:project-dev@googlegroups.com)"));
+
+        assert!(!README_REF.is_match("[Slack API](https://slack.com/api)"));
+        assert!(!README_REF.is_match("[Discord](https://discord.com)"));
+    }
+
+    #[test]
+    fn find_channels_detects_platforms_without_duplicates() {
+        let mut channels = Vec::new();
+        find_channels(
+            r#"
+            Join us on [Slack](https://slack.lfenergy.org/), or in our
+            <a href="https://discord.gg/AbCd123">Discord server</a>. You can
+            also subscribe to https://lists.lfenergy.org/g/project.
+            Slack invite: https://slack.lfenergy.org
+            "#,
+            &mut channels,
+        );
+
+        assert_eq!(
+            channels,
+            vec![
+                (
+                    ChatPlatform::Discord,
+                    "https://discord.gg/AbCd123".to_string()
+                ),
+                (
+                    ChatPlatform::MailingList,
+                    "https://lists.lfenergy.org/g/project".to_string()
+                ),
+                (
+                    ChatPlatform::Slack,
+                    "https://slack.lfenergy.org".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn not_passed_no_channels_found() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("README.md"), "# Project").unwrap();
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::not_passed().evidence(vec![Evidence {
+                source: EvidenceSource::Readme,
+                target: "chat channel links".to_string(),
+                found: false,
+                detail: None,
+            }]),
+        );
+    }
+
+    #[tokio::test]
+    async fn passed_channels_found_in_readme_and_website() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("README.md"),
+            "Join our [Discord](https://discord.gg/AbCd123)",
+        )
+        .unwrap();
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(
+                    r#"<a href="https://matrix.to/#/#project:matrix.org">Matrix</a>"#,
+                ),
+            )
+            .mount(&mock_server)
+            .await;
+        let homepage_url = format!("{}/", mock_server.uri());
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository {
+                    homepage_url: Some(homepage_url.clone()),
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::passed()
+                .value(Some(vec![
+                    "https://discord.gg/AbCd123".to_string(),
+                    "https://matrix.to/#/#project:matrix.org".to_string(),
+                ]))
+                .details(Some(
+                    "# Community chat channels detected
+
+· Discord: https://discord.gg/AbCd123
+· Matrix: https://matrix.to/#/#project:matrix.org
+"
+                    .to_string()
+                ))
+                .evidence(vec![
+                    Evidence {
+                        source: EvidenceSource::Readme,
+                        target: "chat channel links".to_string(),
+                        found: true,
+                        detail: None,
+                    },
+                    Evidence {
+                        source: EvidenceSource::GitHub,
+                        target: "chat channel links in homepage".to_string(),
+                        found: true,
+                        detail: Some(homepage_url),
+                    },
+                ]),
+        );
+    }
+
+    #[tokio::test]
+    async fn passed_website_fetch_failure_ignored() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("README.md"),
+            "Join our [Discord](https://discord.gg/AbCd123)",
+        )
+        .unwrap();
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+        let homepage_url = format!("{}/", mock_server.uri());
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository {
+                    homepage_url: Some(homepage_url.clone()),
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::passed()
+                .value(Some(vec!["https://discord.gg/AbCd123".to_string()]))
+                .details(Some(
+                    "# Community chat channels detected
+
+· Discord: https://discord.gg/AbCd123
+"
+                    .to_string()
+                ))
+                .evidence(vec![
+                    Evidence {
+                        source: EvidenceSource::Readme,
+                        target: "chat channel links".to_string(),
+                        found: true,
+                        detail: None,
+                    },
+                    Evidence {
+                        source: EvidenceSource::GitHub,
+                        target: "chat channel links in homepage".to_string(),
+                        found: false,
+                        detail: Some(homepage_url),
+                    },
+                ]),
+        );
+    }
+
+    #[tokio::test]
+    async fn not_passed_preferred_platforms_not_found() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("README.md"),
+            "Join our [Discord](https://discord.gg/AbCd123)",
+        )
+        .unwrap();
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    preferred_chat_platforms: vec![ChatPlatform::Slack, ChatPlatform::MailingList],
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::not_passed()
+                .value(Some(vec!["https://discord.gg/AbCd123".to_string()]))
+                .details(Some(
+                    "# Community chat channels detected
+
+· Discord: https://discord.gg/AbCd123
+
+No channels found in the foundation's preferred platforms: Slack, Mailing list
+"
+                    .to_string()
+                ))
+                .evidence(vec![Evidence {
+                    source: EvidenceSource::Readme,
+                    target: "chat channel links".to_string(),
+                    found: true,
+                    detail: None,
+                }]),
+        );
+    }
+}
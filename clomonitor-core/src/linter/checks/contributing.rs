@@ -5,7 +5,7 @@ use regex::RegexSet;
 
 use crate::linter::{
     CheckSet,
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
 };
 
 use super::{datasource::github, util::helpers::find_file_or_readme_ref};
@@ -13,6 +13,12 @@ use super::{datasource::github, util::helpers::find_file_or_readme_ref};
 /// Check identifier.
 pub(crate) const ID: CheckId = "contributing";
 
+/// Check name.
+pub(crate) const NAME: &str = "Contributing";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "contributing";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 4;
 
@@ -20,14 +26,20 @@ pub(crate) const WEIGHT: usize = 4;
 pub(crate) const CHECK_SETS: [CheckSet; 3] =
     [CheckSet::Code, CheckSet::CodeLite, CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Patterns used to locate a file in the repository.
-const FILE_PATTERNS: [&str; 3] = [
+pub(crate) const FILE_PATTERNS: [&str; 3] = [
     "contributing*",
     ".github/contributing*",
     "docs/contributing*",
 ];
 
-static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
+pub(crate) static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?im)^#+.*contributing.*$",
         r"(?im)^contributing$",
@@ -39,17 +51,20 @@ static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
 /// Check main function.
 pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
     // File in repo or reference in README file
-    let output = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
+    let mut output = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
     if output.passed {
         return Ok(output);
     }
 
     // File in .github repo
-    if let Some(url) = github::has_community_health_file("CONTRIBUTING.md", &input.gh_md).await? {
-        return Ok(CheckOutput::passed().url(Some(url)));
-    }
-
-    Ok(CheckOutput::not_passed())
+    let url = github::has_community_health_file("CONTRIBUTING.md", &input.gh_md).await?;
+    output.evidence.push(Evidence {
+        source: EvidenceSource::GitHub,
+        target: "CONTRIBUTING.md community health file".to_string(),
+        found: url.is_some(),
+        detail: url.clone(),
+    });
+    Ok(CheckOutput::from_evidence(output.evidence).url(url))
 }
 
 #[cfg(test)]
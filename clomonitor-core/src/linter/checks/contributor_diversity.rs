@@ -0,0 +1,387 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use crate::linter::{
+    CheckSet,
+    check::{CheckId, CheckInput, CheckOutput},
+    datasource::github::md::{MdRepository, MdRepositoryCommitAuthorsTarget},
+};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "contributor_diversity";
+
+/// Check name.
+pub(crate) const NAME: &str = "Contributor diversity";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "contributor-diversity";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 0;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
+
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
+/// Period of time used to find active contributors.
+const ACTIVE_PERIOD: Duration = Duration::days(365);
+
+/// Minimum number of active contributors required.
+const MIN_ACTIVE_CONTRIBUTORS: usize = 3;
+
+/// Maximum share of commits (percentage) the top contributor can author.
+const MAX_TOP_CONTRIBUTOR_SHARE: u64 = 75;
+
+/// Minimum number of distinct email domains required.
+const MIN_EMAIL_DOMAINS: usize = 2;
+
+/// Public and noreply email domains, which do not identify any organization.
+const PUBLIC_EMAIL_DOMAINS: [&str; 17] = [
+    "aol.com",
+    "gmail.com",
+    "gmx.com",
+    "gmx.de",
+    "googlemail.com",
+    "hotmail.com",
+    "icloud.com",
+    "live.com",
+    "mail.ru",
+    "me.com",
+    "noreply.github.com",
+    "outlook.com",
+    "proton.me",
+    "protonmail.com",
+    "qq.com",
+    "users.noreply.github.com",
+    "yahoo.com",
+];
+
+/// Contributor diversity metrics computed from the repository's history.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContributorDiversity {
+    /// Number of contributors with commits in the last 12 months.
+    pub active_contributors: usize,
+
+    /// Share of the commits authored by the top contributor (percentage).
+    pub top_contributor_share: u64,
+
+    /// Number of distinct email domains used by the active contributors.
+    pub email_domains: usize,
+}
+
+/// Commit author details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Author {
+    name: String,
+    email: String,
+}
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput<ContributorDiversity>> {
+    // Compute metrics from the commits in the last 12 months
+    let since = OffsetDateTime::now_utc() - ACTIVE_PERIOD;
+    let authors = recent_commits_authors(&input.li.root, since)?;
+    let value = diversity(&authors, &github_logins(&input.gh_md));
+
+    // Verify metrics against the thresholds
+    let mut issues: Vec<String> = Vec::new();
+    if value.active_contributors < MIN_ACTIVE_CONTRIBUTORS {
+        issues.push(format!(
+            "{} active contributors in the last 12 months (minimum: {MIN_ACTIVE_CONTRIBUTORS})",
+            value.active_contributors
+        ));
+    }
+    if value.top_contributor_share > MAX_TOP_CONTRIBUTOR_SHARE {
+        issues.push(format!(
+            "Top contributor authored {}% of the commits (maximum: {MAX_TOP_CONTRIBUTOR_SHARE}%)",
+            value.top_contributor_share
+        ));
+    }
+    if value.email_domains < MIN_EMAIL_DOMAINS {
+        issues.push(format!(
+            "{} email domains used by active contributors (minimum: {MIN_EMAIL_DOMAINS})",
+            value.email_domains
+        ));
+    }
+
+    // Return check output
+    if issues.is_empty() {
+        return Ok(CheckOutput::passed().value(Some(value)));
+    }
+    let details = issues.iter().fold(
+        "# Contributor diversity issues found\n\n".to_string(),
+        |details, issue| details + "· " + issue + "\n",
+    );
+    Ok(CheckOutput::not_passed()
+        .value(Some(value))
+        .details(Some(details)))
+}
+
+/// Return the authors of the commits made since the time provided in the git
+/// repository located in the path provided. Commits authored by bots are
+/// ignored.
+fn recent_commits_authors(path: &Path, since: OffsetDateTime) -> Result<Vec<Author>> {
+    let repo = git2::Repository::open(path)?;
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(git2::Sort::TIME)?;
+    revwalk.push_head()?;
+
+    let mut authors = Vec::new();
+    for oid in revwalk {
+        let Ok(oid) = oid else {
+            continue;
+        };
+        let commit = repo.find_commit(oid)?;
+        if commit.time().seconds() < since.unix_timestamp() {
+            break;
+        }
+        let author = commit.author();
+        let author = Author {
+            name: author.name().unwrap_or_default().to_string(),
+            email: author.email().unwrap_or_default().to_lowercase(),
+        };
+        if is_bot(&author) {
+            continue;
+        }
+        authors.push(author);
+    }
+    Ok(authors)
+}
+
+/// Return the GitHub logins of the latest commits authors, indexed by email.
+fn github_logins(gh_md: &MdRepository) -> HashMap<String, String> {
+    let mut logins = HashMap::new();
+    if let Some(MdRepositoryCommitAuthorsTarget::Commit(commit)) = gh_md
+        .commit_authors
+        .as_ref()
+        .and_then(|r| r.target.as_ref())
+    {
+        for author in commit.history.nodes.iter().flatten().flatten() {
+            if let Some(author) = &author.author
+                && let (Some(email), Some(user)) = (&author.email, &author.user)
+            {
+                logins.insert(email.to_lowercase(), user.login.clone());
+            }
+        }
+    }
+    logins
+}
+
+/// Compute the contributor diversity metrics from the commits authors
+/// provided. Authors are identified by their GitHub login when available, so
+/// that contributors using multiple emails are only counted once.
+fn diversity(authors: &[Author], logins: &HashMap<String, String>) -> ContributorDiversity {
+    let mut commits_per_contributor: HashMap<&str, u64> = HashMap::new();
+    let mut domains: HashSet<String> = HashSet::new();
+    for author in authors {
+        let id = logins.get(&author.email).unwrap_or(&author.email);
+        *commits_per_contributor.entry(id).or_default() += 1;
+        if let Some((_, domain)) = author.email.rsplit_once('@')
+            && !is_public_email_domain(domain)
+        {
+            domains.insert(domain.to_lowercase());
+        }
+    }
+
+    let top_contributor_share = commits_per_contributor
+        .values()
+        .max()
+        .map_or(0, |top| top * 100 / authors.len() as u64);
+    ContributorDiversity {
+        active_contributors: commits_per_contributor.len(),
+        top_contributor_share,
+        email_domains: domains.len(),
+    }
+}
+
+/// Check if the email domain provided is a public or noreply one.
+fn is_public_email_domain(domain: &str) -> bool {
+    PUBLIC_EMAIL_DOMAINS
+        .iter()
+        .any(|public_domain| domain.eq_ignore_ascii_case(public_domain))
+}
+
+/// Check if the author provided is a bot.
+fn is_bot(author: &Author) -> bool {
+    author.name.ends_with("[bot]") || author.email.contains("[bot]@")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::format_err;
+
+    use crate::linter::LinterInput;
+
+    use super::*;
+
+    fn author(name: &str, email: &str) -> Author {
+        Author {
+            name: name.to_string(),
+            email: email.to_string(),
+        }
+    }
+
+    fn setup_repository(path: &Path, authors: &[(&str, &str)]) {
+        let repo = git2::Repository::init(path).unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        for (name, email) in authors {
+            let signature =
+                git2::Signature::new(name, email, &git2::Time::new(now - 3600, 0)).unwrap();
+            let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            repo.commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                "commit",
+                &tree,
+                &parents,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn is_bot_detected() {
+        assert!(is_bot(&author(
+            "dependabot[bot]",
+            "49699333+dependabot[bot]@users.noreply.github.com"
+        )));
+        assert!(!is_bot(&author("user", "user@project.io")));
+    }
+
+    #[test]
+    fn diversity_computed() {
+        let authors = [
+            author("user1", "user1@company1.com"),
+            author("user1", "user1@users.noreply.github.com"),
+            author("user1", "user1@company1.com"),
+            author("user2", "user2@company2.com"),
+        ];
+        let logins = HashMap::from([
+            ("user1@company1.com".to_string(), "user1".to_string()),
+            (
+                "user1@users.noreply.github.com".to_string(),
+                "user1".to_string(),
+            ),
+        ]);
+
+        assert_eq!(
+            diversity(&authors, &logins),
+            ContributorDiversity {
+                active_contributors: 2,
+                top_contributor_share: 75,
+                email_domains: 2,
+            }
+        );
+        assert_eq!(diversity(&[], &logins), ContributorDiversity::default());
+    }
+
+    #[test]
+    fn diversity_ignores_public_email_domains() {
+        let authors = [
+            author("user1", "user1@gmail.com"),
+            author("user2", "user2@Outlook.com"),
+            author("user3", "3+user3@users.noreply.github.com"),
+            author("user4", "user4@company1.com"),
+        ];
+
+        assert_eq!(diversity(&authors, &HashMap::new()).email_domains, 1);
+        assert!(is_public_email_domain("GMAIL.COM"));
+        assert!(!is_public_email_domain("company1.com"));
+    }
+
+    #[test]
+    fn passed_diverse_contributors() {
+        let root = tempfile::tempdir().unwrap();
+        setup_repository(
+            root.path(),
+            &[
+                ("user1", "user1@company1.com"),
+                ("user2", "user2@company2.com"),
+                (
+                    "dependabot[bot]",
+                    "1+dependabot[bot]@users.noreply.github.com",
+                ),
+                ("user3", "user3@company1.com"),
+            ],
+        );
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .unwrap(),
+            CheckOutput::passed().value(Some(ContributorDiversity {
+                active_contributors: 3,
+                top_contributor_share: 33,
+                email_domains: 2,
+            })),
+        );
+    }
+
+    #[test]
+    fn not_passed_single_contributor() {
+        let root = tempfile::tempdir().unwrap();
+        setup_repository(
+            root.path(),
+            &[
+                ("user1", "user1@company1.com"),
+                ("user1", "user1@company1.com"),
+            ],
+        );
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .unwrap(),
+            CheckOutput::not_passed()
+                .value(Some(ContributorDiversity {
+                    active_contributors: 1,
+                    top_contributor_share: 100,
+                    email_domains: 1,
+                }))
+                .details(Some(
+                    "# Contributor diversity issues found
+
+· 1 active contributors in the last 12 months (minimum: 3)
+· Top contributor authored 100% of the commits (maximum: 75%)
+· 1 email domains used by active contributors (minimum: 2)
+"
+                    .to_string()
+                )),
+        );
+    }
+}
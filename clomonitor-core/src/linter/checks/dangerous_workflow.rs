@@ -5,19 +5,29 @@ use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
 };
 
-use super::datasource::scorecard;
-
 /// Check identifier.
 pub(crate) const ID: CheckId = "dangerous_workflow";
 
+/// Check name.
+pub(crate) const NAME: &str = "Dangerous workflow";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "dangerous-workflow-from-openssf-scorecard";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 2;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = false;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Check main function.
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
-    Ok(scorecard::get_check(&input.scorecard, ID).into())
+    Ok(CheckOutput::from_scorecard(&input.scorecard, ID))
 }
@@ -5,11 +5,16 @@ use graphql_client::{GraphQLQuery, Response};
 use regex::{Regex, RegexSet};
 use reqwest::StatusCode;
 
+use crate::linter::checks::util::links::HTTP_CLIENT;
+
 use self::md::*;
 
 /// GitHub GraphQL API URL.
 const GITHUB_GRAPHQL_API: &str = "https://api.github.com/graphql";
 
+/// GitHub raw content base URL.
+const GITHUB_RAW_CONTENT: &str = "https://raw.githubusercontent.com";
+
 static GITHUB_REPO_URL: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new("^https://github.com/(?P<org>[^/]+)/(?P<repo>[^/]+)/?$")
         .expect("exprs in GITHUB_REPO_URL to be valid")
@@ -32,10 +37,12 @@ type DateTime = String;
 pub struct Md;
 
 impl MdRepository {
-    #[cfg(test)]
-    pub(crate) fn default() -> Self {
+    /// Create an empty repository metadata instance, used when the GitHub
+    /// metadata is not needed by any of the checks run.
+    pub(crate) fn empty() -> Self {
         Self {
             code_of_conduct: None,
+            commit_authors: None,
             default_branch_ref: Some(MdRepositoryDefaultBranchRef {
                 name: "master".to_string(),
             }),
@@ -44,6 +51,8 @@ impl MdRepository {
             license_info: None,
             name: String::new(),
             pull_requests: MdRepositoryPullRequests { nodes: None },
+            recent_issues: MdRepositoryRecentIssues { nodes: None },
+            recent_pull_requests: MdRepositoryRecentPullRequests { nodes: None },
             owner: MdRepositoryOwner {
                 login: String::new(),
                 on: MdRepositoryOwnerOn::Organization,
@@ -52,6 +61,11 @@ impl MdRepository {
             security_policy_url: None,
         }
     }
+
+    #[cfg(test)]
+    pub(crate) fn default() -> Self {
+        Self::empty()
+    }
 }
 
 /// Get repository's metadata from the Github GraphQL API.
@@ -108,9 +122,9 @@ pub(crate) fn default_branch(r: Option<&MdRepositoryDefaultBranchRef>) -> String
     }
 }
 
-/// Check if the repo has a check in the latest merged PR that matches any of
-/// the regular expressions provided.
-pub(crate) fn has_check(gh_md: &MdRepository, re: &RegexSet) -> bool {
+/// Find a check in the latest merged PR that matches any of the regular
+/// expressions provided, returning its name when found.
+pub(crate) fn find_check(gh_md: &MdRepository, re: &RegexSet) -> Option<String> {
     // Get latest PR head commit from metadata
     let latest_pr_head_commit = gh_md
         .pull_requests
@@ -129,48 +143,40 @@ pub(crate) fn has_check(gh_md: &MdRepository, re: &RegexSet) -> bool {
         .and_then(|check_suites| check_suites.nodes.as_ref());
 
     // Search in check suites apps name
-    if let Some(true) = check_suites.map(|check_suites| {
-        check_suites.iter().flatten().any(|s| {
-            if let Some(app) = s.app.as_ref() {
-                return re.is_match(&app.name);
-            }
-            false
-        })
-    }) {
-        return true;
+    if let Some(name) = check_suites
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|check_suite| check_suite.app.as_ref())
+        .map(|app| &app.name)
+        .find(|name| re.is_match(name))
+    {
+        return Some(name.clone());
     }
 
     // Search in check suites check runs name
-    if let Some(true) = check_suites.map(|check_suites| {
-        check_suites.iter().flatten().any(|check_suite| {
-            if let Some(match_found) = check_suite
-                .check_runs
-                .as_ref()
-                .and_then(|check_runs| check_runs.nodes.as_ref())
-                .map(|check_runs| {
-                    check_runs
-                        .iter()
-                        .flatten()
-                        .any(|check_run| re.is_match(&check_run.name))
-                })
-            {
-                return match_found;
-            }
-            false
-        })
-    }) {
-        return true;
+    if let Some(name) = check_suites
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|check_suite| check_suite.check_runs.as_ref())
+        .filter_map(|check_runs| check_runs.nodes.as_ref())
+        .flatten()
+        .flatten()
+        .map(|check_run| &check_run.name)
+        .find(|name| re.is_match(name))
+    {
+        return Some(name.clone());
     }
 
     // Search in commit statuses context
-    if let Some(true) = latest_pr_head_commit
+    latest_pr_head_commit
         .and_then(|commit| commit.status.as_ref())
-        .map(|status| status.contexts.iter().any(|c| re.is_match(&c.context)))
-    {
-        return true;
-    }
-
-    false
+        .into_iter()
+        .flat_map(|status| &status.contexts)
+        .map(|c| &c.context)
+        .find(|context| re.is_match(context))
+        .cloned()
 }
 
 /// Check if the given default community health file is available in the
@@ -203,6 +209,34 @@ pub(crate) async fn has_community_health_file(
     }
 }
 
+/// Get the content of the file provided from the .github repository of the
+/// organization (or user) owning the repository, if available.
+pub(crate) async fn org_file(repo_url: &str, file: &str) -> Result<Option<String>> {
+    let Ok((owner, _)) = get_owner_and_repo(repo_url) else {
+        return Ok(None);
+    };
+    let file_raw_url = format!("{GITHUB_RAW_CONTENT}/{owner}/.github/HEAD/{file}");
+    get_raw_file(&file_raw_url).await
+}
+
+/// Get the content of the raw file located at the url provided, returning
+/// None when the file is not found. The shared http client is used, so slow
+/// responses time out instead of blocking the lint.
+async fn get_raw_file(url: &str) -> Result<Option<String>> {
+    let resp = HTTP_CLIENT
+        .get(url)
+        .send()
+        .await
+        .context(format!("error getting file {url}"))?;
+    match resp.status() {
+        StatusCode::OK => Ok(Some(resp.text().await?)),
+        StatusCode::NOT_FOUND => Ok(None),
+        status => Err(format_err!(
+            "unexpected status code getting file {url}: {status}"
+        )),
+    }
+}
+
 /// Get the repository's latest release from the metadata provided.
 pub(crate) fn latest_release(gh_md: &MdRepository) -> Option<&MdRepositoryReleasesNodes> {
     // Return the latest release if it's not a prerelease
@@ -270,6 +304,11 @@ fn path_to_url(path: &Path) -> String {
 
 #[cfg(test)]
 mod tests {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
     use super::*;
 
     #[test]
@@ -314,7 +353,7 @@ mod tests {
     }
 
     #[test]
-    fn has_check_in_check_suite_app_name() {
+    fn find_check_in_check_suite_app_name() {
         let gh_md = MdRepository {
             pull_requests: MdRepositoryPullRequests {
                 nodes: Some(vec![Some(MdRepositoryPullRequestsNodes {
@@ -340,11 +379,14 @@ mod tests {
             ..MdRepository::default()
         };
 
-        assert!(has_check(&gh_md, &RegexSet::new(["dco"]).unwrap()));
+        assert_eq!(
+            find_check(&gh_md, &RegexSet::new(["dco"]).unwrap()),
+            Some("dco".to_string())
+        );
     }
 
     #[test]
-    fn has_check_in_check_suite_check_run_name() {
+    fn find_check_in_check_suite_check_run_name() {
         let gh_md = MdRepository {
             pull_requests: MdRepositoryPullRequests {
                 nodes: Some(vec![Some(MdRepositoryPullRequestsNodes {
@@ -372,11 +414,14 @@ mod tests {
             ..MdRepository::default()
         };
 
-        assert!(has_check(&gh_md, &RegexSet::new(["dco"]).unwrap()));
+        assert_eq!(
+            find_check(&gh_md, &RegexSet::new(["dco"]).unwrap()),
+            Some("dco".to_string())
+        );
     }
 
     #[test]
-    fn has_check_in_commit_status_context() {
+    fn find_check_in_commit_status_context() {
         let gh_md = MdRepository {
             pull_requests: MdRepositoryPullRequests {
                 nodes: Some(vec![Some(MdRepositoryPullRequestsNodes {
@@ -399,7 +444,10 @@ mod tests {
             ..MdRepository::default()
         };
 
-        assert!(has_check(&gh_md, &RegexSet::new(["dco"]).unwrap()));
+        assert_eq!(
+            find_check(&gh_md, &RegexSet::new(["dco"]).unwrap()),
+            Some("dco".to_string())
+        );
     }
 
     #[test]
@@ -503,4 +551,62 @@ mod tests {
     fn get_owner_and_repo_invalid_url() {
         assert!(get_owner_and_repo("https://github.com/org").is_err());
     }
+
+    #[tokio::test]
+    async fn get_raw_file_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/.clomonitor.yml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("exemptions: []"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        assert_eq!(
+            get_raw_file(&format!("{}/.clomonitor.yml", mock_server.uri()))
+                .await
+                .unwrap(),
+            Some("exemptions: []".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn get_raw_file_not_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/.clomonitor.yml"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        assert_eq!(
+            get_raw_file(&format!("{}/.clomonitor.yml", mock_server.uri()))
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn get_raw_file_unexpected_status() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/.clomonitor.yml"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        assert!(
+            get_raw_file(&format!("{}/.clomonitor.yml", mock_server.uri()))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn org_file_invalid_repository_url() {
+        assert_eq!(org_file("", ".clomonitor.yml").await.unwrap(), None);
+    }
 }
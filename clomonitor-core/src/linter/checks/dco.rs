@@ -5,7 +5,7 @@ use regex::{Regex, RegexSet};
 
 use crate::linter::{
     CheckSet,
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
 };
 
 use super::datasource::github;
@@ -13,12 +13,24 @@ use super::datasource::github;
 /// Check identifier.
 pub(crate) const ID: CheckId = "dco";
 
+/// Check name.
+pub(crate) const NAME: &str = "DCO";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "developer-certificate-of-origin";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Maximum number of commits used to check if the repository requires DCO.
 const DCO_MAX_COMMITS: usize = 20;
 
@@ -29,18 +41,28 @@ static CHECK_REF: LazyLock<RegexSet> =
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // DCO signature in commits
-    if let Ok(passed) = commits_have_dco_signature(&input.li.root)
-        && passed
-    {
-        return Ok(CheckOutput::passed());
+    let signature = commits_have_dco_signature(&input.li.root);
+    let signature_evidence = Evidence {
+        source: EvidenceSource::Git,
+        target: "DCO signature in latest commits".to_string(),
+        found: signature.as_ref().is_ok_and(|passed| *passed),
+        detail: signature.err().map(|err| err.to_string()),
+    };
+    if signature_evidence.found {
+        return Ok(CheckOutput::from_evidence(vec![signature_evidence]));
     }
 
     // DCO check in Github
-    if github::has_check(&input.gh_md, &CHECK_REF) {
-        return Ok(CheckOutput::passed());
-    }
-
-    Ok(CheckOutput::not_passed())
+    let check = github::find_check(&input.gh_md, &CHECK_REF);
+    Ok(CheckOutput::from_evidence(vec![
+        signature_evidence,
+        Evidence {
+            source: EvidenceSource::GitHub,
+            target: "DCO check in latest merged PR".to_string(),
+            found: check.is_some(),
+            detail: check,
+        },
+    ]))
 }
 
 /// Check if the last commits on the git repository located in the path
@@ -5,12 +5,24 @@ use crate::linter::{CheckId, CheckOutput, CheckSet, check::CheckInput};
 /// Check identifier.
 pub(crate) const ID: CheckId = "dependencies_policy";
 
+/// Check name.
+pub(crate) const NAME: &str = "Dependencies policy";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "dependencies-policy";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 2;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = false;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = true;
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     if let Some(policy_url) = input
@@ -27,7 +39,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
+    use std::{collections::HashMap, path::Path};
 
     use anyhow::format_err;
 
@@ -50,6 +62,7 @@ mod tests {
                     .canonicalize()
                     .unwrap(),
             ),
+            evidence: HashMap::new(),
         })
         .unwrap();
 
@@ -68,6 +81,7 @@ mod tests {
                     .canonicalize()
                     .unwrap(),
             ),
+            evidence: HashMap::new(),
         })
         .unwrap();
 
@@ -91,6 +105,7 @@ mod tests {
                     .canonicalize()
                     .unwrap(),
             ),
+            evidence: HashMap::new(),
         })
         .unwrap();
 
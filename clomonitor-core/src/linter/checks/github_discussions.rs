@@ -9,12 +9,24 @@ use crate::linter::{
 /// Check identifier.
 pub(crate) const ID: CheckId = "github_discussions";
 
+/// Check name.
+pub(crate) const NAME: &str = "GitHub discussions";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "github-discussions";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 0;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     if let Some(latest_discussion) = input
@@ -35,6 +47,8 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use anyhow::format_err;
 
     use crate::linter::{
@@ -58,6 +72,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -85,6 +100,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -112,6 +128,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::passed().url(Some("discussion_url".to_string())),
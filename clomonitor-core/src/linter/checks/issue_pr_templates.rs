@@ -0,0 +1,290 @@
+use std::{path::Path, sync::LazyLock};
+
+use anyhow::Result;
+use regex::{Regex, RegexSet};
+
+use crate::linter::{
+    CheckSet,
+    check::{CheckId, CheckInput, CheckOutput},
+    datasource::github::{self, md::MdRepository},
+};
+
+use super::util::{
+    fs,
+    path::{self, Globs},
+};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "issue_pr_templates";
+
+/// Check name.
+pub(crate) const NAME: &str = "Issue and PR templates";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "issue-and-pr-templates";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 0;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
+
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
+/// Patterns used to locate issue templates (including YAML issue forms) in the
+/// repository.
+const ISSUE_TEMPLATE_PATTERNS: [&str; 6] = [
+    ".github/ISSUE_TEMPLATE/*.md",
+    ".github/ISSUE_TEMPLATE/*.yml",
+    ".github/ISSUE_TEMPLATE/*.yaml",
+    ".github/issue_template.md",
+    "issue_template.md",
+    "docs/issue_template.md",
+];
+
+/// Patterns used to locate pull request templates in the repository.
+const PR_TEMPLATE_PATTERNS: [&str; 4] = [
+    ".github/pull_request_template.md",
+    ".github/PULL_REQUEST_TEMPLATE/*.md",
+    "pull_request_template.md",
+    "docs/pull_request_template.md",
+];
+
+/// Issue templates files looked for in the organization's .github repository.
+const ORG_ISSUE_TEMPLATE_FILES: [&str; 6] = [
+    "ISSUE_TEMPLATE/config.yml",
+    "ISSUE_TEMPLATE/bug_report.md",
+    "ISSUE_TEMPLATE/bug_report.yml",
+    "ISSUE_TEMPLATE/feature_request.md",
+    "ISSUE_TEMPLATE/feature_request.yml",
+    "ISSUE_TEMPLATE.md",
+];
+
+/// Security issue templates files looked for in the organization's .github
+/// repository.
+const ORG_SECURITY_ISSUE_TEMPLATE_FILES: [&str; 4] = [
+    "ISSUE_TEMPLATE/security.md",
+    "ISSUE_TEMPLATE/security.yml",
+    "ISSUE_TEMPLATE/security_report.md",
+    "ISSUE_TEMPLATE/security_report.yml",
+];
+
+/// Pull request template files looked for in the organization's .github
+/// repository.
+const ORG_PR_TEMPLATE_FILES: [&str; 2] = ["pull_request_template.md", "PULL_REQUEST_TEMPLATE.md"];
+
+static SECURITY_FILE_NAME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(security|vulnerabilit)").expect("exprs in SECURITY_FILE_NAME to be valid")
+});
+
+static SECURITY_TEMPLATE: LazyLock<RegexSet> = LazyLock::new(|| {
+    RegexSet::new([r"(?im)^\s*(name|title|about|description)\s*:.*(security|vulnerabilit)"])
+        .expect("exprs in SECURITY_TEMPLATE to be valid")
+});
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    let root = &input.li.root;
+    let mut issues: Vec<String> = Vec::new();
+
+    // Issue templates in repo
+    let issue_templates: Vec<_> = path::matches(&Globs {
+        root,
+        patterns: &ISSUE_TEMPLATE_PATTERNS,
+        case_sensitive: false,
+    })?
+    .into_iter()
+    .filter(|path| !is_config_file(path))
+    .collect();
+    if issue_templates.is_empty() {
+        // Issue templates in .github repo
+        if has_org_file(&ORG_ISSUE_TEMPLATE_FILES, &input.gh_md).await? {
+            for file in ORG_SECURITY_ISSUE_TEMPLATE_FILES {
+                if let Some(url) = github::has_community_health_file(file, &input.gh_md).await? {
+                    issues.push(security_template_issue(&url));
+                }
+            }
+        } else {
+            issues.push("No issue templates found".to_string());
+        }
+    } else {
+        for path in &issue_templates {
+            if let Ok(content) = fs::read_to_string(path)
+                && is_security_template(path, &content)
+            {
+                let path = path.strip_prefix(root).unwrap_or(path);
+                issues.push(security_template_issue(&path.to_string_lossy()));
+            }
+        }
+    }
+
+    // Pull request template in repo or in .github repo
+    let pr_template = path::find(&Globs {
+        root,
+        patterns: &PR_TEMPLATE_PATTERNS,
+        case_sensitive: false,
+    })?;
+    if pr_template.is_none() && !has_org_file(&ORG_PR_TEMPLATE_FILES, &input.gh_md).await? {
+        issues.push("No pull request template found".to_string());
+    }
+
+    // Return check output
+    if issues.is_empty() {
+        return Ok(CheckOutput::passed());
+    }
+    let details = issues.iter().fold(
+        "# Issue and pull request templates issues found\n\n".to_string(),
+        |details, issue| details + "· " + issue + "\n",
+    );
+    Ok(CheckOutput::not_passed().details(Some(details)))
+}
+
+/// Check if any of the files provided is available in the .github repository.
+async fn has_org_file(files: &[&str], gh_md: &MdRepository) -> Result<bool> {
+    for file in files {
+        if github::has_community_health_file(file, gh_md)
+            .await?
+            .is_some()
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Check if the path provided is the issue template chooser configuration
+/// file, which is not an issue template itself.
+fn is_config_file(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| {
+        let name = name.to_string_lossy().to_lowercase();
+        name == "config.yml" || name == "config.yaml"
+    })
+}
+
+/// Check if the issue template provided is meant to report security issues.
+fn is_security_template(path: &Path, content: &str) -> bool {
+    path.file_name()
+        .is_some_and(|name| SECURITY_FILE_NAME.is_match(&name.to_string_lossy()))
+        || SECURITY_TEMPLATE.is_match(content)
+}
+
+/// Return the issue reported when a security issue template is found.
+fn security_template_issue(location: &str) -> String {
+    format!(
+        "Security issue template found, vulnerabilities should be reported privately instead of in public issues: {location}"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::format_err;
+
+    use crate::linter::LinterInput;
+
+    use super::*;
+
+    #[test]
+    fn security_template_match() {
+        assert!(SECURITY_TEMPLATE.is_match("---\nname: Security vulnerability\nabout: Report"));
+        assert!(SECURITY_TEMPLATE.is_match("name: Report\ndescription: Report a vulnerability"));
+        assert!(SECURITY_TEMPLATE.is_match("title: \"[SECURITY] \""));
+
+        assert!(!SECURITY_TEMPLATE.is_match("name: Bug report\nabout: Create a report"));
+        assert!(!SECURITY_TEMPLATE.is_match("Please do not report security issues here"));
+    }
+
+    #[test]
+    fn is_security_template_by_file_name() {
+        assert!(is_security_template(
+            Path::new(".github/ISSUE_TEMPLATE/security_report.md"),
+            ""
+        ));
+        assert!(!is_security_template(
+            Path::new(".github/ISSUE_TEMPLATE/bug_report.md"),
+            "name: Bug report"
+        ));
+    }
+
+    #[test]
+    fn is_config_file_detected() {
+        assert!(is_config_file(Path::new(
+            ".github/ISSUE_TEMPLATE/config.yml"
+        )));
+        assert!(!is_config_file(Path::new(".github/ISSUE_TEMPLATE/bug.yml")));
+    }
+
+    #[tokio::test]
+    async fn passed_templates_found_in_repo() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join(".github/ISSUE_TEMPLATE")).unwrap();
+        std::fs::write(
+            root.path().join(".github/ISSUE_TEMPLATE/bug_report.yml"),
+            "name: Bug report\ndescription: File a bug report",
+        )
+        .unwrap();
+        std::fs::write(
+            root.path().join(".github/ISSUE_TEMPLATE/config.yml"),
+            "contact_links:\n  - name: Report a security vulnerability\n    url: https://project.io/security",
+        )
+        .unwrap();
+        std::fs::write(root.path().join(".github/pull_request_template.md"), "").unwrap();
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::passed(),
+        );
+    }
+
+    #[tokio::test]
+    async fn not_passed_security_template_found_in_repo() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join(".github/ISSUE_TEMPLATE")).unwrap();
+        std::fs::write(
+            root.path().join(".github/ISSUE_TEMPLATE/report.md"),
+            "---\nname: Vulnerability report\nabout: Report a vulnerability\n---",
+        )
+        .unwrap();
+        std::fs::write(root.path().join(".github/pull_request_template.md"), "").unwrap();
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    root: root.path().to_owned(),
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::not_passed().details(Some(
+                "# Issue and pull request templates issues found
+
+· Security issue template found, vulnerabilities should be reported privately instead of in public issues: .github/ISSUE_TEMPLATE/report.md
+"
+                .to_string()
+            )),
+        );
+    }
+}
@@ -3,17 +3,29 @@ use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
 };
 
-use super::util::helpers::{find_exemption, should_skip_check};
+use super::util::helpers::{find_exemption, find_expired_exemption, should_skip_check};
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "license_approved";
 
+/// Check name.
+pub(crate) const NAME: &str = "Approved";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "approved-license";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 10;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 3] = [CheckSet::Code, CheckSet::CodeLite, CheckSet::Docs];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// CNCF approved licenses.
 /// https://github.com/cncf/foundation/blob/master/allowed-third-party-license-policy.md
 static APPROVED_LICENSES: [&str; 11] = [
@@ -33,21 +45,26 @@ static APPROVED_LICENSES: [&str; 11] = [
 /// Check main function.
 pub(crate) fn check(input: &CheckInput, spdx_id: Option<String>) -> Option<CheckOutput> {
     // Check if this check should be skipped
-    if should_skip_check(ID, &input.li.check_sets) {
+    if should_skip_check(ID, input.li) {
         return None;
     }
 
     // Check if an exemption has been declared for this check
-    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref()) {
+    let check_sets = &input.li.check_sets;
+    if let Some(exemption) = find_exemption(ID, input.cm_md.as_ref(), check_sets) {
         return Some(CheckOutput::from(exemption));
     }
 
     // SPDX id in list of approved licenses
-    if spdx_id.is_some_and(|spdx_id| is_approved(&spdx_id)) {
-        return Some(CheckOutput::passed());
-    }
+    let output = if spdx_id.is_some_and(|spdx_id| is_approved(&spdx_id)) {
+        CheckOutput::passed()
+    } else {
+        CheckOutput::not_passed()
+    };
 
-    Some(CheckOutput::not_passed())
+    // Flag the output when the exemption declared for this check has expired
+    let expired = find_expired_exemption(ID, input.cm_md.as_ref(), check_sets);
+    Some(output.expired_exemption(expired))
 }
 
 /// Check if the license provided is an approved one.
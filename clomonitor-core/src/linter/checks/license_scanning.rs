@@ -8,17 +8,32 @@ use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
 };
 
-use super::util::{content, helpers::readme_globs};
+use super::util::{
+    content,
+    helpers::{readme_globs, readme_root},
+};
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "license_scanning";
 
+/// Check name.
+pub(crate) const NAME: &str = "Scanning";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "license-scanning";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 5;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = false;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 pub(crate) static FOSSA_URL: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r#"(https://app.fossa.(?:io|com)/projects/[^"'\)\s]+)"#)
         .expect("exprs in FOSSA_URL to be valid")
@@ -42,7 +57,10 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     }
 
     // Reference in README file
-    if let Some(url) = content::find(&readme_globs(&input.li.root), &[&FOSSA_URL, &SNYK_URL])? {
+    if let Some(url) = content::find(
+        &readme_globs(&readme_root(input.li)?),
+        &[&FOSSA_URL, &SNYK_URL],
+    )? {
         return Ok(CheckOutput::passed().url(Some(url)));
     }
 
@@ -51,6 +69,8 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use anyhow::format_err;
 
     use crate::linter::{
@@ -70,6 +90,7 @@ mod tests {
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -82,12 +103,15 @@ mod tests {
             check(&CheckInput {
                 li: &LinterInput::default(),
                 cm_md: Some(Metadata {
+                    evidence: None,
                     exemptions: None,
+                    gates: None,
                     license_scanning: None,
                 }),
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -100,7 +124,9 @@ mod tests {
             check(&CheckInput {
                 li: &LinterInput::default(),
                 cm_md: Some(Metadata {
+                    evidence: None,
                     exemptions: None,
+                    gates: None,
                     license_scanning: Some(LicenseScanning {
                         url: Some("license_scanning_url".to_string()),
                     }),
@@ -108,6 +134,7 @@ mod tests {
                 gh_md: MdRepository::default(),
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::passed().url(Some("license_scanning_url".to_string())),
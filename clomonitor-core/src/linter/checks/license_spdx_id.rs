@@ -3,8 +3,8 @@ use std::sync::LazyLock;
 use anyhow::Result;
 use askalono::*;
 
-use crate::linter::check::{CheckId, CheckInput, CheckOutput};
-use crate::linter::checks::util::path;
+use crate::linter::check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource};
+use crate::linter::checks::util::{helpers::project_dir, path};
 use crate::linter::{CheckSet, util};
 
 use super::util::path::Globs;
@@ -12,12 +12,24 @@ use super::util::path::Globs;
 /// Check identifier.
 pub(crate) const ID: CheckId = "license_spdx_id";
 
+/// Check name.
+pub(crate) const NAME: &str = "SPDX id";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "spdx-id";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 5;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 3] = [CheckSet::Code, CheckSet::CodeLite, CheckSet::Docs];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// SPDX licenses data. Used to detect license used by repositories.
 const LICENSES_DATA: &[u8] = include_bytes!("licenses/licenses.bin.zstd");
 
@@ -27,26 +39,35 @@ pub(crate) const FILE_PATTERNS: [&str; 2] = ["LICENSE*", "COPYING*"];
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput<String>> {
     // File in repo
-    if let Some(spdx_id) = detect(&Globs {
-        root: &input.li.root,
+    let spdx_id = detect(&Globs {
+        root: &project_dir(input.li, &FILE_PATTERNS, true)?,
         patterns: &FILE_PATTERNS,
         case_sensitive: true,
-    })? {
-        return Ok(CheckOutput::passed().value(Some(spdx_id)));
+    })?;
+    let file_evidence = Evidence {
+        source: EvidenceSource::File,
+        target: FILE_PATTERNS.join(", "),
+        found: spdx_id.is_some(),
+        detail: spdx_id.clone(),
+    };
+    if spdx_id.is_some() {
+        return Ok(CheckOutput::from_evidence(vec![file_evidence]).value(spdx_id));
     }
 
     // License detected by Github
-    if let Some(spdx_id) = input
+    let spdx_id = input
         .gh_md
         .license_info
         .as_ref()
-        .and_then(|l| l.spdx_id.as_ref())
-        && spdx_id != "NOASSERTION"
-    {
-        return Ok(CheckOutput::passed().value(Some(spdx_id.clone())));
-    }
-
-    Ok(CheckOutput::not_passed())
+        .and_then(|l| l.spdx_id.clone())
+        .filter(|spdx_id| spdx_id != "NOASSERTION");
+    let github_evidence = Evidence {
+        source: EvidenceSource::GitHub,
+        target: "license detected".to_string(),
+        found: spdx_id.is_some(),
+        detail: spdx_id.clone(),
+    };
+    Ok(CheckOutput::from_evidence(vec![file_evidence, github_evidence]).value(spdx_id))
 }
 
 /// Detect repository's license and return its SPDX id if possible.
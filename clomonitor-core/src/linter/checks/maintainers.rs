@@ -13,12 +13,24 @@ use super::util::helpers::find_file_or_readme_ref;
 /// Check identifier.
 pub(crate) const ID: CheckId = "maintainers";
 
+/// Check name.
+pub(crate) const NAME: &str = "Maintainers";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "maintainers";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 3;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Patterns used to locate a file in the repository.
 pub(crate) static FILE_PATTERNS: [&str; 7] = [
     "maintainers*",
@@ -30,7 +42,7 @@ pub(crate) static FILE_PATTERNS: [&str; 7] = [
     "docs/codeowners*",
 ];
 
-static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
+pub(crate) static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?im)^#+.*maintainers.*$",
         r"(?im)^maintainers$",
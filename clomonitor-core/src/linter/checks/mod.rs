@@ -6,12 +6,15 @@ pub(crate) mod adopters;
 pub(crate) mod analytics;
 pub(crate) mod artifacthub_badge;
 pub(crate) mod binary_artifacts;
+pub(crate) mod broken_links;
 pub(crate) mod changelog;
 pub(crate) mod cla;
 pub(crate) mod code_of_conduct;
 pub(crate) mod code_review;
+pub(crate) mod community_chat;
 pub(crate) mod community_meeting;
 pub(crate) mod contributing;
+pub(crate) mod contributor_diversity;
 pub(crate) mod dangerous_workflow;
 pub(crate) mod datasource;
 pub(crate) mod dco;
@@ -19,6 +22,7 @@ pub(crate) mod dependencies_policy;
 pub(crate) mod dependency_update_tool;
 pub(crate) mod github_discussions;
 pub(crate) mod governance;
+pub(crate) mod issue_pr_templates;
 pub(crate) mod license_approved;
 pub(crate) mod license_scanning;
 pub(crate) mod license_spdx_id;
@@ -28,6 +32,7 @@ pub(crate) mod openssf_badge;
 pub(crate) mod openssf_scorecard_badge;
 pub(crate) mod readme;
 pub(crate) mod recent_release;
+pub(crate) mod responsiveness;
 pub(crate) mod roadmap;
 pub(crate) mod sbom;
 pub(crate) mod security_insights;
@@ -39,6 +44,7 @@ pub(crate) mod token_permissions;
 pub(crate) mod trademark_disclaimer;
 pub(crate) mod util;
 pub(crate) mod website;
+pub(crate) mod website_hygiene;
 
 pub(crate) static CHECKS: LazyLock<HashMap<CheckId, CheckConfig>> = LazyLock::new(|| {
     let mut checks = HashMap::new();
@@ -48,9 +54,13 @@ pub(crate) static CHECKS: LazyLock<HashMap<CheckId, CheckConfig>> = LazyLock::ne
             checks.insert(
                 $check::ID,
                 CheckConfig {
+                    name: $check::NAME,
+                    docs_anchor: $check::DOCS_ANCHOR,
                     weight: $check::WEIGHT,
                     check_sets: $check::CHECK_SETS.to_vec(),
                     scorecard_name: None,
+                    needs_github_md: $check::NEEDS_GITHUB_MD,
+                    needs_security_insights: $check::NEEDS_SECURITY_INSIGHTS,
                 },
             );
         };
@@ -58,9 +68,13 @@ pub(crate) static CHECKS: LazyLock<HashMap<CheckId, CheckConfig>> = LazyLock::ne
             checks.insert(
                 $check::ID,
                 CheckConfig {
+                    name: $check::NAME,
+                    docs_anchor: $check::DOCS_ANCHOR,
                     weight: $check::WEIGHT,
                     check_sets: $check::CHECK_SETS.to_vec(),
                     scorecard_name: Some($scorecard_name.to_string()),
+                    needs_github_md: $check::NEEDS_GITHUB_MD,
+                    needs_security_insights: $check::NEEDS_SECURITY_INSIGHTS,
                 },
             );
         };
@@ -70,18 +84,22 @@ pub(crate) static CHECKS: LazyLock<HashMap<CheckId, CheckConfig>> = LazyLock::ne
     register_check!(analytics);
     register_check!(artifacthub_badge);
     register_check!(binary_artifacts, "Binary-Artifacts");
+    register_check!(broken_links);
     register_check!(changelog);
     register_check!(cla);
     register_check!(code_of_conduct);
     register_check!(code_review, "Code-Review");
+    register_check!(community_chat);
     register_check!(community_meeting);
     register_check!(contributing);
+    register_check!(contributor_diversity);
     register_check!(dangerous_workflow, "Dangerous-Workflow");
     register_check!(dco);
     register_check!(dependencies_policy);
     register_check!(dependency_update_tool, "Dependency-Update-Tool");
     register_check!(github_discussions);
     register_check!(governance);
+    register_check!(issue_pr_templates);
     register_check!(license_approved);
     register_check!(license_scanning);
     register_check!(license_spdx_id);
@@ -91,6 +109,7 @@ pub(crate) static CHECKS: LazyLock<HashMap<CheckId, CheckConfig>> = LazyLock::ne
     register_check!(openssf_scorecard_badge);
     register_check!(readme);
     register_check!(recent_release);
+    register_check!(responsiveness);
     register_check!(roadmap);
     register_check!(sbom);
     register_check!(security_insights);
@@ -101,6 +120,7 @@ pub(crate) static CHECKS: LazyLock<HashMap<CheckId, CheckConfig>> = LazyLock::ne
     register_check!(token_permissions, "Token-Permissions");
     register_check!(trademark_disclaimer);
     register_check!(website);
+    register_check!(website_hygiene);
 
     checks
 });
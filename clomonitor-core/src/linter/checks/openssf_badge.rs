@@ -8,17 +8,29 @@ use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
 };
 
-use super::util::helpers::readme_capture;
+use super::util::helpers::{readme_capture_evidence, readme_root};
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "openssf_badge";
 
+/// Check name.
+pub(crate) const NAME: &str = "OpenSSF best practices badge";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "openssf-badge";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 5;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = false;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 static OPENSSF_URL: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"(https://www.bestpractices.dev/projects/\d+)")
         .expect("exprs in OPENSSF_URL to be valid")
@@ -32,11 +44,15 @@ static OPENSSF_URL_LEGACY: LazyLock<Regex> = LazyLock::new(|| {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Reference in README file
-    if let Some(url) = readme_capture(&input.li.root, &[&OPENSSF_URL, &OPENSSF_URL_LEGACY])? {
-        return Ok(CheckOutput::passed().url(Some(url)));
+    let (url, evidence) = readme_capture_evidence(
+        &readme_root(input.li)?,
+        &[&OPENSSF_URL, &OPENSSF_URL_LEGACY],
+    )?;
+    if url.is_some() {
+        return Ok(CheckOutput::passed().url(url).evidence(vec![evidence]));
     }
 
-    Ok(CheckOutput::not_passed())
+    Ok(CheckOutput::not_passed().evidence(vec![evidence]))
 }
 
 #[cfg(test)]
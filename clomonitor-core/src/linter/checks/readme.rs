@@ -7,14 +7,17 @@ use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
 };
 
-use super::{
-    datasource::github,
-    util::{helpers::readme_globs, path},
-};
+use super::{datasource::github, util::helpers::find_project_file_evidence};
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "readme";
 
+/// Check name.
+pub(crate) const NAME: &str = "Readme";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "readme";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 10;
 
@@ -26,21 +29,30 @@ pub(crate) const CHECK_SETS: [CheckSet; 4] = [
     CheckSet::Docs,
 ];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Patterns used to locate a file in the repository.
 pub(crate) static FILE_PATTERNS: [&str; 3] = ["README*", ".github/README*", "docs/README*"];
 
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo
-    if let Some(path) = path::find(&readme_globs(&input.li.root))? {
+    let (path, evidence) = find_project_file_evidence(input.li, &FILE_PATTERNS, true)?;
+    if let Some(path) = path {
         let url = github::build_url(
             &path,
             &input.gh_md.owner.login,
             &input.gh_md.name,
             &github::default_branch(input.gh_md.default_branch_ref.as_ref()),
         );
-        return Ok(CheckOutput::passed().url(Some(url)));
+        return Ok(CheckOutput::passed()
+            .url(Some(url))
+            .evidence(vec![evidence]));
     }
 
-    Ok(CheckOutput::not_passed())
+    Ok(CheckOutput::not_passed().evidence(vec![evidence]))
 }
@@ -11,12 +11,24 @@ use super::datasource::github;
 /// Check identifier.
 pub(crate) const ID: CheckId = "recent_release";
 
+/// Check name.
+pub(crate) const NAME: &str = "Recent release";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "recent-release";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 3;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::CodeLite];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Recent release (< 1 year old) in GitHub
@@ -33,6 +45,8 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use anyhow::format_err;
 
     use crate::linter::{
@@ -56,6 +70,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -87,6 +102,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::not_passed(),
@@ -118,6 +134,7 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
             CheckOutput::passed().url(Some("release_url".to_string())),
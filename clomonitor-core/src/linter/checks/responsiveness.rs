@@ -0,0 +1,457 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+use crate::linter::{
+    CheckSet,
+    check::{CheckId, CheckInput, CheckOutput},
+    datasource::github::md::*,
+};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "responsiveness";
+
+/// Check name.
+pub(crate) const NAME: &str = "Responsiveness";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "responsiveness";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 0;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
+
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
+/// Responsiveness metrics computed from the latest issues and pull requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Responsiveness {
+    pub issues: ResponsivenessStats,
+    pub pull_requests: ResponsivenessStats,
+}
+
+/// Responsiveness metrics for a set of issues or pull requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponsivenessStats {
+    /// Number of items analyzed.
+    pub analyzed: usize,
+
+    /// Median time to first response, in hours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_response_median_hours: Option<u64>,
+
+    /// Median time to close, in hours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_median_hours: Option<u64>,
+}
+
+/// Issue or pull request activity used to compute the responsiveness metrics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Item {
+    created: OffsetDateTime,
+    closed: Option<OffsetDateTime>,
+    first_response: Option<OffsetDateTime>,
+}
+
+impl Item {
+    /// Create a new item instance from the timestamps provided.
+    fn new<'a>(
+        created_at: &str,
+        closed_at: Option<&str>,
+        responses: impl Iterator<Item = &'a str>,
+    ) -> Result<Self> {
+        let mut first_response_at: Option<OffsetDateTime> = None;
+        for response in responses {
+            let response_at = OffsetDateTime::parse(response, &Rfc3339)?;
+            if first_response_at.is_none_or(|first| response_at < first) {
+                first_response_at = Some(response_at);
+            }
+        }
+        Ok(Self {
+            created: OffsetDateTime::parse(created_at, &Rfc3339)?,
+            closed: closed_at
+                .map(|closed_at| OffsetDateTime::parse(closed_at, &Rfc3339))
+                .transpose()?,
+            first_response: first_response_at,
+        })
+    }
+}
+
+/// Check main function.
+pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput<Responsiveness>> {
+    // Compute metrics from the latest issues and pull requests in GitHub
+    let now = OffsetDateTime::now_utc();
+    let value = Responsiveness {
+        issues: stats(&issues(&input.gh_md)?, now),
+        pull_requests: stats(&pull_requests(&input.gh_md)?, now),
+    };
+    if value.issues.analyzed == 0 && value.pull_requests.analyzed == 0 {
+        return Ok(CheckOutput::not_passed());
+    }
+
+    // Verify metrics against the SLOs
+    let slo = &input.li.responsiveness_slo;
+    let mut issues: Vec<String> = Vec::new();
+    for (kind, stats) in [
+        ("issues", &value.issues),
+        ("pull requests", &value.pull_requests),
+    ] {
+        if let Some(hours) = stats.first_response_median_hours
+            && hours > slo.first_response_hours
+        {
+            issues.push(format!(
+                "Median time to first response in {kind} is {hours}h (SLO: {}h)",
+                slo.first_response_hours
+            ));
+        }
+        if let Some(hours) = stats.close_median_hours
+            && hours > slo.close_hours
+        {
+            issues.push(format!(
+                "Median time to close {kind} is {hours}h (SLO: {}h)",
+                slo.close_hours
+            ));
+        }
+    }
+
+    // Return check output
+    if issues.is_empty() {
+        return Ok(CheckOutput::passed().value(Some(value)));
+    }
+    let details = issues.iter().fold(
+        "# Responsiveness SLOs not met\n\n".to_string(),
+        |details, issue| details + "· " + issue + "\n",
+    );
+    Ok(CheckOutput::not_passed()
+        .value(Some(value))
+        .details(Some(details)))
+}
+
+/// Return the latest issues from the metadata provided. Issues opened by bots
+/// are ignored, as well as comments from bots or from the issue's author.
+fn issues(gh_md: &MdRepository) -> Result<Vec<Item>> {
+    let mut items = Vec::new();
+    for issue in gh_md.recent_issues.nodes.iter().flatten().flatten() {
+        if issue
+            .author
+            .as_ref()
+            .is_some_and(|a| matches!(a.on, MdRepositoryRecentIssuesNodesAuthorOn::Bot))
+        {
+            continue;
+        }
+        let author = issue.author.as_ref().map(|a| a.login.as_str());
+        let responses = issue
+            .comments
+            .nodes
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|c| {
+                c.author.as_ref().is_some_and(|a| {
+                    !matches!(
+                        a.on,
+                        MdRepositoryRecentIssuesNodesCommentsNodesAuthorOn::Bot
+                    ) && Some(a.login.as_str()) != author
+                })
+            })
+            .map(|c| c.created_at.as_str());
+        items.push(Item::new(
+            &issue.created_at,
+            issue.closed_at.as_deref(),
+            responses,
+        )?);
+    }
+    Ok(items)
+}
+
+/// Return the latest pull requests from the metadata provided. Pull requests
+/// opened by bots are ignored, as well as comments and reviews from bots or
+/// from the pull request's author.
+fn pull_requests(gh_md: &MdRepository) -> Result<Vec<Item>> {
+    let mut items = Vec::new();
+    for pr in gh_md.recent_pull_requests.nodes.iter().flatten().flatten() {
+        if pr
+            .author
+            .as_ref()
+            .is_some_and(|a| matches!(a.on, MdRepositoryRecentPullRequestsNodesAuthorOn::Bot))
+        {
+            continue;
+        }
+        let author = pr.author.as_ref().map(|a| a.login.as_str());
+        let comments = pr
+            .comments
+            .nodes
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|c| {
+                c.author.as_ref().is_some_and(|a| {
+                    !matches!(
+                        a.on,
+                        MdRepositoryRecentPullRequestsNodesCommentsNodesAuthorOn::Bot
+                    ) && Some(a.login.as_str()) != author
+                })
+            })
+            .map(|c| c.created_at.as_str());
+        let reviews = pr
+            .reviews
+            .iter()
+            .flat_map(|r| r.nodes.iter().flatten().flatten())
+            .filter(|r| {
+                r.author.as_ref().is_some_and(|a| {
+                    !matches!(
+                        a.on,
+                        MdRepositoryRecentPullRequestsNodesReviewsNodesAuthorOn::Bot
+                    ) && Some(a.login.as_str()) != author
+                })
+            })
+            .map(|r| r.created_at.as_str());
+        items.push(Item::new(
+            &pr.created_at,
+            pr.closed_at.as_deref(),
+            comments.chain(reviews),
+        )?);
+    }
+    Ok(items)
+}
+
+/// Compute the responsiveness stats for the items provided. Open items that
+/// haven't got a response yet count as waiting since they were created.
+fn stats(items: &[Item], now: OffsetDateTime) -> ResponsivenessStats {
+    let hours = |from: OffsetDateTime, to: OffsetDateTime| -> u64 {
+        u64::try_from((to - from).whole_hours()).unwrap_or_default()
+    };
+
+    let first_response: Vec<u64> = items
+        .iter()
+        .filter_map(|item| match (item.first_response, item.closed) {
+            (Some(first_response_at), _) => Some(hours(item.created, first_response_at)),
+            (None, None) => Some(hours(item.created, now)),
+            (None, Some(_)) => None,
+        })
+        .collect();
+    let close: Vec<u64> = items
+        .iter()
+        .filter_map(|item| item.closed.map(|closed_at| hours(item.created, closed_at)))
+        .collect();
+
+    ResponsivenessStats {
+        analyzed: items.len(),
+        first_response_median_hours: median(first_response),
+        close_median_hours: median(close),
+    }
+}
+
+/// Return the median of the values provided.
+fn median(mut values: Vec<u64>) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        Some(u64::midpoint(values[mid - 1], values[mid]))
+    } else {
+        Some(values[mid])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::format_err;
+    use time::macros::datetime;
+
+    use crate::linter::{LinterInput, ResponsivenessSlo};
+
+    use super::*;
+
+    fn issue(
+        author: &str,
+        created_at: &str,
+        closed_at: Option<&str>,
+        comments: &[(&str, &str)],
+    ) -> MdRepositoryRecentIssuesNodes {
+        MdRepositoryRecentIssuesNodes {
+            author: Some(MdRepositoryRecentIssuesNodesAuthor {
+                login: author.to_string(),
+                on: MdRepositoryRecentIssuesNodesAuthorOn::User,
+            }),
+            closed_at: closed_at.map(ToString::to_string),
+            comments: MdRepositoryRecentIssuesNodesComments {
+                nodes: Some(
+                    comments
+                        .iter()
+                        .map(|(author, created_at)| {
+                            Some(MdRepositoryRecentIssuesNodesCommentsNodes {
+                                author: Some(MdRepositoryRecentIssuesNodesCommentsNodesAuthor {
+                                    login: (*author).to_string(),
+                                    on: MdRepositoryRecentIssuesNodesCommentsNodesAuthorOn::User,
+                                }),
+                                created_at: (*created_at).to_string(),
+                            })
+                        })
+                        .collect(),
+                ),
+            },
+            created_at: created_at.to_string(),
+        }
+    }
+
+    #[test]
+    fn median_computed() {
+        assert_eq!(median(vec![]), None);
+        assert_eq!(median(vec![5]), Some(5));
+        assert_eq!(median(vec![9, 1, 5]), Some(5));
+        assert_eq!(median(vec![8, 2, 4, 10]), Some(6));
+    }
+
+    #[test]
+    fn stats_computed() {
+        let now = datetime!(2024-01-10 0:00 UTC);
+        let items = vec![
+            // Answered after 2h, closed after 24h
+            Item {
+                created: datetime!(2024-01-01 0:00 UTC),
+                closed: Some(datetime!(2024-01-02 0:00 UTC)),
+                first_response: Some(datetime!(2024-01-01 2:00 UTC)),
+            },
+            // Closed after 1h without a response (ignored for first response)
+            Item {
+                created: datetime!(2024-01-01 0:00 UTC),
+                closed: Some(datetime!(2024-01-01 1:00 UTC)),
+                first_response: None,
+            },
+            // Still waiting for a response after 48h
+            Item {
+                created: datetime!(2024-01-08 0:00 UTC),
+                closed: None,
+                first_response: None,
+            },
+        ];
+
+        assert_eq!(
+            stats(&items, now),
+            ResponsivenessStats {
+                analyzed: 3,
+                first_response_median_hours: Some(25),
+                close_median_hours: Some(12),
+            }
+        );
+    }
+
+    #[test]
+    fn not_passed_no_issues_or_prs_found() {
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput::default(),
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .unwrap(),
+            CheckOutput::not_passed(),
+        );
+    }
+
+    #[test]
+    fn passed_slos_met() {
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput::default(),
+                cm_md: None,
+                gh_md: MdRepository {
+                    recent_issues: MdRepositoryRecentIssues {
+                        nodes: Some(vec![
+                            Some(issue(
+                                "user1",
+                                "2024-01-01T00:00:00Z",
+                                Some("2024-01-03T00:00:00Z"),
+                                &[
+                                    ("user1", "2024-01-01T01:00:00Z"),
+                                    ("maintainer", "2024-01-01T04:00:00Z"),
+                                ],
+                            )),
+                            Some(issue(
+                                "user2",
+                                "2024-01-01T00:00:00Z",
+                                Some("2024-01-01T12:00:00Z"),
+                                &[("maintainer", "2024-01-01T10:00:00Z")],
+                            )),
+                        ]),
+                    },
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .unwrap(),
+            CheckOutput::passed().value(Some(Responsiveness {
+                issues: ResponsivenessStats {
+                    analyzed: 2,
+                    first_response_median_hours: Some(7),
+                    close_median_hours: Some(30),
+                },
+                pull_requests: ResponsivenessStats::default(),
+            })),
+        );
+    }
+
+    #[test]
+    fn not_passed_slos_not_met() {
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput {
+                    responsiveness_slo: ResponsivenessSlo {
+                        first_response_hours: 2,
+                        close_hours: 24,
+                    },
+                    ..LinterInput::default()
+                },
+                cm_md: None,
+                gh_md: MdRepository {
+                    recent_issues: MdRepositoryRecentIssues {
+                        nodes: Some(vec![Some(issue(
+                            "user1",
+                            "2024-01-01T00:00:00Z",
+                            Some("2024-01-03T00:00:00Z"),
+                            &[("maintainer", "2024-01-01T04:00:00Z")],
+                        ))]),
+                    },
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .unwrap(),
+            CheckOutput::not_passed()
+                .value(Some(Responsiveness {
+                    issues: ResponsivenessStats {
+                        analyzed: 1,
+                        first_response_median_hours: Some(4),
+                        close_median_hours: Some(48),
+                    },
+                    pull_requests: ResponsivenessStats::default(),
+                }))
+                .details(Some(
+                    "# Responsiveness SLOs not met
+
+· Median time to first response in issues is 4h (SLO: 2h)
+· Median time to close issues is 48h (SLO: 24h)
+"
+                    .to_string()
+                )),
+        );
+    }
+}
@@ -5,21 +5,33 @@ use regex::RegexSet;
 
 use crate::linter::{
     CheckSet,
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
 };
 
-use super::{datasource::github, util::helpers::readme_matches};
+use super::{datasource::github, util::helpers::find_readme_ref};
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "sbom";
 
+/// Check name.
+pub(crate) const NAME: &str = "SBOM";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "software-bill-of-materials-sbom";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
-static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
+pub(crate) static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?im)^#+.*sbom.*$",
         r"(?im)^#+.*software bill of materials.*$",
@@ -35,28 +47,35 @@ static RELEASE_REF: LazyLock<RegexSet> =
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Asset in last release
-    if let Some(true) = github::latest_release(&input.gh_md)
+    let asset = github::latest_release(&input.gh_md)
         .and_then(|r| r.release_assets.nodes.as_ref())
-        .map(|assets| {
+        .and_then(|assets| {
             assets
                 .iter()
                 .flatten()
-                .any(|asset| RELEASE_REF.is_match(&asset.name))
+                .find(|asset| RELEASE_REF.is_match(&asset.name))
         })
-    {
-        return Ok(CheckOutput::passed());
+        .map(|asset| asset.name.clone());
+    let asset_evidence = Evidence {
+        source: EvidenceSource::GitHub,
+        target: "SBOM asset in latest release".to_string(),
+        found: asset.is_some(),
+        detail: asset,
+    };
+    if asset_evidence.found {
+        return Ok(CheckOutput::passed().evidence(vec![asset_evidence]));
     }
 
     // Reference in README file
-    if readme_matches(&input.li.root, &README_REF)? {
-        return Ok(CheckOutput::passed());
-    }
-
-    Ok(CheckOutput::not_passed())
+    let mut evidence = vec![asset_evidence];
+    evidence.extend(find_readme_ref(input, &README_REF)?.evidence);
+    Ok(CheckOutput::from_evidence(evidence))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::{collections::HashMap, iter};
+
     use anyhow::format_err;
 
     use crate::linter::{
@@ -69,6 +88,15 @@ mod tests {
 
     use super::*;
 
+    fn readme_ref_not_found() -> impl Iterator<Item = Evidence> {
+        README_REF.patterns().iter().map(|pattern| Evidence {
+            source: EvidenceSource::Readme,
+            target: pattern.clone(),
+            found: false,
+            detail: None,
+        })
+    }
+
     #[test]
     fn not_passed_no_release_found() {
         assert_eq!(
@@ -80,9 +108,19 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
-            CheckOutput::not_passed(),
+            CheckOutput::not_passed().evidence(
+                iter::once(Evidence {
+                    source: EvidenceSource::GitHub,
+                    target: "SBOM asset in latest release".to_string(),
+                    found: false,
+                    detail: None,
+                })
+                .chain(readme_ref_not_found())
+                .collect()
+            ),
         );
     }
 
@@ -113,9 +151,19 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
-            CheckOutput::not_passed(),
+            CheckOutput::not_passed().evidence(
+                iter::once(Evidence {
+                    source: EvidenceSource::GitHub,
+                    target: "SBOM asset in latest release".to_string(),
+                    found: false,
+                    detail: None,
+                })
+                .chain(readme_ref_not_found())
+                .collect()
+            ),
         );
     }
 
@@ -146,9 +194,15 @@ mod tests {
                 },
                 scorecard: Err(format_err!("no scorecard available")),
                 security_insights: Ok(None),
+                evidence: HashMap::new(),
             })
             .unwrap(),
-            CheckOutput::passed(),
+            CheckOutput::passed().evidence(vec![Evidence {
+                source: EvidenceSource::GitHub,
+                target: "SBOM asset in latest release".to_string(),
+                found: true,
+                detail: Some("test_sbom.spdx.json".to_string()),
+            }]),
         );
     }
 
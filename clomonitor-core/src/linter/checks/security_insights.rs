@@ -7,12 +7,24 @@ use super::datasource::github;
 /// Check identifier.
 pub(crate) const ID: CheckId = "security_insights";
 
+/// Check name.
+pub(crate) const NAME: &str = "Security insights";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "security-insights";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Code];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = true;
+
 /// Check main function.
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
@@ -37,7 +49,7 @@ pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
 
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
+    use std::{collections::HashMap, path::Path};
 
     use anyhow::format_err;
 
@@ -60,6 +72,7 @@ mod tests {
                     .canonicalize()
                     .unwrap(),
             ),
+            evidence: HashMap::new(),
         })
         .unwrap();
 
@@ -83,6 +96,7 @@ mod tests {
                     .canonicalize()
                     .unwrap(),
             ),
+            evidence: HashMap::new(),
         })
         .unwrap();
 
@@ -106,6 +120,7 @@ mod tests {
                     .canonicalize()
                     .unwrap(),
             ),
+            evidence: HashMap::new(),
         })
         .unwrap();
 
@@ -5,7 +5,7 @@ use regex::RegexSet;
 
 use crate::linter::{
     CheckSet,
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
 };
 
 use super::util::helpers::find_file_or_readme_ref;
@@ -13,16 +13,28 @@ use super::util::helpers::find_file_or_readme_ref;
 /// Check identifier.
 pub(crate) const ID: CheckId = "security_policy";
 
+/// Check name.
+pub(crate) const NAME: &str = "Security policy";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "security-policy";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 3;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 2] = [CheckSet::Code, CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Patterns used to locate a file in the repository.
-static FILE_PATTERNS: [&str; 3] = ["security*", ".github/security*", "docs/security*"];
+pub(crate) static FILE_PATTERNS: [&str; 3] = ["security*", ".github/security*", "docs/security*"];
 
-static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
+pub(crate) static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?im)^#+.*security.*$",
         r"(?im)^security$",
@@ -34,17 +46,20 @@ static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // File in repo or reference in README file
-    let output = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
+    let mut output = find_file_or_readme_ref(input, &FILE_PATTERNS, &README_REF)?;
     if output.passed {
         return Ok(output);
     }
 
     // File in Github (default community health file, for example)
-    if let Some(url) = input.gh_md.security_policy_url.as_ref() {
-        return Ok(CheckOutput::passed().url(Some(url.clone())));
-    }
-
-    Ok(CheckOutput::not_passed())
+    let url = input.gh_md.security_policy_url.clone();
+    output.evidence.push(Evidence {
+        source: EvidenceSource::GitHub,
+        target: "security policy url".to_string(),
+        found: url.is_some(),
+        detail: url.clone(),
+    });
+    Ok(CheckOutput::from_evidence(output.evidence).url(url))
 }
 
 #[cfg(test)]
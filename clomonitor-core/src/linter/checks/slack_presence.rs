@@ -8,18 +8,30 @@ use crate::linter::{
     check::{CheckId, CheckInput, CheckOutput},
 };
 
-use super::util::helpers::readme_matches;
+use super::util::helpers::find_readme_ref;
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "slack_presence";
 
+/// Check name.
+pub(crate) const NAME: &str = "Slack presence";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "slack-presence";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 0;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
-static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = false;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
+pub(crate) static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"(?i)https?://cloud-native.slack.com",
         r"(?i)https?://slack.cncf.io",
@@ -32,11 +44,7 @@ static README_REF: LazyLock<RegexSet> = LazyLock::new(|| {
 /// Check main function.
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Reference in README file
-    if readme_matches(&input.li.root, &README_REF)? {
-        return Ok(CheckOutput::passed());
-    }
-
-    Ok(CheckOutput::not_passed())
+    find_readme_ref(input, &README_REF)
 }
 
 #[cfg(test)]
@@ -10,12 +10,24 @@ use super::datasource::landscape;
 /// Check identifier.
 pub(crate) const ID: CheckId = "summary_table";
 
+/// Check name.
+pub(crate) const NAME: &str = "Summary table";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "summary-table";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 1;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = false;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Check main function.
 pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
     // Get landscape (if necessary info is available)
@@ -13,12 +13,24 @@ use super::util::content;
 /// Check identifier.
 pub(crate) const ID: CheckId = "trademark_disclaimer";
 
+/// Check name.
+pub(crate) const NAME: &str = "Trademark disclaimer";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "trademark-disclaimer";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 5;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 pub(crate) static TRADEMARK_DISCLAIMER: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new([
         r"https://(?:w{3}\.)?linuxfoundation.org/(?:legal/)?trademark-usage",
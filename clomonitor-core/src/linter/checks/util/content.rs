@@ -22,17 +22,6 @@ pub(crate) fn find(globs: &Globs, regexps: &[&Regex]) -> Result<Option<String>>
     Ok(None)
 }
 
-/// Check if the content of any of the files that match the globs provided
-/// matches any of the regular expressions given.
-pub(crate) fn matches(globs: &Globs, re: &RegexSet) -> Result<bool> {
-    Ok(path::matches(globs)?.iter().any(|path| {
-        if let Ok(content) = super::fs::read_to_string(path) {
-            return re.is_match(&content);
-        }
-        false
-    }))
-}
-
 /// Check if the content of the url provided matches any of the regular
 /// expressions given.
 pub(crate) async fn remote_matches(url: &str, re: &RegexSet) -> Result<bool> {
@@ -117,66 +106,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn matches_match() {
-        assert!(
-            matches(
-                &Globs {
-                    root: Path::new(TESTDATA_PATH),
-                    patterns: &["README*"],
-                    case_sensitive: true,
-                },
-                &RegexSet::new([r"(?im)^#+.*adopters.*$"]).unwrap(),
-            )
-            .unwrap()
-        );
-    }
-
-    #[test]
-    fn matches_no_match() {
-        assert!(
-            !matches(
-                &Globs {
-                    root: Path::new(TESTDATA_PATH),
-                    patterns: &["README*"],
-                    case_sensitive: true,
-                },
-                &RegexSet::new(["non-existing pattern"]).unwrap(),
-            )
-            .unwrap()
-        );
-    }
-
-    #[test]
-    fn matches_file_not_found() {
-        assert!(
-            !matches(
-                &Globs {
-                    root: Path::new(TESTDATA_PATH),
-                    patterns: &["nonexisting"],
-                    case_sensitive: true,
-                },
-                &RegexSet::new(["pattern"]).unwrap(),
-            )
-            .unwrap()
-        );
-    }
-
-    #[test]
-    fn matches_invalid_glob_pattern() {
-        assert!(
-            matches(
-                &Globs {
-                    root: Path::new(TESTDATA_PATH),
-                    patterns: &["invalid***"],
-                    case_sensitive: true,
-                },
-                &RegexSet::new(["pattern"]).unwrap(),
-            )
-            .is_err()
-        );
-    }
-
     #[tokio::test]
     async fn remote_matches_match() {
         let mock_server = MockServer::start().await;
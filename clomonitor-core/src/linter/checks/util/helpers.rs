@@ -1,18 +1,23 @@
-use std::path::Path;
+use std::{
+    collections::BTreeSet,
+    iter,
+    path::{Path, PathBuf},
+};
 
 use anyhow::Result;
 use regex::{Regex, RegexSet};
+use time::OffsetDateTime;
 
 use crate::linter::{
-    CHECKS, CheckSet,
-    check::{CheckInput, CheckOutput},
+    CHECKS, CheckSet, LinterInput,
+    check::{CheckInput, CheckOutput, Evidence, EvidenceSource},
     checks::readme,
     datasource::github,
     metadata::{Exemption, Metadata},
 };
 
 use super::{
-    content,
+    content, fs, links,
     path::{self, Globs},
 };
 
@@ -24,32 +29,156 @@ pub(crate) fn find_file_or_readme_ref(
     re: &RegexSet,
 ) -> Result<CheckOutput> {
     // File in repo
-    if let Some(path) = path::find(&Globs {
-        root: &input.li.root,
-        patterns,
-        case_sensitive: false,
-    })? {
+    let (path, file_evidence) = find_project_file_evidence(input.li, patterns, false)?;
+    if let Some(path) = path {
         let url = github::build_url(
             &path,
             &input.gh_md.owner.login,
             &input.gh_md.name,
             &github::default_branch(input.gh_md.default_branch_ref.as_ref()),
         );
-        return Ok(CheckOutput::passed().url(Some(url)));
+        return Ok(CheckOutput::passed()
+            .url(Some(url))
+            .evidence(vec![file_evidence]));
     }
 
-    // Reference in README file
-    if readme_matches(&input.li.root, re)? {
-        return Ok(CheckOutput::passed());
+    // Reference in README file (links to missing files are ignored)
+    let readme_evidence = readme_refs_evidence(input.li, re, true)?;
+    Ok(CheckOutput::from_evidence(
+        iter::once(file_evidence).chain(readme_evidence).collect(),
+    ))
+}
+
+/// Check if any of the regular expressions provided matches the README file
+/// content, recording the evidence of each of them.
+pub(crate) fn find_readme_ref(input: &CheckInput, re: &RegexSet) -> Result<CheckOutput> {
+    let evidence = readme_refs_evidence(input.li, re, false)?;
+    Ok(CheckOutput::from_evidence(evidence))
+}
+
+/// Find the first file that matches any of the patterns provided in the
+/// project's directory. For projects located in a subdirectory of the
+/// repository, the repository root is used as a fallback for shared files
+/// (like the license or the code of conduct). The path returned is relative
+/// to the repository root.
+pub(crate) fn find_project_file(
+    li: &LinterInput,
+    patterns: &[&str],
+    case_sensitive: bool,
+) -> Result<Option<PathBuf>> {
+    if let Some(subpath) = &li.path {
+        let subpath_patterns: Vec<String> = patterns
+            .iter()
+            .map(|pattern| subpath.join(pattern).to_string_lossy().into_owned())
+            .collect();
+        let subpath_patterns: Vec<&str> = subpath_patterns.iter().map(String::as_str).collect();
+        if let Some(path) = path::find(&Globs {
+            root: &li.root,
+            patterns: &subpath_patterns,
+            case_sensitive,
+        })? {
+            return Ok(Some(path));
+        }
     }
+    path::find(&Globs {
+        root: &li.root,
+        patterns,
+        case_sensitive,
+    })
+}
 
-    Ok(CheckOutput::not_passed())
+/// Find the first file that matches any of the patterns provided in the
+/// project's directory (see `find_project_file`), along with the evidence of
+/// the lookup.
+pub(crate) fn find_project_file_evidence(
+    li: &LinterInput,
+    patterns: &[&str],
+    case_sensitive: bool,
+) -> Result<(Option<PathBuf>, Evidence)> {
+    let path = find_project_file(li, patterns, case_sensitive)?;
+    let evidence = Evidence {
+        source: EvidenceSource::File,
+        target: patterns.join(", "),
+        found: path.is_some(),
+        detail: path.as_ref().map(|path| path.to_string_lossy().to_string()),
+    };
+    Ok((path, evidence))
 }
 
-/// Check if the README file content matches any of the regular expressions
-/// provided.
-pub(crate) fn readme_matches(root: &Path, re: &RegexSet) -> Result<bool> {
-    content::matches(&readme_globs(root), re)
+/// Return the directory where the files matching any of the patterns provided
+/// should be read from: the project's subdirectory when it contains any of
+/// them, the repository root otherwise.
+pub(crate) fn project_dir(
+    li: &LinterInput,
+    patterns: &[&str],
+    case_sensitive: bool,
+) -> Result<PathBuf> {
+    if let Some(subpath) = &li.path {
+        let dir = li.root.join(subpath);
+        if !path::matches(&Globs {
+            root: &dir,
+            patterns,
+            case_sensitive,
+        })?
+        .is_empty()
+        {
+            return Ok(dir);
+        }
+    }
+    Ok(li.root.clone())
+}
+
+/// Return the directory where the project's README file is located.
+pub(crate) fn readme_root(li: &LinterInput) -> Result<PathBuf> {
+    project_dir(li, &readme::FILE_PATTERNS, true)
+}
+
+/// Return the indexes of the regular expressions provided that match the
+/// project's README file content. When requested, the Markdown links to local
+/// files that do not exist are removed from the content before matching it
+/// (root-relative links are resolved against the repository root).
+pub(crate) fn readme_matching_refs(
+    li: &LinterInput,
+    re: &RegexSet,
+    ignore_dead_links: bool,
+) -> Result<Vec<usize>> {
+    let readme_root = readme_root(li)?;
+    let mut matching_refs = BTreeSet::new();
+    for path in path::matches(&readme_globs(&readme_root))? {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let matches = if ignore_dead_links {
+            let dir = path.parent().unwrap_or(&readme_root);
+            re.matches(&links::remove_dead_local(&content, &li.root, dir))
+        } else {
+            re.matches(&content)
+        };
+        matching_refs.extend(matches.iter());
+    }
+    Ok(matching_refs.into_iter().collect())
+}
+
+/// Return the evidence of looking for the regular expressions provided in the
+/// project's README file, one entry per expression. When requested, the
+/// Markdown links to local files that do not exist are ignored.
+pub(crate) fn readme_refs_evidence(
+    li: &LinterInput,
+    re: &RegexSet,
+    ignore_dead_links: bool,
+) -> Result<Vec<Evidence>> {
+    let matching_refs = readme_matching_refs(li, re, ignore_dead_links)?;
+    Ok(re
+        .patterns()
+        .iter()
+        .enumerate()
+        .map(|(i, pattern)| Evidence {
+            source: EvidenceSource::Readme,
+            target: pattern.clone(),
+            found: matching_refs.contains(&i),
+            detail: None,
+        })
+        .collect())
 }
 
 /// Check if the README file content matches any of the regular expressions
@@ -58,6 +187,26 @@ pub(crate) fn readme_capture(root: &Path, regexps: &[&Regex]) -> Result<Option<S
     content::find(&readme_globs(root), regexps)
 }
 
+/// Check if the README file content matches any of the regular expressions
+/// provided (see `readme_capture`), along with the evidence of the lookup.
+pub(crate) fn readme_capture_evidence(
+    root: &Path,
+    regexps: &[&Regex],
+) -> Result<(Option<String>, Evidence)> {
+    let value = readme_capture(root, regexps)?;
+    let evidence = Evidence {
+        source: EvidenceSource::Readme,
+        target: regexps
+            .iter()
+            .map(|re| re.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        found: value.is_some(),
+        detail: value.clone(),
+    };
+    Ok((value, evidence))
+}
+
 // Returns a Globs instance used to locate the README file.
 pub(crate) fn readme_globs(root: &Path) -> Globs<'_> {
     Globs {
@@ -68,7 +217,34 @@ pub(crate) fn readme_globs(root: &Path) -> Globs<'_> {
 }
 
 /// Check if the repository is exempt from passing the provided check.
-pub(crate) fn find_exemption(check_id: &str, cm_md: Option<&Metadata>) -> Option<Exemption> {
+/// Exemptions that have expired or whose scope does not include any of the
+/// check sets provided do not apply.
+pub(crate) fn find_exemption(
+    check_id: &str,
+    cm_md: Option<&Metadata>,
+    check_sets: &[CheckSet],
+) -> Option<Exemption> {
+    find_declared_exemption(check_id, cm_md, check_sets)
+        .filter(|exemption| !exemption.expired(OffsetDateTime::now_utc().date()))
+}
+
+/// Return the exemption declared for the provided check if it has expired.
+pub(crate) fn find_expired_exemption(
+    check_id: &str,
+    cm_md: Option<&Metadata>,
+    check_sets: &[CheckSet],
+) -> Option<Exemption> {
+    find_declared_exemption(check_id, cm_md, check_sets)
+        .filter(|exemption| exemption.expired(OffsetDateTime::now_utc().date()))
+}
+
+/// Return the exemption declared for the provided check, as long as it has a
+/// reason and its scope includes any of the check sets provided.
+fn find_declared_exemption(
+    check_id: &str,
+    cm_md: Option<&Metadata>,
+    check_sets: &[CheckSet],
+) -> Option<Exemption> {
     if let Some(exemption) = cm_md
         .as_ref()
         .and_then(|md| md.exemptions.as_ref())
@@ -78,6 +254,7 @@ pub(crate) fn find_exemption(check_id: &str, cm_md: Option<&Metadata>) -> Option
                 .find(|exemption| exemption.check == check_id)
         })
         && !exemption.reason.is_empty()
+        && exemption.in_scope(check_sets)
     {
         return Some(exemption.clone());
     }
@@ -85,28 +262,56 @@ pub(crate) fn find_exemption(check_id: &str, cm_md: Option<&Metadata>) -> Option
     None
 }
 
+/// Make the check output provided pass when it did not, as long as a reachable
+/// evidence url has been declared for the check in the metadata file.
+pub(crate) fn apply_evidence<T>(
+    check_id: &str,
+    input: &CheckInput,
+    output: CheckOutput<T>,
+) -> CheckOutput<T> {
+    if output.passed {
+        return output;
+    }
+    match input.evidence.get(check_id) {
+        Some(Ok(url)) => CheckOutput::passed().url(Some(url.clone())),
+        Some(Err(err)) if output.details.is_none() => output.details(Some(format!("{err:#}"))),
+        _ => output,
+    }
+}
+
 /// Check if the check provided should be skipped.
-pub(crate) fn should_skip_check(check_id: &str, check_sets: &[CheckSet]) -> bool {
+pub(crate) fn should_skip_check(check_id: &str, li: &LinterInput) -> bool {
     // Skip if the check doesn't belong to any of the check sets provided
     if !CHECKS[check_id]
         .check_sets
         .iter()
-        .any(|check_set| check_sets.contains(check_set))
+        .any(|check_set| li.check_sets.contains(check_set))
     {
         return true;
     }
 
+    // Skip if some checks were selected and this one is not one of them
+    if !li.checks.is_empty() && !li.checks.iter().any(|id| id == check_id) {
+        return true;
+    }
+
+    // Skip if the check was explicitly requested to be skipped
+    if li.skip_checks.iter().any(|id| id == check_id) {
+        return true;
+    }
+
     false
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::{collections::HashMap, path::PathBuf};
 
     use anyhow::format_err;
+    use time::macros::date;
 
     use crate::linter::{
-        LinterInput, adopters,
+        adopters,
         datasource::github::md::{MdRepository, MdRepositoryOwner, MdRepositoryOwnerOn},
         sbom,
     };
@@ -135,14 +340,22 @@ mod tests {
                     },
                     scorecard: Err(format_err!("no scorecard available")),
                     security_insights: Ok(None),
+                    evidence: HashMap::new(),
                 },
                 &["README*"],
                 &RegexSet::new(["nothing"]).unwrap(),
             )
             .unwrap(),
-            CheckOutput::passed().url(Some(
-                "https://github.com/owner/repo/blob/master/README.md".to_string()
-            )),
+            CheckOutput::passed()
+                .url(Some(
+                    "https://github.com/owner/repo/blob/master/README.md".to_string()
+                ))
+                .evidence(vec![Evidence {
+                    source: EvidenceSource::File,
+                    target: "README*".to_string(),
+                    found: true,
+                    detail: Some("README.md".to_string()),
+                }]),
         );
     }
 
@@ -159,12 +372,26 @@ mod tests {
                     gh_md: MdRepository::default(),
                     scorecard: Err(format_err!("no scorecard available")),
                     security_insights: Ok(None),
+                    evidence: HashMap::new(),
                 },
                 &["ADOPTERS*"],
                 &RegexSet::new([r"(?im)^#+.*adopters.*$"]).unwrap(),
             )
             .unwrap(),
-            CheckOutput::passed(),
+            CheckOutput::passed().evidence(vec![
+                Evidence {
+                    source: EvidenceSource::File,
+                    target: "ADOPTERS*".to_string(),
+                    found: false,
+                    detail: None,
+                },
+                Evidence {
+                    source: EvidenceSource::Readme,
+                    target: r"(?im)^#+.*adopters.*$".to_string(),
+                    found: true,
+                    detail: None,
+                },
+            ]),
         );
     }
 
@@ -181,13 +408,223 @@ mod tests {
                     gh_md: MdRepository::default(),
                     scorecard: Err(format_err!("no scorecard available")),
                     security_insights: Ok(None),
+                    evidence: HashMap::new(),
                 },
                 &["inexistent_file*"],
                 &RegexSet::new(["inexistent_ref"]).unwrap(),
             )
             .unwrap(),
-            CheckOutput::not_passed(),
+            CheckOutput::not_passed().evidence(vec![
+                Evidence {
+                    source: EvidenceSource::File,
+                    target: "inexistent_file*".to_string(),
+                    found: false,
+                    detail: None,
+                },
+                Evidence {
+                    source: EvidenceSource::Readme,
+                    target: "inexistent_ref".to_string(),
+                    found: false,
+                    detail: None,
+                },
+            ]),
+        );
+    }
+
+    #[test]
+    fn find_file_or_readme_ref_file_found_in_subpath() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("projects/project1")).unwrap();
+        std::fs::write(root.path().join("projects/project1/GOVERNANCE.md"), "").unwrap();
+        std::fs::write(root.path().join("GOVERNANCE.md"), "").unwrap();
+        let li = LinterInput {
+            root: root.path().to_owned(),
+            path: Some(PathBuf::from("projects/project1")),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            find_file_or_readme_ref(
+                &CheckInput {
+                    li: &li,
+                    cm_md: None,
+                    gh_md: MdRepository {
+                        name: "repo".to_string(),
+                        owner: MdRepositoryOwner {
+                            login: "owner".to_string(),
+                            on: MdRepositoryOwnerOn::Organization,
+                        },
+                        ..MdRepository::default()
+                    },
+                    scorecard: Err(format_err!("no scorecard available")),
+                    security_insights: Ok(None),
+                    evidence: HashMap::new(),
+                },
+                &["governance*"],
+                &RegexSet::new(["nothing"]).unwrap(),
+            )
+            .unwrap(),
+            CheckOutput::passed()
+                .url(Some(
+                    "https://github.com/owner/repo/blob/master/projects/project1/GOVERNANCE.md"
+                        .to_string()
+                ))
+                .evidence(vec![Evidence {
+                    source: EvidenceSource::File,
+                    target: "governance*".to_string(),
+                    found: true,
+                    detail: Some("projects/project1/GOVERNANCE.md".to_string()),
+                }]),
+        );
+    }
+
+    #[test]
+    fn find_project_file_falls_back_to_root() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("projects/project1")).unwrap();
+        std::fs::write(root.path().join("projects/project1/README.md"), "").unwrap();
+        std::fs::write(root.path().join("LICENSE"), "").unwrap();
+        let li = LinterInput {
+            root: root.path().to_owned(),
+            path: Some(PathBuf::from("projects/project1")),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            find_project_file(&li, &["README*"], true).unwrap(),
+            Some(PathBuf::from("projects/project1/README.md"))
+        );
+        assert_eq!(
+            find_project_file(&li, &["LICENSE*"], true).unwrap(),
+            Some(PathBuf::from("LICENSE"))
+        );
+        assert_eq!(find_project_file(&li, &["CHANGELOG*"], true).unwrap(), None);
+    }
+
+    #[test]
+    fn project_dir_uses_subpath_when_files_found_there() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("projects/project1")).unwrap();
+        std::fs::write(root.path().join("projects/project1/README.md"), "").unwrap();
+        let li = LinterInput {
+            root: root.path().to_owned(),
+            path: Some(PathBuf::from("projects/project1")),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(
+            readme_root(&li).unwrap(),
+            root.path().join("projects/project1")
+        );
+        assert_eq!(
+            project_dir(&li, &[".clomonitor.yml"], true).unwrap(),
+            root.path().to_owned()
+        );
+    }
+
+    #[test]
+    fn find_file_or_readme_ref_dead_link_ignored() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("README.md"),
+            "See the [governance process](docs/process.md).",
+        )
+        .unwrap();
+        let li = LinterInput {
+            root: root.path().to_owned(),
+            ..LinterInput::default()
+        };
+        let ci = CheckInput {
+            li: &li,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            evidence: HashMap::new(),
+        };
+        let re = RegexSet::new([r"(?i)\[.*governance.*\]\(.*\)"]).unwrap();
+
+        let file_evidence = Evidence {
+            source: EvidenceSource::File,
+            target: "inexistent_file*".to_string(),
+            found: false,
+            detail: None,
+        };
+        assert_eq!(
+            find_file_or_readme_ref(&ci, &["inexistent_file*"], &re).unwrap(),
+            CheckOutput::not_passed().evidence(vec![
+                file_evidence.clone(),
+                Evidence {
+                    source: EvidenceSource::Readme,
+                    target: r"(?i)\[.*governance.*\]\(.*\)".to_string(),
+                    found: false,
+                    detail: None,
+                },
+            ]),
         );
+
+        std::fs::create_dir(root.path().join("docs")).unwrap();
+        std::fs::write(root.path().join("docs/process.md"), "").unwrap();
+        assert_eq!(
+            find_file_or_readme_ref(&ci, &["inexistent_file*"], &re).unwrap(),
+            CheckOutput::passed().evidence(vec![
+                file_evidence,
+                Evidence {
+                    source: EvidenceSource::Readme,
+                    target: r"(?i)\[.*governance.*\]\(.*\)".to_string(),
+                    found: true,
+                    detail: None,
+                },
+            ]),
+        );
+    }
+
+    #[test]
+    fn readme_matching_refs_found() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("README.md"),
+            "# Governance\n\nSee the [governance process](docs/process.md).",
+        )
+        .unwrap();
+        let re = RegexSet::new([
+            r"(?im)^#+.*governance.*$",
+            r"(?im)^governance$",
+            r"(?i)\[.*governance.*\]\(.*\)",
+        ])
+        .unwrap();
+        let li = LinterInput {
+            root: root.path().to_owned(),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(readme_matching_refs(&li, &re, false).unwrap(), vec![0, 2]);
+        assert_eq!(readme_matching_refs(&li, &re, true).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn readme_matching_refs_root_relative_links_in_subpath() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("docs")).unwrap();
+        std::fs::write(root.path().join("docs/governance.md"), "").unwrap();
+        std::fs::create_dir_all(root.path().join("projects/project1")).unwrap();
+        std::fs::write(
+            root.path().join("projects/project1/README.md"),
+            "See the [governance process](/docs/governance.md) and the [roadmap](/docs/roadmap.md).",
+        )
+        .unwrap();
+        let re = RegexSet::new([
+            r"(?i)\[.*governance.*\]\(.*\)",
+            r"(?i)\[.*roadmap.*\]\(.*\)",
+        ])
+        .unwrap();
+        let li = LinterInput {
+            root: root.path().to_owned(),
+            path: Some(PathBuf::from("projects/project1")),
+            ..LinterInput::default()
+        };
+
+        assert_eq!(readme_matching_refs(&li, &re, true).unwrap(), vec![0]);
     }
 
     #[test]
@@ -196,16 +633,21 @@ mod tests {
             find_exemption(
                 "check-id",
                 Some(&Metadata {
+                    evidence: None,
                     exemptions: Some(vec![Exemption {
                         check: "check-id".to_string(),
                         reason: "sample reason".to_string(),
+                        ..Exemption::default()
                     }]),
+                    gates: None,
                     license_scanning: None
-                })
+                }),
+                &[CheckSet::Code]
             ),
             Some(Exemption {
                 check: "check-id".to_string(),
                 reason: "sample reason".to_string(),
+                ..Exemption::default()
             }),
         );
     }
@@ -216,12 +658,16 @@ mod tests {
             find_exemption(
                 "not-found",
                 Some(&Metadata {
+                    evidence: None,
                     exemptions: Some(vec![Exemption {
                         check: "check-id".to_string(),
                         reason: "sample reason".to_string(),
+                        ..Exemption::default()
                     }]),
+                    gates: None,
                     license_scanning: None
-                })
+                }),
+                &[CheckSet::Code]
             ),
             None,
         );
@@ -233,9 +679,12 @@ mod tests {
             find_exemption(
                 "check-id",
                 Some(&Metadata {
+                    evidence: None,
                     exemptions: None,
+                    gates: None,
                     license_scanning: None
-                })
+                }),
+                &[CheckSet::Code]
             ),
             None,
         );
@@ -243,24 +692,196 @@ mod tests {
 
     #[test]
     fn find_exemption_not_found_no_md() {
-        assert_eq!(find_exemption("check-id", None), None,);
+        assert_eq!(find_exemption("check-id", None, &[CheckSet::Code]), None);
+    }
+
+    #[test]
+    fn find_exemption_not_found_expired() {
+        let md = Metadata {
+            evidence: None,
+            exemptions: Some(vec![Exemption {
+                check: "check-id".to_string(),
+                reason: "sample reason".to_string(),
+                expires: Some(date!(2000 - 01 - 01)),
+                ..Exemption::default()
+            }]),
+            gates: None,
+            license_scanning: None,
+        };
+
+        assert_eq!(
+            find_exemption("check-id", Some(&md), &[CheckSet::Code]),
+            None
+        );
+        assert_eq!(
+            find_expired_exemption("check-id", Some(&md), &[CheckSet::Code]),
+            md.exemptions.as_ref().unwrap().first().cloned()
+        );
+    }
+
+    #[test]
+    fn find_exemption_found_not_expired_yet() {
+        let md = Metadata {
+            evidence: None,
+            exemptions: Some(vec![Exemption {
+                check: "check-id".to_string(),
+                reason: "sample reason".to_string(),
+                expires: Some(date!(9999 - 12 - 31)),
+                approved_by: Some("https://github.com/cncf/toc/issues/1".to_string()),
+                ..Exemption::default()
+            }]),
+            gates: None,
+            license_scanning: None,
+        };
+
+        assert_eq!(
+            find_exemption("check-id", Some(&md), &[CheckSet::Code]),
+            md.exemptions.as_ref().unwrap().first().cloned()
+        );
+        assert_eq!(
+            find_expired_exemption("check-id", Some(&md), &[CheckSet::Code]),
+            None
+        );
+    }
+
+    #[test]
+    fn find_exemption_not_found_out_of_scope() {
+        let md = Metadata {
+            evidence: None,
+            exemptions: Some(vec![Exemption {
+                check: "check-id".to_string(),
+                reason: "sample reason".to_string(),
+                scope: Some(vec![CheckSet::Community]),
+                ..Exemption::default()
+            }]),
+            gates: None,
+            license_scanning: None,
+        };
+
+        assert_eq!(
+            find_exemption("check-id", Some(&md), &[CheckSet::Code]),
+            None
+        );
+        assert!(
+            find_exemption(
+                "check-id",
+                Some(&md),
+                &[CheckSet::Code, CheckSet::Community]
+            )
+            .is_some()
+        );
+    }
+
+    #[test]
+    fn apply_evidence_reachable_url() {
+        let li = LinterInput::default();
+        let input = CheckInput {
+            li: &li,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            evidence: HashMap::from([(
+                "check-id".to_string(),
+                Ok("https://evidence.url".to_string()),
+            )]),
+        };
+
+        assert_eq!(
+            apply_evidence("check-id", &input, CheckOutput::<()>::not_passed()),
+            CheckOutput::passed().url(Some("https://evidence.url".to_string()))
+        );
+        assert_eq!(
+            apply_evidence("other-check-id", &input, CheckOutput::<()>::not_passed()),
+            CheckOutput::not_passed()
+        );
+    }
+
+    #[test]
+    fn apply_evidence_unreachable_url() {
+        let li = LinterInput::default();
+        let input = CheckInput {
+            li: &li,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            evidence: HashMap::from([(
+                "check-id".to_string(),
+                Err(format_err!("evidence url not reachable")),
+            )]),
+        };
+
+        assert_eq!(
+            apply_evidence("check-id", &input, CheckOutput::<()>::not_passed()),
+            CheckOutput::not_passed().details(Some("evidence url not reachable".to_string()))
+        );
+    }
+
+    #[test]
+    fn apply_evidence_check_already_passed() {
+        let li = LinterInput::default();
+        let input = CheckInput {
+            li: &li,
+            cm_md: None,
+            gh_md: MdRepository::default(),
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            evidence: HashMap::from([(
+                "check-id".to_string(),
+                Ok("https://evidence.url".to_string()),
+            )]),
+        };
+
+        assert_eq!(
+            apply_evidence(
+                "check-id",
+                &input,
+                CheckOutput::<()>::passed().url(Some("https://check.url".to_string()))
+            ),
+            CheckOutput::passed().url(Some("https://check.url".to_string()))
+        );
     }
 
     #[test]
     fn should_skip_check_affirmative() {
-        assert!(should_skip_check(adopters::ID, &[CheckSet::Code]));
-        assert!(should_skip_check(sbom::ID, &[CheckSet::Community]));
+        let li = |check_sets: &[CheckSet]| LinterInput {
+            check_sets: check_sets.to_vec(),
+            ..LinterInput::default()
+        };
+        assert!(should_skip_check(adopters::ID, &li(&[CheckSet::Code])));
+        assert!(should_skip_check(sbom::ID, &li(&[CheckSet::Community])));
     }
 
     #[test]
     fn should_skip_check_negative() {
-        assert!(!should_skip_check(
-            adopters::ID,
-            &[CheckSet::Code, CheckSet::Community]
-        ));
-        assert!(!should_skip_check(
-            sbom::ID,
-            &[CheckSet::Code, CheckSet::Community]
-        ));
+        let li = LinterInput {
+            check_sets: vec![CheckSet::Code, CheckSet::Community],
+            ..LinterInput::default()
+        };
+        assert!(!should_skip_check(adopters::ID, &li));
+        assert!(!should_skip_check(sbom::ID, &li));
+    }
+
+    #[test]
+    fn should_skip_check_not_selected() {
+        let li = LinterInput {
+            check_sets: vec![CheckSet::Code, CheckSet::Community],
+            checks: vec![sbom::ID.to_string()],
+            ..LinterInput::default()
+        };
+        assert!(should_skip_check(adopters::ID, &li));
+        assert!(!should_skip_check(sbom::ID, &li));
+    }
+
+    #[test]
+    fn should_skip_check_skipped() {
+        let li = LinterInput {
+            check_sets: vec![CheckSet::Code, CheckSet::Community],
+            skip_checks: vec![sbom::ID.to_string()],
+            ..LinterInput::default()
+        };
+        assert!(!should_skip_check(adopters::ID, &li));
+        assert!(should_skip_check(sbom::ID, &li));
     }
 }
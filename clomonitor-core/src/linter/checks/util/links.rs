@@ -0,0 +1,245 @@
+use std::{borrow::Cow, path::Path, sync::LazyLock, time::Duration};
+
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use regex::{Captures, Regex};
+use reqwest::{Client, Url};
+
+/// Maximum number of links checked concurrently.
+const MAX_CONCURRENT_REQUESTS: usize = 5;
+
+/// Timeout used in each of the requests done to check a link.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HTTP client used to request remote resources (links, websites, etc), which
+/// makes sure no request can hang for longer than the request timeout.
+pub(crate) static HTTP_CLIENT: LazyLock<Client> = LazyLock::new(|| {
+    Client::builder()
+        .user_agent("clomonitor")
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .expect("http client to be built")
+});
+
+static MD_INLINE_LINK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\[((?:[^\[\]]|\[[^\[\]]*\])*)\]\(\s*<?([^()\s<>]+)>?(?:\s+"[^"]*")?\s*\)"#)
+        .expect("exprs in MD_INLINE_LINK to be valid")
+});
+
+static MD_LINK_REF_DEF: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^ {0,3}\[[^\]]+\]:\s*<?([^\s<>]+)>?")
+        .expect("exprs in MD_LINK_REF_DEF to be valid")
+});
+
+/// Extract the links found in the Markdown content provided. Both inline links
+/// (including images) and link reference definitions are supported.
+pub(crate) fn extract_from_markdown(content: &str) -> Vec<String> {
+    let mut links: Vec<String> = Vec::new();
+    let mut add = |link: &str| {
+        if !links.iter().any(|l| l == link) {
+            links.push(link.to_string());
+        }
+    };
+    for c in MD_INLINE_LINK.captures_iter(content) {
+        // Links nested in the link text, like images used as badges
+        for nc in MD_INLINE_LINK.captures_iter(&c[1]) {
+            add(&nc[2]);
+        }
+        add(&c[2]);
+    }
+    for c in MD_LINK_REF_DEF.captures_iter(content) {
+        add(&c[1]);
+    }
+    links
+}
+
+/// Check if the link provided points to a local file (relative links or
+/// absolute paths without a scheme).
+pub(crate) fn is_local(link: &str) -> bool {
+    Url::parse(link).is_err()
+}
+
+/// Check if the link provided points to a remote http(s) resource.
+pub(crate) fn is_remote(link: &str) -> bool {
+    Url::parse(link).is_ok_and(|url| ["http", "https"].contains(&url.scheme()))
+}
+
+/// Check if the local link provided, found in a file located in the directory
+/// given, points to a path that exists in the repository. Links starting with
+/// a slash are resolved against the repository root.
+pub(crate) fn local_target_exists(root: &Path, dir: &Path, link: &str) -> bool {
+    let target = link.split(['#', '?']).next().unwrap_or_default();
+    if target.is_empty() {
+        return true;
+    }
+    let target = target.replace("%20", " ");
+    match target.strip_prefix('/') {
+        Some(target) => root.join(target).exists(),
+        None => dir.join(target).exists(),
+    }
+}
+
+/// Remove the Markdown inline links to local targets that do not exist from
+/// the content provided, keeping only the link text.
+pub(crate) fn remove_dead_local<'a>(content: &'a str, root: &Path, dir: &Path) -> Cow<'a, str> {
+    MD_INLINE_LINK.replace_all(content, |c: &Captures| {
+        if is_local(&c[2]) && !local_target_exists(root, dir, &c[2]) {
+            c[1].to_string()
+        } else {
+            c[0].to_string()
+        }
+    })
+}
+
+/// Check the links provided, returning the ones that could not be reached.
+pub(crate) async fn find_broken(urls: &[String]) -> Result<Vec<String>> {
+    let results: Vec<Option<String>> = stream::iter(urls.to_vec())
+        .map(|url| async move { (!is_reachable(&HTTP_CLIENT, &url).await).then_some(url) })
+        .buffered(MAX_CONCURRENT_REQUESTS)
+        .collect()
+        .await;
+    let broken = results.into_iter().flatten().collect();
+
+    Ok(broken)
+}
+
+/// Check if the url provided can be reached. Some servers don't support HEAD
+/// requests, so a GET request is tried as well when the HEAD one fails.
+async fn is_reachable(http_client: &Client, url: &str) -> bool {
+    if let Ok(resp) = http_client.head(url).send().await
+        && resp.status().is_success()
+    {
+        return true;
+    }
+    matches!(http_client.get(url).send().await, Ok(resp) if resp.status().is_success())
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use super::*;
+
+    const TESTDATA_PATH: &str = "src/testdata";
+
+    #[test]
+    fn extract_from_markdown_links_found() {
+        assert_eq!(
+            extract_from_markdown(
+                r#"
+[![Badge](https://badge.url/badge.svg)](https://badge.url)
+
+Please see the [contributing guide](CONTRIBUTING.md "Contributing") and
+the [governance](<docs/GOVERNANCE.md#process>) documents. [Contributing][1]
+
+[1]: CONTRIBUTING.md
+[website]: https://project.io
+                "#
+            ),
+            vec![
+                "https://badge.url/badge.svg".to_string(),
+                "https://badge.url".to_string(),
+                "CONTRIBUTING.md".to_string(),
+                "docs/GOVERNANCE.md#process".to_string(),
+                "https://project.io".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn extract_from_markdown_no_links_found() {
+        assert!(extract_from_markdown("# Title\n\nText [not a link] (nope)").is_empty());
+    }
+
+    #[test]
+    fn is_local_and_is_remote() {
+        assert!(is_local("CONTRIBUTING.md"));
+        assert!(is_local("/docs/governance.md#process"));
+        assert!(is_local("#anchor"));
+        assert!(!is_local("https://project.io"));
+        assert!(!is_local("mailto:info@project.io"));
+
+        assert!(is_remote("https://project.io"));
+        assert!(is_remote("http://project.io/docs"));
+        assert!(!is_remote("mailto:info@project.io"));
+        assert!(!is_remote("CONTRIBUTING.md"));
+    }
+
+    #[test]
+    fn local_target_exists_resolves_links() {
+        let root = Path::new(TESTDATA_PATH);
+
+        assert!(local_target_exists(root, root, "README.md"));
+        assert!(local_target_exists(root, root, "README.md#adopters"));
+        assert!(local_target_exists(root, root, "#adopters"));
+        assert!(local_target_exists(
+            root,
+            &root.join("security-insights-v1"),
+            "/LICENSE"
+        ));
+        assert!(local_target_exists(
+            root,
+            &root.join("security-insights-v1"),
+            "../OWNERS"
+        ));
+
+        assert!(!local_target_exists(root, root, "CONTRIBUTING.md"));
+        assert!(!local_target_exists(
+            root,
+            &root.join("security-insights-v1"),
+            "OWNERS"
+        ));
+    }
+
+    #[test]
+    fn remove_dead_local_keeps_live_links() {
+        let root = Path::new(TESTDATA_PATH);
+
+        assert_eq!(
+            remove_dead_local(
+                "See [governance](GOVERNANCE.md), [license](LICENSE) and [site](https://project.io).",
+                root,
+                root
+            ),
+            "See governance, [license](LICENSE) and [site](https://project.io)."
+        );
+    }
+
+    #[tokio::test]
+    async fn find_broken_some_links_broken() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .and(path("/ok"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("HEAD"))
+            .and(path("/head-not-allowed"))
+            .respond_with(ResponseTemplate::new(405))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/head-not-allowed"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let urls = vec![
+            format!("{}/ok", mock_server.uri()),
+            format!("{}/head-not-allowed", mock_server.uri()),
+            format!("{}/not-found", mock_server.uri()),
+        ];
+        assert_eq!(
+            find_broken(&urls).await.unwrap(),
+            vec![format!("{}/not-found", mock_server.uri())]
+        );
+    }
+
+    #[tokio::test]
+    async fn find_broken_no_links_provided() {
+        assert!(find_broken(&[]).await.unwrap().is_empty());
+    }
+}
@@ -2,27 +2,44 @@ use anyhow::Result;
 
 use crate::linter::{
     CheckSet,
-    check::{CheckId, CheckInput, CheckOutput},
+    check::{CheckId, CheckInput, CheckOutput, Evidence, EvidenceSource},
 };
 
 /// Check identifier.
 pub(crate) const ID: CheckId = "website";
 
+/// Check name.
+pub(crate) const NAME: &str = "Website";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "website";
+
 /// Check score weight.
 pub(crate) const WEIGHT: usize = 4;
 
 /// Check sets this check belongs to.
 pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Community];
 
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
 /// Check main function.
 #[allow(clippy::unnecessary_wraps)]
 pub(crate) fn check(input: &CheckInput) -> Result<CheckOutput> {
     // Website in Github
-    if let Some(url) = &input.gh_md.homepage_url
-        && !url.is_empty()
-    {
-        return Ok(CheckOutput::passed().url(Some(url.clone())));
-    }
-
-    Ok(CheckOutput::not_passed())
+    let url = input
+        .gh_md
+        .homepage_url
+        .clone()
+        .filter(|url| !url.is_empty());
+    Ok(CheckOutput::from_evidence(vec![Evidence {
+        source: EvidenceSource::GitHub,
+        target: "homepage url".to_string(),
+        found: url.is_some(),
+        detail: url.clone(),
+    }])
+    .url(url))
 }
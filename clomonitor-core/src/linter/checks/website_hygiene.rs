@@ -0,0 +1,425 @@
+use std::sync::LazyLock;
+
+use anyhow::Result;
+use regex::{Regex, RegexSet};
+use reqwest::Url;
+
+use crate::linter::{
+    CheckSet,
+    check::{CheckId, CheckInput, CheckOutput},
+};
+
+use super::util::links::{self, HTTP_CLIENT};
+
+/// Check identifier.
+pub(crate) const ID: CheckId = "website_hygiene";
+
+/// Check name.
+pub(crate) const NAME: &str = "Website hygiene";
+
+/// Check documentation anchor.
+pub(crate) const DOCS_ANCHOR: &str = "website-hygiene";
+
+/// Check score weight.
+pub(crate) const WEIGHT: usize = 0;
+
+/// Check sets this check belongs to.
+pub(crate) const CHECK_SETS: [CheckSet; 1] = [CheckSet::Docs];
+
+/// Whether this check uses the repository's GitHub metadata.
+pub(crate) const NEEDS_GITHUB_MD: bool = true;
+
+/// Whether this check uses the repository's OpenSSF Security Insights file.
+pub(crate) const NEEDS_SECURITY_INSIGHTS: bool = false;
+
+/// Maximum number of top-level navigation links that will be checked.
+const MAX_NAV_LINKS: usize = 20;
+
+static HTML_LANG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)<html\b[^>]*\blang\s*=\s*["']?[a-z]"#)
+        .expect("exprs in HTML_LANG to be valid")
+});
+
+static IMG_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<img\b[^>]*>").expect("exprs in IMG_TAG to be valid"));
+
+static IMG_ALT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\balt\s*=").expect("exprs in IMG_ALT to be valid"));
+
+static IMG_SRC: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\bsrc\s*=\s*["']?([^"'\s>]+)"#).expect("exprs in IMG_SRC to be valid")
+});
+
+static NAV: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?is)<nav\b[^>]*>(.*?)</nav>").expect("exprs in NAV to be valid")
+});
+
+static HREF: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\bhref\s*=\s*["']?([^"'\s>]+)"#).expect("exprs in HREF to be valid")
+});
+
+static SEARCH: LazyLock<RegexSet> = LazyLock::new(|| {
+    RegexSet::new([
+        r#"(?i)<input\b[^>]*\btype\s*=\s*["']?search"#,
+        r#"(?i)\brole\s*=\s*["']?search"#,
+        r"(?i)docsearch",
+        r"(?i)pagefind",
+        r"(?i)lunr(?:\.min)?\.js",
+    ])
+    .expect("exprs in SEARCH to be valid")
+});
+
+/// Check main function.
+pub(crate) async fn check(input: &CheckInput<'_>) -> Result<CheckOutput> {
+    // Website in Github
+    let homepage_url = match &input.gh_md.homepage_url {
+        Some(url) if !url.is_empty() => url,
+        _ => return Ok(CheckOutput::not_passed()),
+    };
+    let Ok(url) = Url::parse(homepage_url) else {
+        return Ok(CheckOutput::not_passed().details(Some(format!(
+            "# Issues found in project's website\n\n· The website url is not valid: {homepage_url}\n"
+        ))));
+    };
+    let mut issues: Vec<String> = Vec::new();
+
+    // HTTPS with a valid redirect from HTTP
+    if url.scheme() == "https" {
+        if !redirects_to_https(&url).await {
+            issues.push("The HTTP version of the website does not redirect to HTTPS".to_string());
+        }
+    } else {
+        issues.push("The website is not served over HTTPS".to_string());
+    }
+
+    // Get landing page content (the request fails on invalid certificates)
+    let (base_url, content) = match fetch(&url).await {
+        Ok(landing_page) => landing_page,
+        Err(err) => {
+            return Ok(CheckOutput::not_passed().details(Some(format!(
+                "# Issues found in project's website\n\n· The website could not be fetched: {err}\n"
+            ))));
+        }
+    };
+
+    // Language declared in the html element
+    if !HTML_LANG.is_match(&content) {
+        issues.push("The `<html>` element does not declare a `lang` attribute".to_string());
+    }
+
+    // Alt text on landing page images
+    let images = images_without_alt(&content);
+    if !images.is_empty() {
+        issues.push(format!(
+            "Images without alt text in the landing page: {}",
+            images.join(", ")
+        ));
+    }
+
+    // Search or sitemap.xml available
+    if !SEARCH.is_match(&content) && !has_sitemap(&base_url).await {
+        issues.push("No search or sitemap.xml found".to_string());
+    }
+
+    // Broken links in the top-level navigation
+    let broken_links = links::find_broken(&nav_links(&content, &base_url)).await?;
+    if !broken_links.is_empty() {
+        issues.push(format!(
+            "Broken links in the top-level navigation: {}",
+            broken_links.join(", ")
+        ));
+    }
+
+    // Return check output
+    if issues.is_empty() {
+        return Ok(CheckOutput::passed().url(Some(homepage_url.clone())));
+    }
+    let details = issues.iter().fold(
+        "# Issues found in project's website\n\n".to_string(),
+        |details, issue| details + "· " + issue + "\n",
+    );
+    Ok(CheckOutput::not_passed().details(Some(details)))
+}
+
+/// Fetch the page at the url provided, returning the final url (once all
+/// redirects have been followed) along with its content.
+async fn fetch(url: &Url) -> Result<(Url, String)> {
+    let resp = HTTP_CLIENT
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?;
+    let base_url = resp.url().clone();
+    let content = resp.text().await?;
+    Ok((base_url, content))
+}
+
+/// Check if requests to the HTTP version of the url provided are redirected
+/// to HTTPS.
+async fn redirects_to_https(url: &Url) -> bool {
+    let mut http_url = url.clone();
+    if http_url.set_scheme("http").is_err() {
+        return false;
+    }
+    matches!(HTTP_CLIENT.get(http_url).send().await, Ok(resp) if resp.url().scheme() == "https")
+}
+
+/// Check if a sitemap.xml file is available in the website provided.
+async fn has_sitemap(base_url: &Url) -> bool {
+    let Ok(sitemap_url) = base_url.join("/sitemap.xml") else {
+        return false;
+    };
+    matches!(HTTP_CLIENT.get(sitemap_url).send().await, Ok(resp) if resp.status().is_success())
+}
+
+/// Return the source of the images found in the content provided that do not
+/// have an alt attribute.
+fn images_without_alt(content: &str) -> Vec<String> {
+    let mut images: Vec<String> = Vec::new();
+    for img in IMG_TAG.find_iter(content).map(|m| m.as_str()) {
+        if IMG_ALT.is_match(img) {
+            continue;
+        }
+        let src = IMG_SRC
+            .captures(img)
+            .map_or_else(|| "<img>".to_string(), |c| c[1].to_string());
+        if !images.contains(&src) {
+            images.push(src);
+        }
+    }
+    images
+}
+
+/// Return the links found in the top-level navigation of the content provided,
+/// resolved against the base url given.
+fn nav_links(content: &str, base_url: &Url) -> Vec<String> {
+    let Some(nav) = NAV.captures(content) else {
+        return vec![];
+    };
+
+    let mut links: Vec<String> = Vec::new();
+    for href in HREF.captures_iter(&nav[1]).map(|c| c[1].to_string()) {
+        if href.starts_with('#') {
+            continue;
+        }
+        let Ok(mut link) = base_url.join(&href) else {
+            continue;
+        };
+        if !["http", "https"].contains(&link.scheme()) {
+            continue;
+        }
+        link.set_fragment(None);
+        let link = link.to_string();
+        if !links.contains(&link) {
+            links.push(link);
+        }
+    }
+    links.truncate(MAX_NAV_LINKS);
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::format_err;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use crate::linter::{LinterInput, datasource::github::md::MdRepository};
+
+    use super::*;
+
+    #[test]
+    fn html_lang_match() {
+        assert!(HTML_LANG.is_match(r#"<!DOCTYPE html><html lang="en">"#));
+        assert!(HTML_LANG.is_match(r#"<html class="no-js" lang='es-ES'>"#));
+        assert!(HTML_LANG.is_match("<html lang=en>"));
+
+        assert!(!HTML_LANG.is_match("<html>"));
+        assert!(!HTML_LANG.is_match(r#"<html lang="">"#));
+    }
+
+    #[test]
+    fn search_match() {
+        assert!(SEARCH.is_match(r#"<input type="search" placeholder="Search">"#));
+        assert!(SEARCH.is_match(r#"<form role="search">"#));
+        assert!(SEARCH.is_match(r#"<script src="https://cdn.jsdelivr.net/npm/@docsearch/js@3">"#));
+        assert!(SEARCH.is_match(r#"<script src="/pagefind/pagefind-ui.js">"#));
+        assert!(SEARCH.is_match(r#"<script src="/js/lunr.min.js">"#));
+
+        assert!(!SEARCH.is_match(r#"<input type="text">"#));
+    }
+
+    #[test]
+    fn images_without_alt_found() {
+        assert_eq!(
+            images_without_alt(
+                r#"
+                <img src="/logo.svg" alt="Project logo">
+                <img src="/decorative.png" alt="">
+                <img src="/diagram.png">
+                <img src='/diagram.png' class="wide">
+                <img class="empty">
+                "#
+            ),
+            vec!["/diagram.png".to_string(), "<img>".to_string()]
+        );
+    }
+
+    #[test]
+    fn nav_links_extracted() {
+        let base_url = Url::parse("https://project.io/").unwrap();
+
+        assert_eq!(
+            nav_links(
+                r##"
+                <header><a href="/outside-nav">Outside</a></header>
+                <nav class="navbar">
+                    <a href="#main">Skip</a>
+                    <a href="/docs/">Docs</a>
+                    <a href="/docs/#install">Install</a>
+                    <a href="blog">Blog</a>
+                    <a href="https://github.com/org/project">GitHub</a>
+                    <a href="mailto:info@project.io">Contact</a>
+                </nav>
+                "##,
+                &base_url
+            ),
+            vec![
+                "https://project.io/docs/".to_string(),
+                "https://project.io/blog".to_string(),
+                "https://github.com/org/project".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn nav_links_no_nav_found() {
+        let base_url = Url::parse("https://project.io/").unwrap();
+
+        assert!(nav_links(r#"<a href="/docs/">Docs</a>"#, &base_url).is_empty());
+    }
+
+    #[tokio::test]
+    async fn not_passed_no_website_found() {
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput::default(),
+                cm_md: None,
+                gh_md: MdRepository::default(),
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::not_passed(),
+        );
+    }
+
+    #[tokio::test]
+    async fn not_passed_invalid_website_url() {
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput::default(),
+                cm_md: None,
+                gh_md: MdRepository {
+                    homepage_url: Some("project.io".to_string()),
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::not_passed().details(Some(
+                "# Issues found in project's website\n\n· The website url is not valid: project.io\n"
+                    .to_string()
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn not_passed_website_cannot_be_fetched() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&mock_server)
+            .await;
+
+        let output = check(&CheckInput {
+            li: &LinterInput::default(),
+            cm_md: None,
+            gh_md: MdRepository {
+                homepage_url: Some(format!("{}/", mock_server.uri())),
+                ..MdRepository::default()
+            },
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            evidence: HashMap::new(),
+        })
+        .await
+        .unwrap();
+        assert!(!output.passed);
+        assert!(
+            output
+                .details
+                .unwrap()
+                .contains("· The website could not be fetched: HTTP status server error (503")
+        );
+    }
+
+    #[tokio::test]
+    async fn not_passed_issues_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"
+                <html>
+                <nav><a href="/docs">Docs</a><a href="/broken">Broken</a></nav>
+                <img src="/logo.png">
+                </html>
+                "#,
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("HEAD"))
+            .and(path("/docs"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        assert_eq!(
+            check(&CheckInput {
+                li: &LinterInput::default(),
+                cm_md: None,
+                gh_md: MdRepository {
+                    homepage_url: Some(format!("{}/", mock_server.uri())),
+                    ..MdRepository::default()
+                },
+                scorecard: Err(format_err!("no scorecard available")),
+                security_insights: Ok(None),
+                evidence: HashMap::new(),
+            })
+            .await
+            .unwrap(),
+            CheckOutput::not_passed().details(Some(format!(
+                "# Issues found in project's website
+
+· The website is not served over HTTPS
+· The `<html>` element does not declare a `lang` attribute
+· Images without alt text in the landing page: /logo.png
+· No search or sitemap.xml found
+· Broken links in the top-level navigation: {}/broken
+",
+                mock_server.uri()
+            ))),
+        );
+    }
+}
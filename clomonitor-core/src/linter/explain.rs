@@ -0,0 +1,299 @@
+use anyhow::{Result, format_err};
+use serde::Serialize;
+
+use super::{
+    CheckId, CheckOutput, Evidence, EvidenceSource, LinterInput,
+    check::CheckInput,
+    checks::{
+        CHECKS,
+        util::helpers::{find_exemption, find_expired_exemption},
+    },
+    run_checks,
+};
+
+/// Explanation of how a check decided its result.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Explanation {
+    pub check_id: CheckId,
+    pub output: Option<CheckOutput>,
+    pub evidence: Vec<Evidence>,
+}
+
+/// Run the check provided on the repository and explain how it decided its
+/// result. Only the requested check is run, so inter-checks exemptions (i.e.
+/// CLA and DCO) are not applied.
+#[allow(clippy::missing_errors_doc)]
+pub async fn explain(li: &LinterInput, check_id: &str) -> Result<Explanation> {
+    let Some((check_id, check)) = CHECKS.get_key_value(check_id) else {
+        return Err(format_err!("unknown check: {check_id}"));
+    };
+
+    // Make sure only the requested check is run, regardless of the check sets
+    // or checks selected
+    let li = LinterInput {
+        check_sets: check.check_sets.clone(),
+        checks: vec![(*check_id).to_string()],
+        skip_checks: vec![],
+        ..li.clone()
+    };
+    let ci = CheckInput::new(&li).await?;
+
+    Ok(explain_check(check_id, &ci).await)
+}
+
+/// Run the check provided and explain its result using the evidence recorded
+/// by the check, along with the exemption and evidence url declared for it in
+/// the metadata file (if any).
+async fn explain_check(check_id: CheckId, ci: &CheckInput<'_>) -> Explanation {
+    let mut output = run_checks(ci)
+        .await
+        .outputs()
+        .into_iter()
+        .find(|(id, _)| *id == check_id)
+        .map(|(_, output)| output);
+
+    let mut evidence = metadata_evidence(check_id, ci);
+    if let Some(output) = output.as_mut() {
+        evidence.append(&mut output.evidence);
+    }
+
+    Explanation {
+        check_id,
+        output,
+        evidence,
+    }
+}
+
+/// Collect the evidence declared in the metadata file for the check provided.
+fn metadata_evidence(check_id: CheckId, ci: &CheckInput<'_>) -> Vec<Evidence> {
+    let mut evidence = Vec::new();
+
+    // Exemption declared in the metadata file
+    let (cm_md, check_sets) = (ci.cm_md.as_ref(), &ci.li.check_sets);
+    if let Some(exemption) = find_exemption(check_id, cm_md, check_sets) {
+        evidence.push(Evidence {
+            source: EvidenceSource::Metadata,
+            target: "exemption in .clomonitor.yml".to_string(),
+            found: true,
+            detail: Some(exemption.reason),
+        });
+    } else if let Some(exemption) = find_expired_exemption(check_id, cm_md, check_sets) {
+        evidence.push(Evidence {
+            source: EvidenceSource::Metadata,
+            target: "exemption in .clomonitor.yml".to_string(),
+            found: false,
+            detail: exemption
+                .expires
+                .map(|expires| format!("exemption expired on {expires}")),
+        });
+    }
+
+    // Evidence url declared in the metadata file
+    if let Some(url) = ci.evidence.get(check_id) {
+        evidence.push(Evidence {
+            source: EvidenceSource::Metadata,
+            target: "evidence in .clomonitor.yml".to_string(),
+            found: url.is_ok(),
+            detail: Some(match url {
+                Ok(url) => url.clone(),
+                Err(err) => format!("{err:#}"),
+            }),
+        });
+    }
+
+    evidence
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, fs, path::PathBuf};
+
+    use anyhow::format_err;
+
+    use crate::linter::{
+        checks::{adopters, changelog, cla, governance, maintained},
+        datasource::github::md::*,
+    };
+
+    use super::*;
+
+    const TESTDATA_PATH: &str = "src/testdata";
+
+    fn linter_input(root: PathBuf, check_id: CheckId) -> LinterInput {
+        LinterInput {
+            root,
+            check_sets: CHECKS[check_id].check_sets.clone(),
+            checks: vec![check_id.to_string()],
+            ..LinterInput::default()
+        }
+    }
+
+    fn check_input(li: &LinterInput, gh_md: MdRepository) -> CheckInput<'_> {
+        CheckInput {
+            li,
+            cm_md: None,
+            gh_md,
+            scorecard: Err(format_err!("no scorecard available")),
+            security_insights: Ok(None),
+            evidence: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn explain_check_file_found() {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join("docs")).unwrap();
+        fs::write(root.path().join("docs/GOVERNANCE.md"), "").unwrap();
+        let li = linter_input(root.path().to_owned(), governance::ID);
+        let ci = check_input(&li, MdRepository::default());
+
+        let explanation = explain_check(governance::ID, &ci).await;
+        assert!(explanation.output.unwrap().passed);
+        assert_eq!(
+            explanation.evidence,
+            vec![Evidence {
+                source: EvidenceSource::File,
+                target: "governance*, docs/governance*".to_string(),
+                found: true,
+                detail: Some("docs/GOVERNANCE.md".to_string()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn explain_check_readme_ref_found() {
+        let li = linter_input(PathBuf::from(TESTDATA_PATH), adopters::ID);
+        let ci = check_input(&li, MdRepository::default());
+
+        let explanation = explain_check(adopters::ID, &ci).await;
+        assert!(explanation.output.unwrap().passed);
+        assert_eq!(
+            explanation.evidence,
+            vec![
+                Evidence {
+                    source: EvidenceSource::File,
+                    target: "adopters*, users*".to_string(),
+                    found: false,
+                    detail: None,
+                },
+                Evidence {
+                    source: EvidenceSource::Readme,
+                    target: r"(?im)^#+.*adopters.*$".to_string(),
+                    found: true,
+                    detail: None,
+                },
+                Evidence {
+                    source: EvidenceSource::Readme,
+                    target: r"(?im)^adopters$".to_string(),
+                    found: false,
+                    detail: None,
+                },
+                Evidence {
+                    source: EvidenceSource::Readme,
+                    target: r"(?i)\[.*adopters.*\]\(.*\)".to_string(),
+                    found: false,
+                    detail: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn explain_check_latest_release_ref_found() {
+        let root = tempfile::tempdir().unwrap();
+        let li = linter_input(root.path().to_owned(), changelog::ID);
+        let gh_md = MdRepository {
+            releases: MdRepositoryReleases {
+                nodes: Some(vec![Some(MdRepositoryReleasesNodes {
+                    created_at: "created_at_date".to_string(),
+                    description: Some("See the changelog for details".to_string()),
+                    is_latest: true,
+                    is_prerelease: false,
+                    release_assets: MdRepositoryReleasesNodesReleaseAssets { nodes: None },
+                    url: "release_url".to_string(),
+                })]),
+            },
+            ..MdRepository::default()
+        };
+        let ci = check_input(&li, gh_md);
+
+        let explanation = explain_check(changelog::ID, &ci).await;
+        assert!(explanation.output.unwrap().passed);
+        assert_eq!(
+            explanation.evidence.last(),
+            Some(&Evidence {
+                source: EvidenceSource::GitHub,
+                target: "changelog reference in latest release description".to_string(),
+                found: true,
+                detail: None,
+            })
+        );
+        assert!(
+            explanation.evidence[..explanation.evidence.len() - 1]
+                .iter()
+                .all(|evidence| !evidence.found)
+        );
+    }
+
+    #[tokio::test]
+    async fn explain_check_pull_request_check_found() {
+        let root = tempfile::tempdir().unwrap();
+        let li = linter_input(root.path().to_owned(), cla::ID);
+        let gh_md = MdRepository {
+            pull_requests: MdRepositoryPullRequests {
+                nodes: Some(vec![Some(MdRepositoryPullRequestsNodes {
+                    commits: MdRepositoryPullRequestsNodesCommits {
+                        nodes: Some(vec![Some(MdRepositoryPullRequestsNodesCommitsNodes {
+                            commit: MdRepositoryPullRequestsNodesCommitsNodesCommit {
+                                check_suites: Some(
+                                    MdRepositoryPullRequestsNodesCommitsNodesCommitCheckSuites {
+                                        nodes: Some(vec![Some(MdRepositoryPullRequestsNodesCommitsNodesCommitCheckSuitesNodes {
+                                            app: Some(MdRepositoryPullRequestsNodesCommitsNodesCommitCheckSuitesNodesApp {
+                                                name: "EasyCLA".to_string(),
+                                            }),
+                                            check_runs: None,
+                                        })]),
+                                    },
+                                ),
+                                status: None,
+                            },
+                        })]),
+                    },
+                })]),
+            },
+            ..MdRepository::default()
+        };
+        let ci = check_input(&li, gh_md);
+
+        let explanation = explain_check(cla::ID, &ci).await;
+        assert!(explanation.output.unwrap().passed);
+        assert_eq!(
+            explanation.evidence,
+            vec![Evidence {
+                source: EvidenceSource::GitHub,
+                target: "CLA check in latest merged PR".to_string(),
+                found: true,
+                detail: Some("EasyCLA".to_string()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn explain_check_scorecard_not_available() {
+        let root = tempfile::tempdir().unwrap();
+        let li = linter_input(root.path().to_owned(), maintained::ID);
+        let ci = check_input(&li, MdRepository::default());
+
+        let explanation = explain_check(maintained::ID, &ci).await;
+        assert!(!explanation.output.unwrap().passed);
+        assert_eq!(
+            explanation.evidence,
+            vec![Evidence {
+                source: EvidenceSource::Scorecard,
+                target: "Maintained".to_string(),
+                found: false,
+                detail: Some("no scorecard available".to_string()),
+            }]
+        );
+    }
+}
@@ -1,26 +1,47 @@
+use std::collections::BTreeMap;
 use std::ffi::OsStr;
+use std::fmt;
 use std::path::Path;
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use time::{
+    Date, OffsetDateTime, format_description::BorrowedFormatItem, macros::format_description,
+};
+use tracing::warn;
+use yaml_rust2::{
+    Event,
+    parser::{MarkedEventReceiver, Parser},
+    scanner::Marker,
+};
 
-use super::util;
+use super::{CHECKS, CheckSet, Report, datasource::github, util};
 
 /// Metadata file name.
 pub(crate) const METADATA_FILE: &str = ".clomonitor.yml";
 
 /// CLOMonitor metadata.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Metadata {
+pub struct Metadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<BTreeMap<String, String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub exemptions: Option<Vec<Exemption>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gates: Option<Gates>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub license_scanning: Option<LicenseScanning>,
 }
 
 impl Metadata {
     /// Create a new metadata instance from the contents of the file located at
     /// the path provided.
-    pub(crate) fn from<P: AsRef<OsStr>>(path: P) -> Result<Option<Self>> {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from<P: AsRef<OsStr>>(path: P) -> Result<Option<Self>> {
         if !Path::new(&path).exists() {
             return Ok(None);
         }
@@ -28,23 +49,456 @@ impl Metadata {
             .context("error reading clomonitor metadata file")?;
         Ok(serde_yaml::from_str(&content)?)
     }
+
+    /// Load the metadata of the repository provided, merging the metadata file
+    /// located at its root with the organization-wide one (located in the
+    /// organization's .github repository), if any.
+    pub(crate) async fn load(root: &Path, url: &str) -> Result<Option<Self>> {
+        let md = Self::from(root.join(METADATA_FILE))?;
+        let org_md = Self::from_org_file(github::org_file(url, METADATA_FILE).await);
+        Ok(match (md, org_md) {
+            (Some(md), Some(org_md)) => Some(md.merge(org_md)),
+            (md, org_md) => md.or(org_md),
+        })
+    }
+
+    /// Parse the content of the organization-wide metadata file provided. The
+    /// repository does not control this file, so errors fetching or parsing it
+    /// are logged as a warning and the file is ignored.
+    fn from_org_file(content: Result<Option<String>>) -> Option<Self> {
+        let org_md = content.and_then(|content| match content {
+            Some(content) => serde_yaml::from_str(&content)
+                .context("error parsing organization clomonitor metadata file"),
+            None => Ok(None),
+        });
+        match org_md {
+            Ok(org_md) => org_md,
+            Err(err) => {
+                warn!(?err, "error loading organization clomonitor metadata file");
+                None
+            }
+        }
+    }
+
+    /// Merge the organization-wide metadata provided into this one. Values
+    /// defined in the repository take precedence: exemptions are merged by
+    /// check (the repository ones replace the organization ones for the same
+    /// check), evidence urls are merged by check, required checks are combined,
+    /// minimum section scores are merged by section and the license scanning
+    /// url is only inherited when the repository does not define one.
+    fn merge(self, org_md: Metadata) -> Self {
+        // Exemptions
+        let exemptions = match (self.exemptions, org_md.exemptions) {
+            (Some(mut exemptions), Some(org_exemptions)) => {
+                for org_exemption in org_exemptions {
+                    if !exemptions.iter().any(|e| e.check == org_exemption.check) {
+                        exemptions.push(org_exemption);
+                    }
+                }
+                Some(exemptions)
+            }
+            (exemptions, org_exemptions) => exemptions.or(org_exemptions),
+        };
+
+        // Evidence
+        let evidence = match (self.evidence, org_md.evidence) {
+            (Some(evidence), Some(mut org_evidence)) => {
+                org_evidence.extend(evidence);
+                Some(org_evidence)
+            }
+            (evidence, org_evidence) => evidence.or(org_evidence),
+        };
+
+        // Gates
+        let gates = match (self.gates, org_md.gates) {
+            (Some(gates), Some(org_gates)) => {
+                let required_checks = match (gates.required_checks, org_gates.required_checks) {
+                    (Some(mut required_checks), Some(org_required_checks)) => {
+                        for check_id in org_required_checks {
+                            if !required_checks.contains(&check_id) {
+                                required_checks.push(check_id);
+                            }
+                        }
+                        Some(required_checks)
+                    }
+                    (required_checks, org_required_checks) => {
+                        required_checks.or(org_required_checks)
+                    }
+                };
+                let min_section_scores =
+                    match (gates.min_section_scores, org_gates.min_section_scores) {
+                        (Some(min_section_scores), Some(mut org_min_section_scores)) => {
+                            org_min_section_scores.extend(min_section_scores);
+                            Some(org_min_section_scores)
+                        }
+                        (min_section_scores, org_min_section_scores) => {
+                            min_section_scores.or(org_min_section_scores)
+                        }
+                    };
+                Some(Gates {
+                    required_checks,
+                    min_section_scores,
+                })
+            }
+            (gates, org_gates) => gates.or(org_gates),
+        };
+
+        // License scanning
+        let license_scanning = match self.license_scanning {
+            Some(LicenseScanning { url: Some(url) }) => Some(LicenseScanning { url: Some(url) }),
+            license_scanning => org_md.license_scanning.or(license_scanning),
+        };
+
+        Metadata {
+            evidence,
+            exemptions,
+            gates,
+            license_scanning,
+        }
+    }
 }
 
+/// Format used for the dates in the metadata file.
+const DATE_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+time::serde::format_description!(date_format, Date, DATE_FORMAT);
+
 /// Metadata check exemption entry.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
-pub(crate) struct Exemption {
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Exemption {
     pub check: String,
     pub reason: String,
+    #[serde(
+        default,
+        with = "date_format::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expires: Option<Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Vec<CheckSet>>,
+}
+
+impl Exemption {
+    /// Check if the exemption has expired on the date provided. Exemptions
+    /// are valid until the end of their expiration date.
+    pub(crate) fn expired(&self, today: Date) -> bool {
+        self.expires.is_some_and(|expires| expires < today)
+    }
+
+    /// Check if the exemption applies to a repository using the check sets
+    /// provided. Exemptions without a scope apply to all check sets.
+    pub(crate) fn in_scope(&self, check_sets: &[CheckSet]) -> bool {
+        self.scope
+            .as_ref()
+            .is_none_or(|scope| scope.iter().any(|check_set| check_sets.contains(check_set)))
+    }
+}
+
+/// Gates section of the metadata, used by the linter CLI to enforce some
+/// requirements regardless of the global score.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Gates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_checks: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_section_scores: Option<BTreeMap<String, f64>>,
 }
 
 /// License scanning section of the metadata.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
-pub(crate) struct LicenseScanning {
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LicenseScanning {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
+/// Keys allowed in the metadata file top level.
+const METADATA_KEYS: [&str; 4] = ["evidence", "exemptions", "gates", "licenseScanning"];
+
+/// Keys allowed in each of the exemptions entries.
+const EXEMPTION_KEYS: [&str; 5] = ["check", "reason", "expires", "approvedBy", "scope"];
+
+/// Keys allowed in the gates section.
+const GATES_KEYS: [&str; 2] = ["requiredChecks", "minSectionScores"];
+
+/// Keys allowed in the license scanning section.
+const LICENSE_SCANNING_KEYS: [&str; 1] = ["url"];
+
+/// Issue found while validating a metadata file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataIssue {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for MetadataIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Validate the content of a metadata file, returning all the issues found.
+/// In addition to the errors that would prevent the file from being loaded,
+/// unknown keys, unknown check identifiers and exemptions that would be
+/// silently ignored (i.e. empty reason) are reported.
+#[must_use]
+pub fn validate(content: &str) -> Vec<MetadataIssue> {
+    // Parse the content keeping track of the line where each node starts
+    let mut builder = NodeBuilder::default();
+    if let Err(err) = Parser::new_from_str(content).load(&mut builder, false) {
+        return vec![MetadataIssue {
+            line: err.marker().line(),
+            message: format!("invalid yaml: {}", err.info()),
+        }];
+    }
+
+    let mut issues = Vec::new();
+    if let Some(root) = &builder.root {
+        validate_keys(root, &METADATA_KEYS, &mut issues);
+        if let Some(exemptions) = root.get("exemptions") {
+            for exemption in exemptions.items() {
+                validate_exemption(exemption, &mut issues);
+            }
+        }
+        if let Some(evidence) = root.get("evidence") {
+            for (check, _) in evidence.entries() {
+                validate_check_id(check, &mut issues);
+            }
+        }
+        if let Some(gates) = root.get("gates") {
+            validate_gates(gates, &mut issues);
+        }
+        if let Some(license_scanning) = root.get("licenseScanning") {
+            validate_keys(license_scanning, &LICENSE_SCANNING_KEYS, &mut issues);
+        }
+    }
+
+    // Invalid types and missing fields are reported by the deserializer
+    if let Err(err) = serde_yaml::from_str::<Option<Metadata>>(content) {
+        issues.push(MetadataIssue {
+            line: err.location().map_or(1, |location| location.line()),
+            message: err.to_string(),
+        });
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Validate an exemption entry.
+fn validate_exemption(exemption: &Node, issues: &mut Vec<MetadataIssue>) {
+    validate_keys(exemption, &EXEMPTION_KEYS, issues);
+    let check = exemption.get("check").and_then(|check| {
+        validate_check_id(check, issues);
+        check.as_str()
+    });
+    if let Some(reason) = exemption.get("reason")
+        && reason.as_str().is_some_and(str::is_empty)
+    {
+        issues.push(MetadataIssue {
+            line: reason.line,
+            message: format!(
+                "empty reason in exemption for check {}, it will be ignored",
+                check.unwrap_or_default()
+            ),
+        });
+    }
+    if let Some(expires) = exemption.get("expires")
+        && let Some(date) = expires
+            .as_str()
+            .and_then(|value| Date::parse(value, DATE_FORMAT).ok())
+        && date < OffsetDateTime::now_utc().date()
+    {
+        issues.push(MetadataIssue {
+            line: expires.line,
+            message: format!(
+                "exemption for check {} expired on {}, it will be ignored",
+                check.unwrap_or_default(),
+                expires.as_str().unwrap_or_default()
+            ),
+        });
+    }
+}
+
+/// Validate the gates section.
+fn validate_gates(gates: &Node, issues: &mut Vec<MetadataIssue>) {
+    validate_keys(gates, &GATES_KEYS, issues);
+    if let Some(required_checks) = gates.get("requiredChecks") {
+        for check in required_checks.items() {
+            validate_check_id(check, issues);
+        }
+    }
+    if let Some(min_section_scores) = gates.get("minSectionScores") {
+        let sections: Vec<&str> = Report::default()
+            .sections_outputs()
+            .into_iter()
+            .map(|(section, _)| section)
+            .collect();
+        for (section, _) in min_section_scores.entries() {
+            if let Some(name) = section.as_str()
+                && !sections.contains(&name)
+            {
+                issues.push(MetadataIssue {
+                    line: section.line,
+                    message: format!("unknown section {name}"),
+                });
+            }
+        }
+    }
+}
+
+/// Verify that the node provided contains a registered check identifier.
+fn validate_check_id(check: &Node, issues: &mut Vec<MetadataIssue>) {
+    if let Some(check_id) = check.as_str()
+        && !CHECKS.contains_key(check_id)
+    {
+        issues.push(MetadataIssue {
+            line: check.line,
+            message: format!("unknown check {check_id}"),
+        });
+    }
+}
+
+/// Verify that the mapping node provided only contains the keys allowed.
+fn validate_keys(node: &Node, allowed_keys: &[&str], issues: &mut Vec<MetadataIssue>) {
+    for (key, _) in node.entries() {
+        if let Some(name) = key.as_str()
+            && !allowed_keys.contains(&name)
+        {
+            issues.push(MetadataIssue {
+                line: key.line,
+                message: format!(
+                    "unknown key {name} (expected one of: {})",
+                    allowed_keys.join(", ")
+                ),
+            });
+        }
+    }
+}
+
+/// YAML node, including the line where it starts in the source.
+#[derive(Debug)]
+struct Node {
+    line: usize,
+    kind: NodeKind,
+}
+
+/// YAML node kind.
+#[derive(Debug)]
+enum NodeKind {
+    Scalar(String),
+    Sequence(Vec<Node>),
+    Mapping(Vec<(Node, Node)>),
+}
+
+impl Node {
+    /// Return the node value if it is a scalar.
+    fn as_str(&self) -> Option<&str> {
+        match &self.kind {
+            NodeKind::Scalar(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Return the node items if it is a sequence.
+    fn items(&self) -> &[Node] {
+        match &self.kind {
+            NodeKind::Sequence(items) => items,
+            _ => &[],
+        }
+    }
+
+    /// Return the node entries if it is a mapping.
+    fn entries(&self) -> &[(Node, Node)] {
+        match &self.kind {
+            NodeKind::Mapping(entries) => entries,
+            _ => &[],
+        }
+    }
+
+    /// Return the value of the key provided if the node is a mapping.
+    fn get(&self, key: &str) -> Option<&Node> {
+        self.entries()
+            .iter()
+            .find(|(k, _)| k.as_str() == Some(key))
+            .map(|(_, value)| value)
+    }
+}
+
+/// YAML parser events receiver that builds a tree of nodes.
+#[derive(Debug, Default)]
+struct NodeBuilder {
+    /// Collections being built, along with the pending key in mappings.
+    stack: Vec<(Node, Option<Node>)>,
+    root: Option<Node>,
+}
+
+impl NodeBuilder {
+    /// Add the node provided to the collection being built (or set it as the
+    /// root node when there is none).
+    fn add(&mut self, node: Node) {
+        let Some((parent, pending_key)) = self.stack.last_mut() else {
+            self.root.get_or_insert(node);
+            return;
+        };
+        match &mut parent.kind {
+            NodeKind::Sequence(items) => items.push(node),
+            NodeKind::Mapping(entries) => match pending_key.take() {
+                Some(key) => entries.push((key, node)),
+                None => *pending_key = Some(node),
+            },
+            NodeKind::Scalar(_) => {}
+        }
+    }
+}
+
+impl MarkedEventReceiver for NodeBuilder {
+    fn on_event(&mut self, ev: Event, mark: Marker) {
+        let line = mark.line();
+        match ev {
+            Event::Scalar(value, ..) => self.add(Node {
+                line,
+                kind: NodeKind::Scalar(value),
+            }),
+            Event::Alias(_) => self.add(Node {
+                line,
+                kind: NodeKind::Scalar(String::new()),
+            }),
+            Event::SequenceStart(..) => self.stack.push((
+                Node {
+                    line,
+                    kind: NodeKind::Sequence(Vec::new()),
+                },
+                None,
+            )),
+            Event::MappingStart(..) => self.stack.push((
+                Node {
+                    line,
+                    kind: NodeKind::Mapping(Vec::new()),
+                },
+                None,
+            )),
+            Event::SequenceEnd | Event::MappingEnd => {
+                if let Some((node, _)) = self.stack.pop() {
+                    self.add(node);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::fs;
+
+    use anyhow::format_err;
+    use time::macros::date;
+
     use super::*;
 
     const TESTDATA_PATH: &str = "src/testdata";
@@ -56,17 +510,189 @@ mod tests {
                 .unwrap()
                 .unwrap(),
             Metadata {
+                evidence: None,
                 license_scanning: Some(LicenseScanning {
                     url: Some("https://license-scanning-results.url".to_string()),
                 }),
                 exemptions: Some(vec![Exemption {
                     check: "artifacthub_badge".to_string(),
                     reason: "this is a sample reason".to_string(),
-                }])
+                    expires: Some(date!(2999 - 12 - 31)),
+                    approved_by: Some("https://github.com/cncf/toc/issues/1".to_string()),
+                    scope: Some(vec![CheckSet::Community]),
+                }]),
+                gates: Some(Gates {
+                    required_checks: Some(vec!["license_approved".to_string()]),
+                    min_section_scores: Some(BTreeMap::from([("security".to_string(), 80.0)])),
+                }),
             },
         );
     }
 
+    #[test]
+    fn from_org_file_success() {
+        assert_eq!(
+            Metadata::from_org_file(Ok(Some("exemptions: []".to_string()))),
+            Some(Metadata {
+                exemptions: Some(vec![]),
+                ..Metadata::default()
+            })
+        );
+    }
+
+    #[test]
+    fn from_org_file_not_found() {
+        assert_eq!(Metadata::from_org_file(Ok(None)), None);
+    }
+
+    #[test]
+    fn from_org_file_fetch_error_is_ignored() {
+        assert_eq!(
+            Metadata::from_org_file(Err(format_err!("unexpected status code"))),
+            None
+        );
+    }
+
+    #[test]
+    fn from_org_file_parse_error_is_ignored() {
+        assert_eq!(
+            Metadata::from_org_file(Ok(Some("exemptions: invalid".to_string()))),
+            None
+        );
+    }
+
+    #[test]
+    fn merge_gives_precedence_to_repository_metadata() {
+        let md = Metadata {
+            evidence: Some(BTreeMap::from([(
+                "adopters".to_string(),
+                "https://project.io/adopters".to_string(),
+            )])),
+            exemptions: Some(vec![Exemption {
+                check: "artifacthub_badge".to_string(),
+                reason: "repository reason".to_string(),
+                ..Exemption::default()
+            }]),
+            gates: Some(Gates {
+                required_checks: Some(vec!["license_approved".to_string()]),
+                min_section_scores: Some(BTreeMap::from([("security".to_string(), 90.0)])),
+            }),
+            license_scanning: Some(LicenseScanning { url: None }),
+        };
+        let org_md = Metadata {
+            evidence: Some(BTreeMap::from([
+                (
+                    "adopters".to_string(),
+                    "https://org.io/adopters".to_string(),
+                ),
+                ("roadmap".to_string(), "https://org.io/roadmap".to_string()),
+            ])),
+            exemptions: Some(vec![
+                Exemption {
+                    check: "artifacthub_badge".to_string(),
+                    reason: "organization reason".to_string(),
+                    ..Exemption::default()
+                },
+                Exemption {
+                    check: "slack_presence".to_string(),
+                    reason: "organization reason".to_string(),
+                    ..Exemption::default()
+                },
+            ]),
+            gates: Some(Gates {
+                required_checks: Some(vec![
+                    "license_approved".to_string(),
+                    "security_policy".to_string(),
+                ]),
+                min_section_scores: Some(BTreeMap::from([
+                    ("legal".to_string(), 100.0),
+                    ("security".to_string(), 80.0),
+                ])),
+            }),
+            license_scanning: Some(LicenseScanning {
+                url: Some("https://org-license-scanning-results.url".to_string()),
+            }),
+        };
+
+        assert_eq!(
+            md.merge(org_md),
+            Metadata {
+                evidence: Some(BTreeMap::from([
+                    (
+                        "adopters".to_string(),
+                        "https://project.io/adopters".to_string()
+                    ),
+                    ("roadmap".to_string(), "https://org.io/roadmap".to_string()),
+                ])),
+                exemptions: Some(vec![
+                    Exemption {
+                        check: "artifacthub_badge".to_string(),
+                        reason: "repository reason".to_string(),
+                        ..Exemption::default()
+                    },
+                    Exemption {
+                        check: "slack_presence".to_string(),
+                        reason: "organization reason".to_string(),
+                        ..Exemption::default()
+                    },
+                ]),
+                gates: Some(Gates {
+                    required_checks: Some(vec![
+                        "license_approved".to_string(),
+                        "security_policy".to_string(),
+                    ]),
+                    min_section_scores: Some(BTreeMap::from([
+                        ("legal".to_string(), 100.0),
+                        ("security".to_string(), 90.0),
+                    ])),
+                }),
+                license_scanning: Some(LicenseScanning {
+                    url: Some("https://org-license-scanning-results.url".to_string()),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn merge_keeps_repository_license_scanning_url() {
+        let md = Metadata {
+            evidence: None,
+            exemptions: None,
+            gates: None,
+            license_scanning: Some(LicenseScanning {
+                url: Some("https://license-scanning-results.url".to_string()),
+            }),
+        };
+        let org_md = Metadata {
+            evidence: None,
+            exemptions: Some(vec![Exemption {
+                check: "slack_presence".to_string(),
+                reason: "organization reason".to_string(),
+                ..Exemption::default()
+            }]),
+            gates: None,
+            license_scanning: Some(LicenseScanning {
+                url: Some("https://org-license-scanning-results.url".to_string()),
+            }),
+        };
+
+        assert_eq!(
+            md.merge(org_md),
+            Metadata {
+                evidence: None,
+                exemptions: Some(vec![Exemption {
+                    check: "slack_presence".to_string(),
+                    reason: "organization reason".to_string(),
+                    ..Exemption::default()
+                }]),
+                gates: None,
+                license_scanning: Some(LicenseScanning {
+                    url: Some("https://license-scanning-results.url".to_string()),
+                }),
+            }
+        );
+    }
+
     #[test]
     fn metadata_from_path_not_found() {
         assert!(matches!(
@@ -79,4 +705,84 @@ mod tests {
     fn metadata_from_path_invalid_metadata_file() {
         assert!(Metadata::from(Path::new(TESTDATA_PATH).join(".clomonitor-invalid.yaml")).is_err());
     }
+
+    #[test]
+    fn validate_valid_metadata() {
+        let content = fs::read_to_string(Path::new(TESTDATA_PATH).join(METADATA_FILE)).unwrap();
+        assert!(validate(&content).is_empty());
+    }
+
+    #[test]
+    fn validate_reports_issues_with_line_numbers() {
+        let content = r#"
+exemptions:
+  - check: artifacthub_badge
+    reason: ""
+  - check: unknown_check
+    reason: "sample reason"
+    comment: "unexpected"
+gates:
+  requiredChecks:
+    - license_approved
+    - another_unknown_check
+  minSectionScores:
+    unknown_section: 80
+unknownKey: true
+"#;
+
+        assert_eq!(
+            validate(content)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "line 4: empty reason in exemption for check artifacthub_badge, it will be ignored",
+                "line 5: unknown check unknown_check",
+                "line 7: unknown key comment (expected one of: check, reason, expires, approvedBy, scope)",
+                "line 11: unknown check another_unknown_check",
+                "line 13: unknown section unknown_section",
+                "line 14: unknown key unknownKey (expected one of: evidence, exemptions, gates, licenseScanning)",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_reports_expired_exemptions() {
+        let content = r"
+exemptions:
+  - check: artifacthub_badge
+    reason: sample reason
+    expires: 2000-01-01
+";
+
+        assert_eq!(
+            validate(content),
+            vec![MetadataIssue {
+                line: 5,
+                message: "exemption for check artifacthub_badge expired on 2000-01-01, it will be ignored"
+                    .to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_reports_invalid_types() {
+        let content = "exemptions:\n  - check: artifacthub_badge\n    reason: [a, b]\n";
+
+        let issues = validate(content);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, 3);
+    }
+
+    #[test]
+    fn validate_reports_invalid_yaml() {
+        assert_eq!(
+            validate("exemptions:\n  - check: [artifacthub_badge\n"),
+            vec![MetadataIssue {
+                line: 3,
+                message: "invalid yaml: while parsing a flow sequence, expected ',' or ']'"
+                    .to_string(),
+            }]
+        );
+    }
 }
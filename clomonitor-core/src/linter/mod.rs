@@ -11,21 +11,72 @@ use time::Date;
 
 use self::{
     check::*,
-    checks::util::helpers::{find_exemption, should_skip_check},
+    checks::util::helpers::{
+        apply_evidence, find_exemption, find_expired_exemption, should_skip_check,
+    },
 };
 
 mod check;
 mod checks;
+mod explain;
 mod metadata;
 mod report;
 
 pub use self::{
-    check::{CheckId, CheckOutput},
+    check::{CheckId, CheckOutput, Evidence, EvidenceSource},
+    explain::{Explanation, explain},
+    metadata::{
+        Exemption, Gates, LicenseScanning, Metadata, MetadataIssue, validate as validate_metadata,
+    },
     report::*,
 };
 pub use checks::datasource::github::setup_http_client as setup_github_http_client;
 pub(crate) use checks::*;
 
+/// Return the score weight of the check provided.
+#[must_use]
+pub fn check_weight(check_id: &str) -> Option<usize> {
+    CHECKS.get(check_id).map(|check| check.weight)
+}
+
+/// Return the anchor of the documentation section of the check provided.
+#[must_use]
+pub fn check_docs_anchor(check_id: &str) -> Option<&'static str> {
+    CHECKS.get(check_id).map(|check| check.docs_anchor)
+}
+
+/// Details of a registered check.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckInfo {
+    pub id: CheckId,
+    pub name: &'static str,
+    pub weight: usize,
+    pub check_sets: Vec<CheckSet>,
+    pub section: &'static str,
+}
+
+/// Return the details of all the registered checks, ordered by section as
+/// they appear in the report.
+#[must_use]
+pub fn registered_checks() -> Vec<CheckInfo> {
+    Report::default()
+        .sections_outputs()
+        .into_iter()
+        .flat_map(|(section, outputs)| {
+            outputs.into_iter().map(move |(check_id, _)| {
+                let check = &CHECKS[check_id];
+                CheckInfo {
+                    id: check_id,
+                    name: check.name,
+                    weight: check.weight,
+                    check_sets: check.check_sets.clone(),
+                    section,
+                }
+            })
+        })
+        .collect()
+}
+
 /// Type alias to represent a Linter trait object.
 pub type DynLinter = Arc<dyn Linter + Send + Sync>;
 
@@ -42,9 +93,23 @@ pub trait Linter {
 pub struct LinterInput {
     pub project: Option<Project>,
     pub root: PathBuf,
+    /// Subdirectory of the repository where the project is located, relative
+    /// to the root (for repositories hosting multiple projects).
+    pub path: Option<PathBuf>,
     pub url: String,
     pub check_sets: Vec<CheckSet>,
+    pub checks: Vec<String>,
+    pub skip_checks: Vec<String>,
     pub github_token: String,
+    pub responsiveness_slo: ResponsivenessSlo,
+    /// Groups of equivalent checks. When any of the checks in a group passes,
+    /// the other checks in the group are exempt. When provided, they replace
+    /// the default ones.
+    pub check_equivalences: Option<Vec<Vec<String>>>,
+    /// Chat platforms preferred by the project's foundation. When provided,
+    /// the community chat check only passes if a channel in any of them is
+    /// found.
+    pub preferred_chat_platforms: Vec<ChatPlatform>,
 }
 
 /// Project's details
@@ -63,6 +128,51 @@ pub struct Foundation {
     pub landscape_url: Option<String>,
 }
 
+/// Service level objectives the responsiveness check is evaluated against.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResponsivenessSlo {
+    /// Maximum median time to first response, in hours.
+    pub first_response_hours: u64,
+    /// Maximum median time to close, in hours.
+    pub close_hours: u64,
+}
+
+impl Default for ResponsivenessSlo {
+    fn default() -> Self {
+        Self {
+            first_response_hours: 72,
+            close_hours: 720,
+        }
+    }
+}
+
+/// Platforms used by projects to host their community chat channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChatPlatform {
+    Discord,
+    Gitter,
+    MailingList,
+    Matrix,
+    Slack,
+    Zulip,
+}
+
+impl fmt::Display for ChatPlatform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self {
+            Self::Discord => "Discord",
+            Self::Gitter => "Gitter",
+            Self::MailingList => "Mailing list",
+            Self::Matrix => "Matrix",
+            Self::Slack => "Slack",
+            Self::Zulip => "Zulip",
+        };
+        write!(f, "{output}")
+    }
+}
+
 /// Check sets define a set of checks that will be run on a given repository.
 /// Multiple check sets can be assigned to a repository.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, ValueEnum, Serialize, Deserialize, ToSql)]
@@ -101,79 +211,122 @@ impl CoreLinter {
     pub fn new() -> Self {
         Self
     }
+
+    /// Lint the repository provided returning a report with the results,
+    /// along with the repository's metadata (including the one inherited from
+    /// the organization's metadata file) used to produce it.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn lint_with_metadata(&self, li: &LinterInput) -> Result<(Report, Option<Metadata>)> {
+        // Prepare check input
+        let ci = CheckInput::new(li).await?;
+
+        // Run checks and apply inter-checks exemptions
+        let mut report = run_checks(&ci).await;
+        report.apply_exemptions(li.check_equivalences.as_deref());
+
+        Ok((report, ci.cm_md))
+    }
 }
 
 #[async_trait]
 impl Linter for CoreLinter {
     async fn lint(&self, li: &LinterInput) -> Result<Report> {
-        // Prepare check input
-        let ci = CheckInput::new(li).await?;
+        let (report, _) = self.lint_with_metadata(li).await?;
+        Ok(report)
+    }
+}
 
-        // Run some async checks concurrently
-        let (analytics, contributing, summary_table, trademark_disclaimer) = tokio::join!(
-            run_async!(analytics, &ci),
-            run_async!(contributing, &ci),
-            run_async!(summary_table, &ci),
-            run_async!(trademark_disclaimer, &ci),
-        );
-
-        // Run some sync checks needed in advance
-        let spdx_id = run!(license_spdx_id, &ci);
-        let mut spdx_id_value: Option<String> = None;
-        if let Some(r) = &spdx_id {
-            spdx_id_value.clone_from(&r.value);
-        }
+/// Run the checks selected in the check input provided, returning a report
+/// with their outputs. Inter-checks exemptions are not applied.
+#[allow(clippy::too_many_lines)]
+pub(crate) async fn run_checks(ci: &CheckInput<'_>) -> Report {
+    // Run some async checks concurrently
+    let (
+        analytics,
+        broken_links,
+        community_chat,
+        contributing,
+        issue_pr_templates,
+        summary_table,
+        trademark_disclaimer,
+        website_hygiene,
+    ) = tokio::join!(
+        run_async!(analytics, ci),
+        run_async!(broken_links, ci),
+        run_async!(community_chat, ci),
+        run_async!(contributing, ci),
+        run_async!(issue_pr_templates, ci),
+        run_async!(summary_table, ci),
+        run_async!(trademark_disclaimer, ci),
+        run_async!(website_hygiene, ci),
+    );
 
-        // Run the remaining sync checks and build report
-        let mut report = Report {
-            documentation: Documentation {
-                adopters: run!(adopters, &ci),
-                changelog: run!(changelog, &ci),
-                code_of_conduct: run!(code_of_conduct, &ci),
-                contributing,
-                governance: run!(governance, &ci),
-                maintainers: run!(maintainers, &ci),
-                readme: run!(readme, &ci),
-                roadmap: run!(roadmap, &ci),
-                summary_table,
-                website: run!(website, &ci),
-            },
-            license: License {
-                license_approved: license_approved::check(&ci, spdx_id_value),
-                license_scanning: run!(license_scanning, &ci),
-                license_spdx_id: spdx_id,
-            },
-            best_practices: BestPractices {
-                analytics,
-                artifacthub_badge: run!(artifacthub_badge, &ci),
-                cla: run!(cla, &ci),
-                community_meeting: run!(community_meeting, &ci),
-                dco: run!(dco, &ci),
-                github_discussions: run!(github_discussions, &ci),
-                openssf_badge: run!(openssf_badge, &ci),
-                openssf_scorecard_badge: run!(openssf_scorecard_badge, &ci),
-                recent_release: run!(recent_release, &ci),
-                slack_presence: run!(slack_presence, &ci),
-            },
-            security: Security {
-                binary_artifacts: run!(binary_artifacts, &ci),
-                code_review: run!(code_review, &ci),
-                dangerous_workflow: run!(dangerous_workflow, &ci),
-                dependencies_policy: run!(dependencies_policy, &ci),
-                dependency_update_tool: run!(dependency_update_tool, &ci),
-                maintained: run!(maintained, &ci),
-                sbom: run!(sbom, &ci),
-                security_insights: run!(security_insights, &ci),
-                security_policy: run!(security_policy, &ci),
-                signed_releases: run!(signed_releases, &ci),
-                token_permissions: run!(token_permissions, &ci),
-            },
-            legal: Legal {
-                trademark_disclaimer,
-            },
-        };
-        report.apply_exemptions();
+    // Run some sync checks needed in advance
+    let spdx_id = run!(license_spdx_id, ci);
+    let mut spdx_id_value: Option<String> = None;
+    if let Some(r) = &spdx_id {
+        spdx_id_value.clone_from(&r.value);
+    } else if !should_skip_check(license_approved::ID, ci.li) {
+        // The license approved check relies on the SPDX id, even when the
+        // license SPDX id check has not been selected
+        spdx_id_value = license_spdx_id::check(ci)
+            .ok()
+            .and_then(|output| output.value);
+    }
 
-        Ok(report)
+    // Run the remaining sync checks and build report
+    Report {
+        schema_version: REPORT_SCHEMA_VERSION,
+        documentation: Documentation {
+            adopters: run!(adopters, ci),
+            broken_links,
+            changelog: run!(changelog, ci),
+            code_of_conduct: run!(code_of_conduct, ci),
+            contributing,
+            governance: run!(governance, ci),
+            maintainers: run!(maintainers, ci),
+            readme: run!(readme, ci),
+            roadmap: run!(roadmap, ci),
+            summary_table,
+            website: run!(website, ci),
+            website_hygiene,
+        },
+        license: License {
+            license_approved: license_approved::check(ci, spdx_id_value),
+            license_scanning: run!(license_scanning, ci),
+            license_spdx_id: spdx_id,
+        },
+        best_practices: BestPractices {
+            analytics,
+            artifacthub_badge: run!(artifacthub_badge, ci),
+            cla: run!(cla, ci),
+            community_chat,
+            community_meeting: run!(community_meeting, ci),
+            contributor_diversity: run!(contributor_diversity, ci),
+            dco: run!(dco, ci),
+            github_discussions: run!(github_discussions, ci),
+            issue_pr_templates,
+            openssf_badge: run!(openssf_badge, ci),
+            openssf_scorecard_badge: run!(openssf_scorecard_badge, ci),
+            recent_release: run!(recent_release, ci),
+            responsiveness: run!(responsiveness, ci),
+            slack_presence: run!(slack_presence, ci),
+        },
+        security: Security {
+            binary_artifacts: run!(binary_artifacts, ci),
+            code_review: run!(code_review, ci),
+            dangerous_workflow: run!(dangerous_workflow, ci),
+            dependencies_policy: run!(dependencies_policy, ci),
+            dependency_update_tool: run!(dependency_update_tool, ci),
+            maintained: run!(maintained, ci),
+            sbom: run!(sbom, ci),
+            security_insights: run!(security_insights, ci),
+            security_policy: run!(security_policy, ci),
+            signed_releases: run!(signed_releases, ci),
+            token_permissions: run!(token_permissions, ci),
+        },
+        legal: Legal {
+            trademark_disclaimer,
+        },
     }
 }
@@ -1,10 +1,85 @@
-use serde::{Deserialize, Serialize};
+use anyhow::{Result, format_err};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
 
 use super::{CheckOutput, check::CheckId, checks::*};
 
+pub use super::checks::contributor_diversity::ContributorDiversity;
+pub use super::checks::responsiveness::{Responsiveness, ResponsivenessStats};
+
+/// Type alias to represent the outputs of the checks in a report section.
+pub type SectionOutputs = Vec<(CheckId, Option<CheckOutput>)>;
+
+/// Type alias to represent the checks in a report, indexed by check id and
+/// ordered as they appear in the report.
+pub type CheckMap = IndexMap<CheckId, ReportCheck>;
+
+/// Report section details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionInfo {
+    pub id: &'static str,
+    pub name: &'static str,
+}
+
+/// Sections of the report, in the order they appear in it.
+pub const REPORT_SECTIONS: [SectionInfo; 5] = [
+    SectionInfo {
+        id: "documentation",
+        name: "Documentation",
+    },
+    SectionInfo {
+        id: "license",
+        name: "License",
+    },
+    SectionInfo {
+        id: "best_practices",
+        name: "Best practices",
+    },
+    SectionInfo {
+        id: "security",
+        name: "Security",
+    },
+    SectionInfo {
+        id: "legal",
+        name: "Legal",
+    },
+];
+
+/// Check entry in the report's check map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportCheck {
+    pub name: &'static str,
+    pub section: SectionInfo,
+
+    /// Check output, including its value serialized as json. Checks that were
+    /// not run have no output.
+    pub output: Option<CheckOutput<Value>>,
+}
+
+/// Groups of equivalent checks applied to reports when no custom groups are
+/// provided: when any of the checks in a group passes, the other checks in the
+/// group are exempt.
+pub const DEFAULT_CHECK_EQUIVALENCES: [&[CheckId]; 2] = [
+    &[cla::ID, dco::ID],
+    &[slack_presence::ID, github_discussions::ID],
+];
+
+/// Version of the report schema produced by the linter. It must be increased
+/// every time the shape of the report changes in a way that requires stored
+/// reports to be upgraded (i.e. a check is removed, renamed or moved to a
+/// different section), adding the corresponding upgrade to `REPORT_UPGRADES`.
+pub const REPORT_SCHEMA_VERSION: u64 = 2;
+
+/// Upgrades applied to stored reports to adapt them to the current schema.
+/// The upgrade at index `i` takes a report from version `i + 1` to `i + 2`.
+const REPORT_UPGRADES: [fn(&mut Value); 1] = [upgrade_report_v1];
+
 /// Linter report.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "Value")]
 pub struct Report {
+    pub schema_version: u64,
     pub documentation: Documentation,
     pub license: License,
     pub best_practices: BestPractices,
@@ -12,50 +87,191 @@ pub struct Report {
     pub legal: Legal,
 }
 
+impl Default for Report {
+    fn default() -> Self {
+        Self {
+            schema_version: REPORT_SCHEMA_VERSION,
+            documentation: Documentation::default(),
+            license: License::default(),
+            best_practices: BestPractices::default(),
+            security: Security::default(),
+            legal: Legal::default(),
+        }
+    }
+}
+
+impl TryFrom<Value> for Report {
+    type Error = anyhow::Error;
+
+    fn try_from(mut data: Value) -> Result<Self> {
+        upgrade_report_data(&mut data)?;
+
+        Ok(Report {
+            schema_version: REPORT_SCHEMA_VERSION,
+            documentation: report_section(&mut data, "documentation")?,
+            license: report_section(&mut data, "license")?,
+            best_practices: report_section(&mut data, "best_practices")?,
+            security: report_section(&mut data, "security")?,
+            legal: report_section(&mut data, "legal")?,
+        })
+    }
+}
+
 impl Report {
-    /// Apply inter-checks exemptions.
-    pub(crate) fn apply_exemptions(&mut self) {
-        let passed = |o: Option<&CheckOutput>| -> bool {
-            match o {
-                Some(o) => o.passed || o.exempt,
-                None => false,
-            }
-        };
+    /// Return the outputs (without values) of the checks available in the
+    /// report, along with their identifiers.
+    #[must_use]
+    pub fn outputs(&self) -> Vec<(CheckId, CheckOutput)> {
+        self.sections_outputs()
+            .into_iter()
+            .flat_map(|(_, outputs)| outputs)
+            .filter_map(|(check_id, output)| output.map(|output| (check_id, output)))
+            .collect()
+    }
 
-        // CLA / DCO
-        if passed(self.best_practices.cla.as_ref()) && !passed(self.best_practices.dco.as_ref()) {
-            self.best_practices.dco =
-                Some(CheckOutput::exempt().exemption_reason(Some("CLA check passed".to_string())));
-        }
-        if passed(self.best_practices.dco.as_ref()) && !passed(self.best_practices.cla.as_ref()) {
-            self.best_practices.cla =
-                Some(CheckOutput::exempt().exemption_reason(Some("DCO check passed".to_string())));
-        }
+    /// Return the outputs (without values) of all the checks in the report,
+    /// grouped by section. Checks that were not run have no output.
+    #[must_use]
+    pub fn sections_outputs(&self) -> Vec<(&'static str, SectionOutputs)> {
+        REPORT_SECTIONS
+            .iter()
+            .zip([
+                self.documentation.outputs(),
+                self.license.outputs(),
+                self.best_practices.outputs(),
+                self.security.outputs(),
+                self.legal.outputs(),
+            ])
+            .map(|(section, outputs)| (section.id, outputs))
+            .collect()
+    }
 
-        // Slack presence / GitHub discussions
-        if passed(self.best_practices.slack_presence.as_ref())
-            && !passed(self.best_practices.github_discussions.as_ref())
-        {
-            self.best_practices.github_discussions = Some(
-                CheckOutput::exempt()
-                    .exemption_reason(Some("Slack presence check passed".to_string())),
-            );
-        }
-        if passed(self.best_practices.github_discussions.as_ref())
-            && !passed(self.best_practices.slack_presence.as_ref())
-        {
-            self.best_practices.slack_presence = Some(
-                CheckOutput::exempt()
-                    .exemption_reason(Some("Github discussions check passed".to_string())),
-            );
+    /// Return all the checks in the report, along with their sections and
+    /// outputs (including their values), ordered as they appear in it.
+    #[must_use]
+    pub fn checks(&self) -> CheckMap {
+        REPORT_SECTIONS
+            .iter()
+            .zip([
+                self.documentation.json_outputs(),
+                self.license.json_outputs(),
+                self.best_practices.json_outputs(),
+                self.security.json_outputs(),
+                self.legal.json_outputs(),
+            ])
+            .flat_map(|(section, outputs)| {
+                outputs.into_iter().map(move |(check_id, output)| {
+                    let check = ReportCheck {
+                        name: CHECKS[check_id].name,
+                        section: *section,
+                        output,
+                    };
+                    (check_id, check)
+                })
+            })
+            .collect()
+    }
+
+    /// Apply inter-checks exemptions. When any of the checks in an
+    /// equivalence group passes, the checks in the group that did not pass
+    /// are exempt. Only checks that were run (and have an output) can be
+    /// exempt. The groups provided replace the default ones.
+    pub(crate) fn apply_exemptions(&mut self, equivalences: Option<&[Vec<String>]>) {
+        for group in check_equivalences(equivalences) {
+            let passed: Vec<CheckId> = self
+                .outputs()
+                .into_iter()
+                .filter(|(_, output)| output.passed || output.exempt)
+                .map(|(check_id, _)| check_id)
+                .collect();
+            let Some(passed_check_id) = group
+                .iter()
+                .find(|check_id| passed.contains(&check_id.as_str()))
+            else {
+                continue;
+            };
+            let Some(passed_check) = CHECKS.get(passed_check_id.as_str()) else {
+                continue;
+            };
+            let reason = format!("{} check passed", passed_check.name);
+            for check_id in &group {
+                if !passed.contains(&check_id.as_str()) {
+                    self.exempt(check_id, &reason);
+                }
+            }
         }
     }
+
+    /// Exempt the check provided, using the given exemption reason. Checks
+    /// without output are left untouched.
+    fn exempt(&mut self, check_id: &str, reason: &str) {
+        let _ = self.documentation.exempt(check_id, reason)
+            || self.license.exempt(check_id, reason)
+            || self.best_practices.exempt(check_id, reason)
+            || self.security.exempt(check_id, reason)
+            || self.legal.exempt(check_id, reason);
+    }
+}
+
+/// Return the groups of equivalent checks applied to reports: the ones
+/// provided or, when none were provided, the default groups.
+#[must_use]
+pub fn check_equivalences(equivalences: Option<&[Vec<String>]>) -> Vec<Vec<String>> {
+    match equivalences {
+        Some(equivalences) => equivalences.to_vec(),
+        None => DEFAULT_CHECK_EQUIVALENCES
+            .iter()
+            .map(|group| group.iter().map(ToString::to_string).collect())
+            .collect(),
+    }
+}
+
+/// Upgrade the report data provided to the current schema version. Reports
+/// stored before the schema was versioned are considered to be version 1.
+#[allow(clippy::missing_errors_doc)]
+pub fn upgrade_report_data(data: &mut Value) -> Result<()> {
+    let Some(report) = data.as_object_mut() else {
+        return Err(format_err!("invalid report data: object expected"));
+    };
+    let version = match report.get("schema_version") {
+        Some(version) => version
+            .as_u64()
+            .ok_or_else(|| format_err!("invalid report schema version: {version}"))?,
+        None => 1,
+    };
+    if version == 0 || version > REPORT_SCHEMA_VERSION {
+        return Err(format_err!("unsupported report schema version: {version}"));
+    }
+
+    for upgrade in REPORT_UPGRADES.iter().skip(usize::try_from(version)? - 1) {
+        upgrade(data);
+    }
+    data["schema_version"] = REPORT_SCHEMA_VERSION.into();
+
+    Ok(())
+}
+
+/// Take the section provided from the report data. Sections missing in the
+/// data are considered empty.
+fn report_section<T: DeserializeOwned + Default>(data: &mut Value, name: &str) -> Result<T> {
+    match data.get_mut(name).map(Value::take) {
+        Some(Value::Null) | None => Ok(T::default()),
+        Some(section) => Ok(serde_json::from_value(section)?),
+    }
+}
+
+/// Upgrade report from version 1 to 2: the annual review check was removed.
+fn upgrade_report_v1(data: &mut Value) {
+    if let Some(documentation) = data.get_mut("documentation").and_then(Value::as_object_mut) {
+        documentation.remove("annual_review");
+    }
 }
 
 /// Documentation section of the report.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Documentation {
     pub adopters: Option<CheckOutput>,
+    pub broken_links: Option<CheckOutput>,
     pub changelog: Option<CheckOutput>,
     pub code_of_conduct: Option<CheckOutput>,
     pub contributing: Option<CheckOutput>,
@@ -65,12 +281,14 @@ pub struct Documentation {
     pub roadmap: Option<CheckOutput>,
     pub summary_table: Option<CheckOutput>,
     pub website: Option<CheckOutput>,
+    pub website_hygiene: Option<CheckOutput>,
 }
 
 #[rustfmt::skip]
 section_impl!(
     Documentation,
     adopters,
+    broken_links,
     changelog,
     code_of_conduct,
     contributing,
@@ -79,7 +297,8 @@ section_impl!(
     readme,
     roadmap,
     summary_table,
-    website
+    website,
+    website_hygiene
 );
 
 /// License section of the report.
@@ -104,12 +323,16 @@ pub struct BestPractices {
     pub analytics: Option<CheckOutput<Vec<String>>>,
     pub artifacthub_badge: Option<CheckOutput>,
     pub cla: Option<CheckOutput>,
+    pub community_chat: Option<CheckOutput<Vec<String>>>,
     pub community_meeting: Option<CheckOutput>,
+    pub contributor_diversity: Option<CheckOutput<ContributorDiversity>>,
     pub dco: Option<CheckOutput>,
     pub github_discussions: Option<CheckOutput>,
+    pub issue_pr_templates: Option<CheckOutput>,
     pub openssf_badge: Option<CheckOutput>,
     pub openssf_scorecard_badge: Option<CheckOutput>,
     pub recent_release: Option<CheckOutput>,
+    pub responsiveness: Option<CheckOutput<Responsiveness>>,
     pub slack_presence: Option<CheckOutput>,
 }
 
@@ -119,12 +342,16 @@ section_impl!(
     analytics,
     artifacthub_badge,
     cla,
+    community_chat,
     community_meeting,
+    contributor_diversity,
     dco,
     github_discussions,
+    issue_pr_templates,
     openssf_badge,
     openssf_scorecard_badge,
     recent_release,
+    responsiveness,
     slack_presence
 );
 
@@ -195,6 +422,34 @@ macro_rules! section_impl {
                 )*
                 checks
             }
+
+            pub(crate) fn outputs(&self) -> SectionOutputs {
+                vec![
+                    $(
+                    ($check::ID, self.$check.as_ref().map(CheckOutput::without_value)),
+                    )*
+                ]
+            }
+
+            pub(crate) fn exempt(&mut self, check_id: &str, reason: &str) -> bool {
+                $(
+                if check_id == $check::ID {
+                    if self.$check.is_some() {
+                        self.$check = Some(CheckOutput::exempt().exemption_reason(Some(reason.to_string())));
+                    }
+                    return true;
+                }
+                )*
+                false
+            }
+
+            pub(crate) fn json_outputs(&self) -> Vec<(CheckId, Option<CheckOutput<Value>>)> {
+                vec![
+                    $(
+                    ($check::ID, self.$check.as_ref().map(CheckOutput::with_json_value)),
+                    )*
+                ]
+            }
         }
     };
 }
@@ -202,6 +457,8 @@ use section_impl;
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::*;
 
     #[test]
@@ -209,12 +466,13 @@ mod tests {
         let mut report = Report {
             best_practices: BestPractices {
                 cla: Some(CheckOutput::passed()),
+                dco: Some(CheckOutput::not_passed()),
                 ..Default::default()
             },
             ..Default::default()
         };
 
-        report.apply_exemptions();
+        report.apply_exemptions(None);
         assert_eq!(
             report,
             Report {
@@ -236,12 +494,13 @@ mod tests {
         let mut report = Report {
             best_practices: BestPractices {
                 dco: Some(CheckOutput::passed()),
+                cla: Some(CheckOutput::not_passed()),
                 ..Default::default()
             },
             ..Default::default()
         };
 
-        report.apply_exemptions();
+        report.apply_exemptions(None);
         assert_eq!(
             report,
             Report {
@@ -263,12 +522,13 @@ mod tests {
         let mut report = Report {
             best_practices: BestPractices {
                 slack_presence: Some(CheckOutput::passed()),
+                github_discussions: Some(CheckOutput::not_passed()),
                 ..Default::default()
             },
             ..Default::default()
         };
 
-        report.apply_exemptions();
+        report.apply_exemptions(None);
         assert_eq!(
             report,
             Report {
@@ -290,12 +550,13 @@ mod tests {
         let mut report = Report {
             best_practices: BestPractices {
                 github_discussions: Some(CheckOutput::passed()),
+                slack_presence: Some(CheckOutput::not_passed()),
                 ..Default::default()
             },
             ..Default::default()
         };
 
-        report.apply_exemptions();
+        report.apply_exemptions(None);
         assert_eq!(
             report,
             Report {
@@ -303,7 +564,7 @@ mod tests {
                     github_discussions: Some(CheckOutput::passed()),
                     slack_presence: Some(
                         CheckOutput::exempt()
-                            .exemption_reason(Some("Github discussions check passed".to_string()))
+                            .exemption_reason(Some("GitHub discussions check passed".to_string()))
                     ),
                     ..Default::default()
                 },
@@ -311,4 +572,352 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn apply_exemptions_community_chat_passed() {
+        let mut report = Report {
+            best_practices: BestPractices {
+                community_chat: Some(
+                    CheckOutput::passed()
+                        .value(Some(vec!["https://discord.gg/AbCd123".to_string()])),
+                ),
+                slack_presence: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected = report.clone();
+
+        report.apply_exemptions(None);
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn apply_exemptions_checks_without_output_not_exempt() {
+        let mut report = Report {
+            best_practices: BestPractices {
+                cla: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected = report.clone();
+
+        report.apply_exemptions(None);
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn apply_exemptions_group_members_without_output_not_exempt() {
+        let mut report = Report {
+            best_practices: BestPractices {
+                github_discussions: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected = report.clone();
+
+        report.apply_exemptions(None);
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn apply_exemptions_custom_equivalence_groups_replace_defaults() {
+        let mut report = Report {
+            best_practices: BestPractices {
+                cla: Some(CheckOutput::passed()),
+                dco: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected = report.clone();
+
+        report.apply_exemptions(Some(&[vec![
+            "openssf_badge".to_string(),
+            "security_policy".to_string(),
+        ]]));
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn apply_exemptions_custom_equivalence_group() {
+        let mut report = Report {
+            best_practices: BestPractices {
+                openssf_badge: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        report.apply_exemptions(Some(&[vec![
+            "openssf_badge".to_string(),
+            "security_policy".to_string(),
+        ]]));
+        assert_eq!(
+            report,
+            Report {
+                best_practices: BestPractices {
+                    openssf_badge: Some(CheckOutput::passed()),
+                    ..Default::default()
+                },
+                security: Security {
+                    security_policy: Some(CheckOutput::exempt().exemption_reason(Some(
+                        "OpenSSF best practices badge check passed".to_string()
+                    ))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn apply_exemptions_equivalence_group_without_passed_checks() {
+        let mut report = Report {
+            best_practices: BestPractices {
+                cla: Some(CheckOutput::not_passed()),
+                dco: Some(CheckOutput::not_passed()),
+                slack_presence: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected = report.clone();
+
+        report.apply_exemptions(Some(&[vec![
+            "slack_presence".to_string(),
+            "community_meeting".to_string(),
+        ]]));
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn outputs_returned_without_values() {
+        let report = Report {
+            documentation: Documentation {
+                readme: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                analytics: Some(
+                    CheckOutput::not_passed()
+                        .value(Some(vec!["GA4".to_string()]))
+                        .details(Some("details".to_string())),
+                ),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            report.outputs(),
+            vec![
+                (readme::ID, CheckOutput::passed()),
+                (
+                    analytics::ID,
+                    CheckOutput::not_passed().details(Some("details".to_string()))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn sections_outputs_include_checks_not_run() {
+        let report = Report {
+            legal: Legal {
+                trademark_disclaimer: Some(CheckOutput::passed()),
+            },
+            ..Default::default()
+        };
+
+        let sections_outputs = report.sections_outputs();
+        assert_eq!(
+            sections_outputs
+                .iter()
+                .map(|(section, _)| *section)
+                .collect::<Vec<_>>(),
+            vec![
+                "documentation",
+                "license",
+                "best_practices",
+                "security",
+                "legal"
+            ]
+        );
+        assert_eq!(
+            sections_outputs[1].1,
+            vec![
+                (license_approved::ID, None),
+                (license_scanning::ID, None),
+                (license_spdx_id::ID, None),
+            ]
+        );
+        assert_eq!(
+            sections_outputs[4].1,
+            vec![(trademark_disclaimer::ID, Some(CheckOutput::passed()))]
+        );
+    }
+
+    #[test]
+    fn checks_returns_all_checks_in_report_order() {
+        let report = Report {
+            documentation: Documentation {
+                readme: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            license: License {
+                license_spdx_id: Some(CheckOutput::passed().value(Some("Apache-2.0".to_string()))),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let checks = report.checks();
+
+        assert_eq!(
+            checks.keys().copied().collect::<Vec<_>>(),
+            report
+                .sections_outputs()
+                .into_iter()
+                .flat_map(|(_, outputs)| outputs.into_iter().map(|(check_id, _)| check_id))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            checks[readme::ID],
+            ReportCheck {
+                name: "Readme",
+                section: REPORT_SECTIONS[0],
+                output: Some(CheckOutput::passed()),
+            }
+        );
+        assert_eq!(
+            checks[license_spdx_id::ID],
+            ReportCheck {
+                name: "SPDX id",
+                section: REPORT_SECTIONS[1],
+                output: Some(CheckOutput::passed().value(Some(json!("Apache-2.0")))),
+            }
+        );
+        assert_eq!(checks[adopters::ID].output, None);
+    }
+
+    #[test]
+    fn report_serialized_with_schema_version() {
+        let data = serde_json::to_value(Report::default()).unwrap();
+
+        assert_eq!(data["schema_version"], json!(REPORT_SCHEMA_VERSION));
+        assert_eq!(
+            serde_json::from_value::<Report>(data).unwrap(),
+            Report::default()
+        );
+    }
+
+    #[test]
+    fn report_without_schema_version_is_upgraded() {
+        let report: Report = serde_json::from_value(json!({
+            "documentation": {
+                "annual_review": {
+                    "passed": false,
+                    "exempt": false,
+                    "failed": false
+                },
+                "readme": {
+                    "passed": true,
+                    "exempt": false,
+                    "failed": false
+                }
+            },
+            "license": {},
+            "best_practices": {},
+            "security": {},
+            "legal": {}
+        }))
+        .unwrap();
+
+        assert_eq!(
+            report,
+            Report {
+                documentation: Documentation {
+                    readme: Some(CheckOutput::passed()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn report_missing_sections_are_empty() {
+        let report: Report = serde_json::from_value(json!({
+            "schema_version": REPORT_SCHEMA_VERSION,
+            "legal": {
+                "trademark_disclaimer": {
+                    "passed": true,
+                    "exempt": false,
+                    "failed": false
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            report,
+            Report {
+                legal: Legal {
+                    trademark_disclaimer: Some(CheckOutput::passed()),
+                },
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn report_with_unsupported_schema_version_is_rejected() {
+        let err = serde_json::from_value::<Report>(json!({
+            "schema_version": REPORT_SCHEMA_VERSION + 1,
+        }))
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "unsupported report schema version: {}",
+                REPORT_SCHEMA_VERSION + 1
+            )
+        );
+    }
+
+    #[test]
+    fn upgrade_report_data_sets_current_schema_version() {
+        let mut data = json!({
+            "documentation": {
+                "annual_review": null,
+                "adopters": null
+            }
+        });
+        upgrade_report_data(&mut data).unwrap();
+
+        assert_eq!(
+            data,
+            json!({
+                "schema_version": REPORT_SCHEMA_VERSION,
+                "documentation": {
+                    "adopters": null
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn upgrade_report_data_requires_an_object() {
+        assert_eq!(
+            upgrade_report_data(&mut json!([])).unwrap_err().to_string(),
+            "invalid report data: object expected"
+        );
+    }
 }
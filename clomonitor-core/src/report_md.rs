@@ -0,0 +1,63 @@
+use askama::Template;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    linter::{CheckSet, Report},
+    score::Score,
+};
+
+/// Template for the repository report in markdown format.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "repository-report.md")]
+pub struct RepositoryReportMDTemplate {
+    pub name: String,
+    pub url: String,
+    pub check_sets: Vec<CheckSet>,
+    pub score: Option<Score>,
+    pub report: Option<Report>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::linter::{CheckOutput, Documentation};
+
+    use super::*;
+
+    #[test]
+    fn render_exemptions() {
+        let approved_by = Some("https://github.com/cncf/toc/issues/1".to_string());
+        let report_md = RepositoryReportMDTemplate {
+            name: "repo".to_string(),
+            url: "https://github.com/org/repo".to_string(),
+            check_sets: vec![CheckSet::Code],
+            score: Some(Score {
+                documentation: Some(50.0),
+                ..Score::default()
+            }),
+            report: Some(Report {
+                documentation: Documentation {
+                    adopters: Some(
+                        CheckOutput::exempt()
+                            .exemption_reason(Some("reason".to_string()))
+                            .exemption_approved_by(approved_by.clone()),
+                    ),
+                    roadmap: Some(
+                        CheckOutput::not_passed()
+                            .exemption_expired(true)
+                            .exemption_approved_by(approved_by),
+                    ),
+                    ..Documentation::default()
+                },
+                ..Report::default()
+            }),
+        };
+        let md = report_md.render().unwrap();
+
+        assert!(md.contains(
+            "- [x] Adopters ([_docs_](https://clomonitor.io/docs/topics/checks/#adopters)) `EXEMPT` (exemption approved by https://github.com/cncf/toc/issues/1)"
+        ));
+        assert!(md.contains(
+            "- [ ] Roadmap ([_docs_](https://clomonitor.io/docs/topics/checks/#roadmap)) `EXEMPTION EXPIRED` (exemption approved by https://github.com/cncf/toc/issues/1)"
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, sync::Arc};
+
 use serde::{Deserialize, Serialize};
 
 use crate::linter::*;
@@ -51,6 +53,19 @@ impl Score {
     pub fn rating(&self) -> char {
         rating(self.global())
     }
+
+    /// Return the score of the report section provided, if it was scored.
+    #[must_use]
+    pub fn section(&self, section_id: &str) -> Option<f64> {
+        match section_id {
+            "documentation" => self.documentation,
+            "license" => self.license,
+            "best_practices" => self.best_practices,
+            "security" => self.security,
+            "legal" => self.legal,
+            _ => None,
+        }
+    }
 }
 
 /// Calculate score for the given linter report.
@@ -128,6 +143,91 @@ fn calculate_section(
     (Some(score), Some(weight))
 }
 
+/// Contribution of a check to the score.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckContribution {
+    pub check_id: CheckId,
+    pub section: &'static str,
+    pub weight: usize,
+
+    /// Share of the section score the check accounts for (0-100).
+    pub section_share: f64,
+
+    /// Share of the global score the check accounts for (0-100).
+    pub global_share: f64,
+
+    /// Whether the check passed or is exempt.
+    pub passed: bool,
+
+    /// Points the global score would gain if the check passed.
+    pub potential_gain: f64,
+}
+
+/// Score breakdown, including the contribution of each of the checks run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreExplanation {
+    pub score: Score,
+    pub checks: Vec<CheckContribution>,
+}
+
+impl ScoreExplanation {
+    /// Return the checks that would increase the global score if they passed,
+    /// ordered by the points they would add (biggest wins first).
+    #[must_use]
+    pub fn biggest_wins(&self) -> Vec<&CheckContribution> {
+        let mut wins: Vec<&CheckContribution> = self
+            .checks
+            .iter()
+            .filter(|check| check.potential_gain > 0.0)
+            .collect();
+        wins.sort_by(|a, b| b.potential_gain.total_cmp(&a.potential_gain));
+        wins
+    }
+}
+
+/// Explain the score of the given linter report, detailing how much each of
+/// the checks run contributes to it.
+#[must_use]
+pub fn explain(report: &Report) -> ScoreExplanation {
+    let score = calculate(report);
+    let global_weight = score.global_weight;
+
+    let share = |weight: usize, total_weight: usize| -> f64 {
+        if total_weight == 0 {
+            return 0.0;
+        }
+        weight as f64 / total_weight as f64 * 100.0
+    };
+
+    let checks = report
+        .sections_outputs()
+        .into_iter()
+        .flat_map(|(section, outputs)| {
+            let section_weight = outputs
+                .iter()
+                .filter(|(_, output)| output.is_some())
+                .fold(0, |weight, (check_id, _)| weight + CHECKS[check_id].weight);
+            outputs.into_iter().filter_map(move |(check_id, output)| {
+                let output = output?;
+                let weight = CHECKS[check_id].weight;
+                let passed = output.passed || output.exempt;
+                let global_share = share(weight, global_weight);
+                Some(CheckContribution {
+                    check_id,
+                    section,
+                    weight,
+                    section_share: share(weight, section_weight),
+                    global_share,
+                    passed,
+                    potential_gain: if passed { 0.0 } else { global_share },
+                })
+            })
+        })
+        .collect();
+
+    ScoreExplanation { score, checks }
+}
+
 /// Merge the scores provided into a single score.
 #[must_use]
 pub fn merge(scores: &[Score]) -> Score {
@@ -206,6 +306,152 @@ pub fn rating(score: f64) -> char {
     }
 }
 
+/// Type alias to represent a ScoringModel trait object.
+pub type DynScoringModel = Arc<dyn ScoringModel + Send + Sync>;
+
+/// Trait that defines how scores and ratings are calculated. The default
+/// implementations provide the weighted averaging and rating thresholds used
+/// by CLOMonitor out of the box.
+pub trait ScoringModel {
+    /// Calculate score for the given linter report.
+    fn calculate(&self, report: &Report) -> Score {
+        calculate(report)
+    }
+
+    /// Merge the scores provided into a single score.
+    fn merge(&self, scores: &[Score]) -> Score {
+        merge(scores)
+    }
+
+    /// Return the rating (a, b, c or d) for the score provided.
+    fn rating(&self, score: &Score, _input: &RatingInput) -> char {
+        score.rating()
+    }
+}
+
+/// Project information a scoring model can use to rate a score.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RatingInput {
+    pub maturity: Option<String>,
+    pub passed_checks: Vec<String>,
+}
+
+/// Default scoring model, based on weighted averages.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WeightedAverage;
+
+impl ScoringModel for WeightedAverage {}
+
+/// Scoring model configuration, used to select the scoring model a foundation
+/// uses.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(tag = "model", rename_all = "kebab-case")]
+pub enum ScoringModelConfig {
+    #[default]
+    WeightedAverage,
+    Policy(PolicyModel),
+}
+
+impl ScoringModelConfig {
+    /// Build the scoring model defined in this configuration.
+    #[must_use]
+    pub fn build(self) -> DynScoringModel {
+        match self {
+            ScoringModelConfig::WeightedAverage => Arc::new(WeightedAverage),
+            ScoringModelConfig::Policy(model) => Arc::new(model),
+        }
+    }
+}
+
+/// Scoring model that calculates scores using weighted averages, but rates
+/// them using configurable thresholds. Thresholds can be adjusted for each
+/// maturity level, and failing any of the mandatory checks caps the rating.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PolicyModel {
+    /// Rating thresholds used by default.
+    pub thresholds: RatingThresholds,
+
+    /// Rating thresholds used for each maturity level.
+    pub maturity_thresholds: HashMap<String, RatingThresholds>,
+
+    /// Checks a project must pass to get a rating better than the maximum
+    /// rating defined below.
+    pub mandatory_checks: Vec<String>,
+
+    /// Best rating a project not passing all mandatory checks can get.
+    pub mandatory_checks_max_rating: char,
+}
+
+impl Default for PolicyModel {
+    fn default() -> Self {
+        Self {
+            thresholds: RatingThresholds::default(),
+            maturity_thresholds: HashMap::new(),
+            mandatory_checks: vec![],
+            mandatory_checks_max_rating: 'b',
+        }
+    }
+}
+
+impl ScoringModel for PolicyModel {
+    fn rating(&self, score: &Score, input: &RatingInput) -> char {
+        let thresholds = input
+            .maturity
+            .as_ref()
+            .and_then(|maturity| self.maturity_thresholds.get(maturity))
+            .unwrap_or(&self.thresholds);
+        let rating = thresholds.rating(score.global());
+
+        // Ratings are ordered from best (a) to worst (d)
+        let mandatory_checks_passed = self
+            .mandatory_checks
+            .iter()
+            .all(|check_id| input.passed_checks.contains(check_id));
+        if mandatory_checks_passed {
+            rating
+        } else {
+            rating.max(self.mandatory_checks_max_rating)
+        }
+    }
+}
+
+/// Minimum score required to get each of the ratings. Scores below the `c`
+/// threshold are rated as `d`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RatingThresholds {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl Default for RatingThresholds {
+    fn default() -> Self {
+        Self {
+            a: 75.0,
+            b: 50.0,
+            c: 25.0,
+        }
+    }
+}
+
+impl RatingThresholds {
+    /// Return the score's rating (a, b, c or d).
+    #[must_use]
+    pub fn rating(&self, score: f64) -> char {
+        let score = score.round();
+        if score >= self.a {
+            'a'
+        } else if score >= self.b {
+            'b'
+        } else if score >= self.c {
+            'c'
+        } else {
+            'd'
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,6 +482,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn score_section() {
+        let score = Score {
+            security: Some(70.0),
+            ..Score::default()
+        };
+
+        assert_eq!(score.section("security"), Some(70.0));
+        assert_eq!(score.section("legal"), None);
+        assert_eq!(score.section("unknown"), None);
+    }
+
     #[test]
     fn rating_returns_correct_level() {
         assert_eq!(rating(80.0), 'a');
@@ -247,60 +505,188 @@ mod tests {
         assert_eq!(rating(20.0), 'd');
     }
 
+    /// Report with all checks passed.
+    fn report_with_all_checks_passed() -> Report {
+        Report {
+            schema_version: REPORT_SCHEMA_VERSION,
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                broken_links: Some(CheckOutput::passed()),
+                code_of_conduct: Some(CheckOutput::passed()),
+                contributing: Some(CheckOutput::passed()),
+                changelog: Some(CheckOutput::passed()),
+                governance: Some(CheckOutput::passed()),
+                maintainers: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::passed()),
+                roadmap: Some(CheckOutput::passed()),
+                summary_table: Some(CheckOutput::passed()),
+                website: Some(CheckOutput::passed()),
+                website_hygiene: Some(CheckOutput::passed()),
+            },
+            license: License {
+                license_approved: Some(CheckOutput::passed()),
+                license_scanning: Some(
+                    CheckOutput::passed().url(Some("https://license-scanning.url".to_string())),
+                ),
+                license_spdx_id: Some(CheckOutput::passed().value(Some("Apache-2.0".to_string()))),
+            },
+            best_practices: BestPractices {
+                analytics: Some(CheckOutput::passed()),
+                artifacthub_badge: Some(CheckOutput::exempt()),
+                cla: Some(CheckOutput::passed()),
+                community_chat: Some(CheckOutput::passed()),
+                community_meeting: Some(CheckOutput::passed()),
+                contributor_diversity: Some(CheckOutput::passed()),
+                dco: Some(CheckOutput::passed()),
+                github_discussions: Some(CheckOutput::passed()),
+                issue_pr_templates: Some(CheckOutput::passed()),
+                openssf_badge: Some(CheckOutput::passed()),
+                openssf_scorecard_badge: Some(CheckOutput::passed()),
+                recent_release: Some(CheckOutput::passed()),
+                responsiveness: Some(CheckOutput::passed()),
+                slack_presence: Some(CheckOutput::passed()),
+            },
+            security: Security {
+                binary_artifacts: Some(CheckOutput::passed()),
+                code_review: Some(CheckOutput::passed()),
+                dangerous_workflow: Some(CheckOutput::passed()),
+                dependencies_policy: Some(CheckOutput::passed()),
+                dependency_update_tool: Some(CheckOutput::passed()),
+                maintained: Some(CheckOutput::passed()),
+                sbom: Some(CheckOutput::passed()),
+                security_insights: Some(CheckOutput::passed()),
+                security_policy: Some(CheckOutput::passed()),
+                signed_releases: Some(CheckOutput::passed()),
+                token_permissions: Some(CheckOutput::passed()),
+            },
+            legal: Legal {
+                trademark_disclaimer: Some(CheckOutput::passed()),
+            },
+        }
+    }
+
+    /// Report with all checks not passed.
+    fn report_with_all_checks_non_passed() -> Report {
+        Report {
+            schema_version: REPORT_SCHEMA_VERSION,
+            documentation: Documentation {
+                adopters: Some(CheckOutput::not_passed()),
+                broken_links: Some(CheckOutput::not_passed()),
+                code_of_conduct: Some(CheckOutput::not_passed()),
+                contributing: Some(CheckOutput::not_passed()),
+                changelog: Some(CheckOutput::not_passed()),
+                governance: Some(CheckOutput::not_passed()),
+                maintainers: Some(CheckOutput::not_passed()),
+                readme: Some(CheckOutput::not_passed()),
+                roadmap: Some(CheckOutput::not_passed()),
+                summary_table: Some(CheckOutput::not_passed()),
+                website: Some(CheckOutput::not_passed()),
+                website_hygiene: Some(CheckOutput::not_passed()),
+            },
+            license: License {
+                license_approved: Some(CheckOutput::not_passed()),
+                license_scanning: Some(CheckOutput::not_passed()),
+                license_spdx_id: Some(CheckOutput::not_passed()),
+            },
+            best_practices: BestPractices {
+                analytics: Some(CheckOutput::not_passed()),
+                artifacthub_badge: Some(CheckOutput::not_passed()),
+                cla: Some(CheckOutput::not_passed()),
+                community_chat: Some(CheckOutput::not_passed()),
+                community_meeting: Some(CheckOutput::not_passed()),
+                contributor_diversity: Some(CheckOutput::not_passed()),
+                dco: Some(CheckOutput::not_passed()),
+                github_discussions: Some(CheckOutput::not_passed()),
+                issue_pr_templates: Some(CheckOutput::not_passed()),
+                openssf_badge: Some(CheckOutput::not_passed()),
+                openssf_scorecard_badge: Some(CheckOutput::not_passed()),
+                recent_release: Some(CheckOutput::not_passed()),
+                responsiveness: Some(CheckOutput::not_passed()),
+                slack_presence: Some(CheckOutput::not_passed()),
+            },
+            security: Security {
+                binary_artifacts: Some(CheckOutput::not_passed()),
+                code_review: Some(CheckOutput::not_passed()),
+                dangerous_workflow: Some(CheckOutput::not_passed()),
+                dependencies_policy: Some(CheckOutput::not_passed()),
+                dependency_update_tool: Some(CheckOutput::not_passed()),
+                maintained: Some(CheckOutput::not_passed()),
+                sbom: Some(CheckOutput::not_passed()),
+                security_insights: Some(CheckOutput::not_passed()),
+                security_policy: Some(CheckOutput::not_passed()),
+                signed_releases: Some(CheckOutput::not_passed()),
+                token_permissions: Some(CheckOutput::not_passed()),
+            },
+            legal: Legal {
+                trademark_disclaimer: Some(CheckOutput::not_passed()),
+            },
+        }
+    }
+
+    /// Report with some checks missing and the rest passed.
+    fn report_with_some_missing_checks_but_all_passed() -> Report {
+        Report {
+            schema_version: REPORT_SCHEMA_VERSION,
+            documentation: Documentation {
+                adopters: None,
+                broken_links: None,
+                code_of_conduct: None,
+                contributing: Some(CheckOutput::passed()),
+                changelog: Some(CheckOutput::passed()),
+                governance: None,
+                maintainers: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::passed()),
+                roadmap: None,
+                summary_table: None,
+                website: None,
+                website_hygiene: None,
+            },
+            license: License {
+                license_approved: Some(CheckOutput::passed()),
+                license_scanning: Some(
+                    CheckOutput::passed().url(Some("https://license-scanning.url".to_string())),
+                ),
+                license_spdx_id: Some(CheckOutput::passed().value(Some("Apache-2.0".to_string()))),
+            },
+            best_practices: BestPractices {
+                analytics: Some(CheckOutput::passed()),
+                artifacthub_badge: Some(CheckOutput::exempt()),
+                cla: Some(CheckOutput::passed()),
+                community_chat: None,
+                community_meeting: None,
+                contributor_diversity: None,
+                dco: Some(CheckOutput::passed()),
+                github_discussions: Some(CheckOutput::passed()),
+                issue_pr_templates: Some(CheckOutput::passed()),
+                openssf_badge: Some(CheckOutput::passed()),
+                openssf_scorecard_badge: Some(CheckOutput::passed()),
+                recent_release: Some(CheckOutput::passed()),
+                responsiveness: Some(CheckOutput::passed()),
+                slack_presence: None,
+            },
+            security: Security {
+                binary_artifacts: Some(CheckOutput::passed()),
+                code_review: Some(CheckOutput::passed()),
+                dangerous_workflow: Some(CheckOutput::passed()),
+                dependencies_policy: Some(CheckOutput::passed()),
+                dependency_update_tool: Some(CheckOutput::passed()),
+                maintained: Some(CheckOutput::passed()),
+                sbom: Some(CheckOutput::passed()),
+                security_policy: Some(CheckOutput::passed()),
+                security_insights: Some(CheckOutput::passed()),
+                signed_releases: Some(CheckOutput::passed()),
+                token_permissions: Some(CheckOutput::passed()),
+            },
+            legal: Legal {
+                trademark_disclaimer: None,
+            },
+        }
+    }
+
     #[test]
     fn calculate_report_with_all_checks_passed_got_max_score() {
         assert_eq!(
-            calculate(&Report {
-                documentation: Documentation {
-                    adopters: Some(CheckOutput::passed()),
-                    code_of_conduct: Some(CheckOutput::passed()),
-                    contributing: Some(CheckOutput::passed()),
-                    changelog: Some(CheckOutput::passed()),
-                    governance: Some(CheckOutput::passed()),
-                    maintainers: Some(CheckOutput::passed()),
-                    readme: Some(CheckOutput::passed()),
-                    roadmap: Some(CheckOutput::passed()),
-                    summary_table: Some(CheckOutput::passed()),
-                    website: Some(CheckOutput::passed()),
-                },
-                license: License {
-                    license_approved: Some(CheckOutput::passed()),
-                    license_scanning: Some(
-                        CheckOutput::passed().url(Some("https://license-scanning.url".to_string()))
-                    ),
-                    license_spdx_id: Some(
-                        CheckOutput::passed().value(Some("Apache-2.0".to_string()))
-                    ),
-                },
-                best_practices: BestPractices {
-                    analytics: Some(CheckOutput::passed()),
-                    artifacthub_badge: Some(CheckOutput::exempt()),
-                    cla: Some(CheckOutput::passed()),
-                    community_meeting: Some(CheckOutput::passed()),
-                    dco: Some(CheckOutput::passed()),
-                    github_discussions: Some(CheckOutput::passed()),
-                    openssf_badge: Some(CheckOutput::passed()),
-                    openssf_scorecard_badge: Some(CheckOutput::passed()),
-                    recent_release: Some(CheckOutput::passed()),
-                    slack_presence: Some(CheckOutput::passed()),
-                },
-                security: Security {
-                    binary_artifacts: Some(CheckOutput::passed()),
-                    code_review: Some(CheckOutput::passed()),
-                    dangerous_workflow: Some(CheckOutput::passed()),
-                    dependencies_policy: Some(CheckOutput::passed()),
-                    dependency_update_tool: Some(CheckOutput::passed()),
-                    maintained: Some(CheckOutput::passed()),
-                    sbom: Some(CheckOutput::passed()),
-                    security_insights: Some(CheckOutput::passed()),
-                    security_policy: Some(CheckOutput::passed()),
-                    signed_releases: Some(CheckOutput::passed()),
-                    token_permissions: Some(CheckOutput::passed()),
-                },
-                legal: Legal {
-                    trademark_disclaimer: Some(CheckOutput::passed()),
-                },
-            }),
+            calculate(&report_with_all_checks_passed()),
             Score {
                 global: 100.0,
                 global_weight: 96,
@@ -321,53 +707,7 @@ mod tests {
     #[test]
     fn calculate_report_with_all_checks_non_passed_got_min_score() {
         assert_eq!(
-            calculate(&Report {
-                documentation: Documentation {
-                    adopters: Some(CheckOutput::not_passed()),
-                    code_of_conduct: Some(CheckOutput::not_passed()),
-                    contributing: Some(CheckOutput::not_passed()),
-                    changelog: Some(CheckOutput::not_passed()),
-                    governance: Some(CheckOutput::not_passed()),
-                    maintainers: Some(CheckOutput::not_passed()),
-                    readme: Some(CheckOutput::not_passed()),
-                    roadmap: Some(CheckOutput::not_passed()),
-                    summary_table: Some(CheckOutput::not_passed()),
-                    website: Some(CheckOutput::not_passed()),
-                },
-                license: License {
-                    license_approved: Some(CheckOutput::not_passed()),
-                    license_scanning: Some(CheckOutput::not_passed()),
-                    license_spdx_id: Some(CheckOutput::not_passed()),
-                },
-                best_practices: BestPractices {
-                    analytics: Some(CheckOutput::not_passed()),
-                    artifacthub_badge: Some(CheckOutput::not_passed()),
-                    cla: Some(CheckOutput::not_passed()),
-                    community_meeting: Some(CheckOutput::not_passed()),
-                    dco: Some(CheckOutput::not_passed()),
-                    github_discussions: Some(CheckOutput::not_passed()),
-                    openssf_badge: Some(CheckOutput::not_passed()),
-                    openssf_scorecard_badge: Some(CheckOutput::not_passed()),
-                    recent_release: Some(CheckOutput::not_passed()),
-                    slack_presence: Some(CheckOutput::not_passed()),
-                },
-                security: Security {
-                    binary_artifacts: Some(CheckOutput::not_passed()),
-                    code_review: Some(CheckOutput::not_passed()),
-                    dangerous_workflow: Some(CheckOutput::not_passed()),
-                    dependencies_policy: Some(CheckOutput::not_passed()),
-                    dependency_update_tool: Some(CheckOutput::not_passed()),
-                    maintained: Some(CheckOutput::not_passed()),
-                    sbom: Some(CheckOutput::not_passed()),
-                    security_insights: Some(CheckOutput::not_passed()),
-                    security_policy: Some(CheckOutput::not_passed()),
-                    signed_releases: Some(CheckOutput::not_passed()),
-                    token_permissions: Some(CheckOutput::not_passed()),
-                },
-                legal: Legal {
-                    trademark_disclaimer: Some(CheckOutput::not_passed()),
-                },
-            }),
+            calculate(&report_with_all_checks_non_passed()),
             Score {
                 global: 0.0,
                 global_weight: 96,
@@ -388,57 +728,7 @@ mod tests {
     #[test]
     fn calculate_report_with_some_missing_checks_but_all_passed_got_max_score() {
         assert_eq!(
-            calculate(&Report {
-                documentation: Documentation {
-                    adopters: None,
-                    code_of_conduct: None,
-                    contributing: Some(CheckOutput::passed()),
-                    changelog: Some(CheckOutput::passed()),
-                    governance: None,
-                    maintainers: Some(CheckOutput::passed()),
-                    readme: Some(CheckOutput::passed()),
-                    roadmap: None,
-                    summary_table: None,
-                    website: None,
-                },
-                license: License {
-                    license_approved: Some(CheckOutput::passed()),
-                    license_scanning: Some(
-                        CheckOutput::passed().url(Some("https://license-scanning.url".to_string()))
-                    ),
-                    license_spdx_id: Some(
-                        CheckOutput::passed().value(Some("Apache-2.0".to_string()))
-                    ),
-                },
-                best_practices: BestPractices {
-                    analytics: Some(CheckOutput::passed()),
-                    artifacthub_badge: Some(CheckOutput::exempt()),
-                    cla: Some(CheckOutput::passed()),
-                    community_meeting: None,
-                    dco: Some(CheckOutput::passed()),
-                    github_discussions: Some(CheckOutput::passed()),
-                    openssf_badge: Some(CheckOutput::passed()),
-                    openssf_scorecard_badge: Some(CheckOutput::passed()),
-                    recent_release: Some(CheckOutput::passed()),
-                    slack_presence: None,
-                },
-                security: Security {
-                    binary_artifacts: Some(CheckOutput::passed()),
-                    code_review: Some(CheckOutput::passed()),
-                    dangerous_workflow: Some(CheckOutput::passed()),
-                    dependencies_policy: Some(CheckOutput::passed()),
-                    dependency_update_tool: Some(CheckOutput::passed()),
-                    maintained: Some(CheckOutput::passed()),
-                    sbom: Some(CheckOutput::passed()),
-                    security_policy: Some(CheckOutput::passed()),
-                    security_insights: Some(CheckOutput::passed()),
-                    signed_releases: Some(CheckOutput::passed()),
-                    token_permissions: Some(CheckOutput::passed()),
-                },
-                legal: Legal {
-                    trademark_disclaimer: None,
-                },
-            }),
+            calculate(&report_with_some_missing_checks_but_all_passed()),
             Score {
                 global: 100.0,
                 global_weight: 76,
@@ -456,6 +746,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn explain_report_details_checks_contributions() {
+        let explanation = explain(&Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::not_passed()),
+                roadmap: Some(CheckOutput::exempt()),
+                ..Documentation::default()
+            },
+            legal: Legal {
+                trademark_disclaimer: Some(CheckOutput::not_passed()),
+            },
+            ..Report::default()
+        });
+
+        assert_eq!(explanation.score.global_weight, 17);
+        assert_eq!(
+            explanation.checks,
+            vec![
+                CheckContribution {
+                    check_id: "adopters",
+                    section: "documentation",
+                    weight: 1,
+                    section_share: 1.0 / 12.0 * 100.0,
+                    global_share: 1.0 / 17.0 * 100.0,
+                    passed: true,
+                    potential_gain: 0.0,
+                },
+                CheckContribution {
+                    check_id: "readme",
+                    section: "documentation",
+                    weight: 10,
+                    section_share: 10.0 / 12.0 * 100.0,
+                    global_share: 10.0 / 17.0 * 100.0,
+                    passed: false,
+                    potential_gain: 10.0 / 17.0 * 100.0,
+                },
+                CheckContribution {
+                    check_id: "roadmap",
+                    section: "documentation",
+                    weight: 1,
+                    section_share: 1.0 / 12.0 * 100.0,
+                    global_share: 1.0 / 17.0 * 100.0,
+                    passed: true,
+                    potential_gain: 0.0,
+                },
+                CheckContribution {
+                    check_id: "trademark_disclaimer",
+                    section: "legal",
+                    weight: 5,
+                    section_share: 100.0,
+                    global_share: 5.0 / 17.0 * 100.0,
+                    passed: false,
+                    potential_gain: 5.0 / 17.0 * 100.0,
+                },
+            ]
+        );
+        assert_eq!(
+            explanation
+                .biggest_wins()
+                .iter()
+                .map(|check| check.check_id)
+                .collect::<Vec<_>>(),
+            vec!["readme", "trademark_disclaimer"]
+        );
+    }
+
+    #[test]
+    fn explain_empty_report() {
+        let explanation = explain(&Report::default());
+
+        assert!(explanation.checks.is_empty());
+        assert!(explanation.biggest_wins().is_empty());
+    }
+
     #[test]
     fn merge_scores() {
         assert_eq!(
@@ -505,4 +870,200 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn weighted_average_model_matches_default_scoring() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::not_passed()),
+                ..Documentation::default()
+            },
+            legal: Legal {
+                trademark_disclaimer: Some(CheckOutput::passed()),
+            },
+            ..Report::default()
+        };
+        let score = WeightedAverage.calculate(&report);
+
+        assert_eq!(score, calculate(&report));
+        assert_eq!(
+            WeightedAverage.merge(&[score.clone(), score.clone()]),
+            merge(&[score.clone(), score.clone()])
+        );
+        assert_eq!(
+            WeightedAverage.rating(&score, &RatingInput::default()),
+            score.rating()
+        );
+    }
+
+    #[test]
+    fn weighted_average_model_matches_default_scoring_on_fixtures() {
+        let reports = vec![
+            report_with_all_checks_passed(),
+            report_with_all_checks_non_passed(),
+            report_with_some_missing_checks_but_all_passed(),
+        ];
+        let mut scores = Vec::new();
+        for report in &reports {
+            let score = WeightedAverage.calculate(report);
+
+            assert_eq!(score, calculate(report));
+            assert_eq!(
+                WeightedAverage.rating(&score, &RatingInput::default()),
+                score.rating()
+            );
+            scores.push(score);
+        }
+        assert_eq!(WeightedAverage.merge(&scores), merge(&scores));
+    }
+
+    #[test]
+    fn default_scoring_model_config_is_weighted_average() {
+        let model = ScoringModelConfig::default().build();
+        let score = Score {
+            global: 60.0,
+            ..Score::default()
+        };
+
+        assert_eq!(model.rating(&score, &RatingInput::default()), 'b');
+    }
+
+    #[test]
+    fn policy_model_with_default_thresholds_rates_like_default_model() {
+        let model = PolicyModel::default();
+
+        for global in [100.0, 80.0, 75.0, 74.0, 50.0, 49.0, 25.0, 20.0, 0.0] {
+            let score = Score {
+                global,
+                ..Score::default()
+            };
+            assert_eq!(
+                model.rating(&score, &RatingInput::default()),
+                score.rating()
+            );
+        }
+    }
+
+    #[test]
+    fn policy_model_applies_maturity_thresholds() {
+        let model = PolicyModel {
+            maturity_thresholds: HashMap::from([(
+                "graduated".to_string(),
+                RatingThresholds {
+                    a: 90.0,
+                    b: 75.0,
+                    c: 50.0,
+                },
+            )]),
+            ..PolicyModel::default()
+        };
+        let score = Score {
+            global: 80.0,
+            ..Score::default()
+        };
+
+        let graduated = RatingInput {
+            maturity: Some("graduated".to_string()),
+            ..RatingInput::default()
+        };
+        let sandbox = RatingInput {
+            maturity: Some("sandbox".to_string()),
+            ..RatingInput::default()
+        };
+        assert_eq!(model.rating(&score, &graduated), 'b');
+        assert_eq!(model.rating(&score, &sandbox), 'a');
+        assert_eq!(model.rating(&score, &RatingInput::default()), 'a');
+    }
+
+    #[test]
+    fn policy_model_caps_rating_when_mandatory_checks_not_passed() {
+        let model = PolicyModel {
+            mandatory_checks: vec!["license_approved".to_string(), "readme".to_string()],
+            mandatory_checks_max_rating: 'c',
+            ..PolicyModel::default()
+        };
+        let score = Score {
+            global: 90.0,
+            ..Score::default()
+        };
+
+        let all_passed = RatingInput {
+            passed_checks: vec!["license_approved".to_string(), "readme".to_string()],
+            ..RatingInput::default()
+        };
+        let some_passed = RatingInput {
+            passed_checks: vec!["readme".to_string()],
+            ..RatingInput::default()
+        };
+        assert_eq!(model.rating(&score, &all_passed), 'a');
+        assert_eq!(model.rating(&score, &some_passed), 'c');
+
+        // Ratings already worse than the cap are not affected
+        let low_score = Score {
+            global: 10.0,
+            ..Score::default()
+        };
+        assert_eq!(model.rating(&low_score, &some_passed), 'd');
+    }
+
+    #[test]
+    fn policy_model_uses_weighted_average_scores() {
+        let report = Report {
+            license: License {
+                license_approved: Some(CheckOutput::passed()),
+                license_spdx_id: Some(CheckOutput::not_passed()),
+                ..License::default()
+            },
+            ..Report::default()
+        };
+
+        assert_eq!(
+            PolicyModel::default().calculate(&report),
+            calculate(&report)
+        );
+    }
+
+    #[test]
+    fn scoring_model_config_deserializes_policy_model() {
+        let config: ScoringModelConfig = serde_yaml::from_str(
+            r"
+model: policy
+thresholds:
+  a: 80
+  b: 60
+  c: 40
+maturityThresholds:
+  graduated:
+    a: 90
+    b: 70
+    c: 50
+mandatoryChecks:
+  - license_approved
+mandatoryChecksMaxRating: c
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            ScoringModelConfig::Policy(PolicyModel {
+                thresholds: RatingThresholds {
+                    a: 80.0,
+                    b: 60.0,
+                    c: 40.0,
+                },
+                maturity_thresholds: HashMap::from([(
+                    "graduated".to_string(),
+                    RatingThresholds {
+                        a: 90.0,
+                        b: 70.0,
+                        c: 50.0,
+                    },
+                )]),
+                mandatory_checks: vec!["license_approved".to_string()],
+                mandatory_checks_max_rating: 'c',
+            })
+        );
+    }
 }
@@ -0,0 +1,238 @@
+use std::{collections::HashMap, fs, io, path::Path};
+
+use anyhow::{Context, Result};
+use clomonitor_core::{
+    linter::{CheckId, CheckOutput, Report},
+    score::Score,
+};
+use serde::Deserialize;
+
+/// Linter results of a previous run (as produced by the json output format).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(crate) struct Baseline {
+    pub report: Report,
+    pub score: Score,
+}
+
+impl Baseline {
+    /// Load the baseline from the file provided.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        let data = fs::read_to_string(path).context("error reading baseline file")?;
+        serde_json::from_str(&data).context("error parsing baseline file")
+    }
+}
+
+/// Differences between the current linter results and the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Diff {
+    /// Checks that passed in the baseline and don't pass anymore.
+    pub regressions: Vec<CheckId>,
+
+    /// Checks that didn't pass in the baseline and pass now.
+    pub improvements: Vec<CheckId>,
+
+    /// Global score in the baseline.
+    pub baseline_score: f64,
+
+    /// Current global score.
+    pub score: f64,
+}
+
+impl Diff {
+    /// Compare the current linter results with the baseline provided.
+    pub(crate) fn new(report: &Report, score: &Score, baseline: &Baseline) -> Self {
+        let baseline_passed: HashMap<CheckId, bool> = baseline
+            .report
+            .outputs()
+            .into_iter()
+            .map(|(check_id, output)| (check_id, passed(&output)))
+            .collect();
+
+        let mut regressions = Vec::new();
+        let mut improvements = Vec::new();
+        for (check_id, output) in report.outputs() {
+            match (baseline_passed.get(check_id), passed(&output)) {
+                (Some(true), false) => regressions.push(check_id),
+                (Some(false), true) => improvements.push(check_id),
+                _ => {}
+            }
+        }
+
+        Self {
+            regressions,
+            improvements,
+            baseline_score: baseline.score.global(),
+            score: score.global(),
+        }
+    }
+
+    /// Check if the current results regressed compared to the baseline, which
+    /// happens when any of the checks that passed doesn't pass anymore or the
+    /// global score dropped more than the maximum drop provided.
+    pub(crate) fn regressed(&self, max_score_drop: f64) -> bool {
+        !self.regressions.is_empty() || self.baseline_score - self.score > max_score_drop
+    }
+
+    /// Print the differences with the baseline.
+    pub(crate) fn display(&self, w: &mut impl io::Write) -> Result<()> {
+        writeln!(w, "Baseline comparison\n")?;
+        writeln!(
+            w,
+            "Global score: {} -> {} ({:+})",
+            self.baseline_score.round(),
+            self.score.round(),
+            (self.score - self.baseline_score).round()
+        )?;
+        for (title, checks) in [
+            ("Regressions", &self.regressions),
+            ("Improvements", &self.improvements),
+        ] {
+            writeln!(w, "\n{title}: {}", checks.len())?;
+            for check_id in checks {
+                writeln!(w, "· {check_id}")?;
+            }
+        }
+        writeln!(w)?;
+        Ok(())
+    }
+}
+
+/// Check if the check output provided is considered as passed. Exempt checks
+/// are considered as passed.
+fn passed(output: &CheckOutput) -> bool {
+    output.passed || output.exempt
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+
+    use clomonitor_core::linter::{BestPractices, Documentation};
+
+    use super::*;
+
+    fn baseline() -> Baseline {
+        Baseline {
+            report: Report {
+                documentation: Documentation {
+                    adopters: Some(CheckOutput::passed()),
+                    changelog: Some(CheckOutput::not_passed()),
+                    readme: Some(CheckOutput::passed()),
+                    ..Default::default()
+                },
+                best_practices: BestPractices {
+                    dco: Some(CheckOutput::exempt()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            score: Score {
+                global: 80.0,
+                ..Score::default()
+            },
+        }
+    }
+
+    fn report() -> Report {
+        Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                changelog: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::not_passed()),
+                roadmap: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                dco: Some(CheckOutput::failed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn load_baseline_from_json_output() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let path = tmp_dir.path().join("baseline.json");
+        let baseline = baseline();
+        fs::write(
+            &path,
+            serde_json::json!({
+                "report": baseline.report,
+                "score": baseline.score,
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+    }
+
+    #[test]
+    fn load_baseline_fails_when_file_does_not_exist() {
+        let err = Baseline::load(Path::new("non-existing-baseline.json")).unwrap_err();
+        assert_eq!(err.to_string(), "error reading baseline file");
+    }
+
+    #[test]
+    fn diff_computed() {
+        let score = Score {
+            global: 70.0,
+            ..Score::default()
+        };
+
+        assert_eq!(
+            Diff::new(&report(), &score, &baseline()),
+            Diff {
+                regressions: vec!["readme", "dco"],
+                improvements: vec!["changelog"],
+                baseline_score: 80.0,
+                score: 70.0,
+            }
+        );
+    }
+
+    #[test]
+    fn regressed_when_checks_regress_or_score_drops() {
+        let diff = |regressions: Vec<CheckId>, score: f64| Diff {
+            regressions,
+            improvements: vec![],
+            baseline_score: 80.0,
+            score,
+        };
+
+        assert!(diff(vec!["readme"], 90.0).regressed(5.0));
+        assert!(diff(vec![], 70.0).regressed(5.0));
+        assert!(!diff(vec![], 76.0).regressed(5.0));
+        assert!(!diff(vec![], 80.0).regressed(0.0));
+    }
+
+    #[test]
+    fn display_prints_diff() {
+        let diff = Diff {
+            regressions: vec!["readme", "dco"],
+            improvements: vec!["changelog"],
+            baseline_score: 80.0,
+            score: 70.0,
+        };
+
+        let mut w = Vec::new();
+        diff.display(&mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            "Baseline comparison
+
+Global score: 80 -> 70 (-10)
+
+Regressions: 2
+· readme
+· dco
+
+Improvements: 1
+· changelog
+
+"
+        );
+    }
+}
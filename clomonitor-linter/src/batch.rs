@@ -0,0 +1,373 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, format_err};
+use clomonitor_core::{
+    data::{Project, Repository},
+    git::{Git, GitCLI},
+    linter::{
+        self, ChatPlatform, CoreLinter, Foundation, Linter, LinterInput, Report, ResponsivenessSlo,
+    },
+    score::{self, Score},
+};
+use deadpool::unmanaged::Pool;
+use futures::stream::{self, StreamExt};
+use serde::Serialize;
+
+use crate::Args;
+
+/// Linter results of a project's repositories, including the project's score
+/// (repositories scores merged).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct ProjectResults {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<Score>,
+
+    pub repositories: Vec<RepositoryResults>,
+}
+
+impl ProjectResults {
+    /// Check if the project's score is equal or higher than the pass score
+    /// provided. Projects with no score (none of their repositories could be
+    /// linted) never pass.
+    pub(crate) fn passed(&self, pass_score: f64) -> bool {
+        self.score
+            .as_ref()
+            .is_some_and(|s| s.global() >= pass_score)
+    }
+}
+
+/// Linter results of a single repository.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct RepositoryResults {
+    pub name: String,
+    pub url: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<Report>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<Score>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Lint all the repositories listed in the foundation data file provided,
+/// returning the results grouped by project.
+pub(crate) async fn run(
+    args: &Args,
+    data_file: &Path,
+    github_tokens: Vec<String>,
+) -> Result<Vec<ProjectResults>> {
+    // Load projects from data file
+    let projects = load_projects(data_file)?;
+
+    // Setup GitHub tokens pool
+    if github_tokens.is_empty() {
+        return Err(format_err!("no GitHub tokens provided"));
+    }
+    let gh_tokens_pool = Pool::from(github_tokens);
+
+    // Lint projects' repositories
+    let responsiveness_slo = ResponsivenessSlo {
+        first_response_hours: args.slo_first_response_hours,
+        close_hours: args.slo_close_hours,
+    };
+    let check_equivalences = args.check_equivalences();
+    let preferred_chat_platforms = args.preferred_chat_platform.clone();
+    let foundation = Foundation {
+        foundation_id: args.foundation.clone().unwrap_or_default(),
+        landscape_url: args.landscape_url.clone(),
+    };
+    let repositories = projects.iter().flat_map(|p| {
+        let project = p.linter_project(foundation.clone());
+        p.repositories.iter().map(move |r| (project.clone(), r))
+    });
+    let repositories_results = stream::iter(repositories)
+        .map(|(project, repository)| async {
+            let github_token = gh_tokens_pool.get().await.expect("token -when available-");
+            lint_repository(
+                project,
+                repository,
+                github_token.clone(),
+                responsiveness_slo.clone(),
+                check_equivalences.clone(),
+                preferred_chat_platforms.clone(),
+            )
+            .await
+        })
+        .buffered(args.concurrency)
+        .collect::<Vec<RepositoryResults>>()
+        .await;
+
+    Ok(group_by_project(&projects, repositories_results))
+}
+
+/// Load the projects defined in the foundation data file provided. Excluded
+/// repositories and repositories without check sets are ignored, as well as
+/// projects without repositories to lint.
+pub(crate) fn load_projects(data_file: &Path) -> Result<Vec<Project>> {
+    let data = fs::read_to_string(data_file).context("error reading data file")?;
+    let mut projects: Vec<Project> =
+        serde_yaml::from_str(&data).context("error parsing data file")?;
+
+    for project in &mut projects {
+        project.remove_excluded_repositories();
+        project
+            .repositories
+            .retain(|r| r.check_sets.as_ref().is_some_and(|c| !c.is_empty()));
+        for repository in &project.repositories {
+            repository.linter_check_sets().context(format!(
+                "error parsing data file: invalid repository {}",
+                repository.url
+            ))?;
+        }
+    }
+    projects.retain(|p| !p.repositories.is_empty());
+
+    Ok(projects)
+}
+
+/// Clone and lint the repository provided. Errors are recorded in the results
+/// so that they don't prevent other repositories from being linted.
+async fn lint_repository(
+    project: linter::Project,
+    repository: &Repository,
+    github_token: String,
+    responsiveness_slo: ResponsivenessSlo,
+    check_equivalences: Option<Vec<Vec<String>>>,
+    preferred_chat_platforms: Vec<ChatPlatform>,
+) -> RepositoryResults {
+    let mut results = RepositoryResults {
+        name: repository.name.clone(),
+        url: repository.url.clone(),
+        report: None,
+        score: None,
+        error: None,
+    };
+    match lint(
+        project,
+        repository,
+        github_token,
+        responsiveness_slo,
+        check_equivalences,
+        preferred_chat_platforms,
+    )
+    .await
+    {
+        Ok(report) => {
+            results.score = Some(score::calculate(&report));
+            results.report = Some(report);
+        }
+        Err(err) => results.error = Some(format!("{err:#}")),
+    }
+    results
+}
+
+/// Clone the repository provided in a temporary directory and lint it.
+async fn lint(
+    project: linter::Project,
+    repository: &Repository,
+    github_token: String,
+    responsiveness_slo: ResponsivenessSlo,
+    check_equivalences: Option<Vec<Vec<String>>>,
+    preferred_chat_platforms: Vec<ChatPlatform>,
+) -> Result<Report> {
+    let tmp_dir = tempfile::Builder::new()
+        .prefix("clomonitor-linter")
+        .tempdir()?;
+    GitCLI::new()?
+        .clone_repository(&repository.url, tmp_dir.path())
+        .await
+        .context("error cloning repository")?;
+
+    let input = LinterInput {
+        project: Some(project),
+        root: tmp_dir.path().to_owned(),
+        path: repository.path.as_ref().map(PathBuf::from),
+        url: repository.url.clone(),
+        check_sets: repository.linter_check_sets()?,
+        checks: vec![],
+        skip_checks: vec![],
+        github_token,
+        responsiveness_slo,
+        check_equivalences,
+        preferred_chat_platforms,
+    };
+    CoreLinter::new().lint(&input).await
+}
+
+/// Group the repositories results provided by project, merging the scores of
+/// each project's repositories. Repositories results must be in the same order
+/// the repositories appear in the projects provided.
+fn group_by_project(
+    projects: &[Project],
+    repositories_results: Vec<RepositoryResults>,
+) -> Vec<ProjectResults> {
+    let mut repositories_results = repositories_results.into_iter();
+    projects
+        .iter()
+        .map(|project| {
+            let repositories: Vec<RepositoryResults> = repositories_results
+                .by_ref()
+                .take(project.repositories.len())
+                .collect();
+            let scores: Vec<Score> = repositories
+                .iter()
+                .filter_map(|r| r.score.clone())
+                .collect();
+            ProjectResults {
+                name: project.name.clone(),
+                score: (!scores.is_empty()).then(|| score::merge(&scores)),
+                repositories,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTDATA_PATH: &str = "src/testdata";
+
+    fn repository_results(name: &str, score: Option<Score>) -> RepositoryResults {
+        RepositoryResults {
+            name: name.to_string(),
+            url: format!("https://github.com/org/{name}"),
+            report: None,
+            score,
+            error: None,
+        }
+    }
+
+    fn project(name: &str, repositories: Vec<Repository>) -> Project {
+        Project {
+            name: name.to_string(),
+            display_name: None,
+            description: format!("{name} description"),
+            category: None,
+            home_url: None,
+            logo_url: None,
+            logo_dark_url: None,
+            devstats_url: None,
+            accepted_at: None,
+            maturity: None,
+            digest: None,
+            repositories,
+        }
+    }
+
+    #[test]
+    fn load_projects_skips_excluded_and_untracked_repositories() {
+        let projects = load_projects(&Path::new(TESTDATA_PATH).join("foundation.yaml")).unwrap();
+
+        assert_eq!(
+            projects,
+            vec![Project {
+                display_name: Some("Project 1".to_string()),
+                description: "Project 1 description".to_string(),
+                maturity: Some("sandbox".to_string()),
+                ..project(
+                    "project1",
+                    vec![Repository {
+                        name: "repo1".to_string(),
+                        url: "https://github.com/org/repo1".to_string(),
+                        path: Some("projects/project1".to_string()),
+                        check_sets: Some(vec!["community".to_string(), "code".to_string()]),
+                        exclude: None,
+                    }]
+                )
+            }]
+        );
+    }
+
+    #[test]
+    fn load_projects_fails_when_data_file_is_invalid() {
+        let err = load_projects(&Path::new(TESTDATA_PATH).join("junit.golden")).unwrap_err();
+        assert_eq!(err.to_string(), "error parsing data file");
+    }
+
+    #[test]
+    fn load_projects_fails_when_check_set_is_invalid() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let data_file = tmp_dir.path().join("foundation.yaml");
+        fs::write(
+            &data_file,
+            r"
+- name: project1
+  description: Project 1 description
+  repositories:
+    - name: repo1
+      url: https://github.com/org/repo1
+      check_sets:
+        - unknown
+",
+        )
+        .unwrap();
+
+        let err = load_projects(&data_file).unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "error parsing data file: invalid repository https://github.com/org/repo1: invalid check set: unknown"
+        );
+    }
+
+    #[test]
+    fn group_by_project_merges_repositories_scores() {
+        let repository = |name: &str| Repository {
+            name: name.to_string(),
+            url: format!("https://github.com/org/{name}"),
+            path: None,
+            check_sets: Some(vec!["code".to_string()]),
+            exclude: None,
+        };
+        let projects = vec![
+            project("project1", vec![repository("repo1"), repository("repo2")]),
+            project("project2", vec![repository("repo3")]),
+        ];
+        let score1 = Score {
+            global: 80.0,
+            global_weight: 10,
+            ..Score::default()
+        };
+        let score2 = Score {
+            global: 40.0,
+            global_weight: 10,
+            ..Score::default()
+        };
+        let mut failed = repository_results("repo3", None);
+        failed.error = Some("error cloning repository".to_string());
+
+        assert_eq!(
+            group_by_project(
+                &projects,
+                vec![
+                    repository_results("repo1", Some(score1.clone())),
+                    repository_results("repo2", Some(score2.clone())),
+                    failed.clone(),
+                ]
+            ),
+            vec![
+                ProjectResults {
+                    name: "project1".to_string(),
+                    score: Some(score::merge(&[score1.clone(), score2.clone()])),
+                    repositories: vec![
+                        repository_results("repo1", Some(score1)),
+                        repository_results("repo2", Some(score2)),
+                    ],
+                },
+                ProjectResults {
+                    name: "project2".to_string(),
+                    score: None,
+                    repositories: vec![failed],
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use std::{io, path::PathBuf};
+
+use anyhow::Result;
+use clomonitor_core::linter::Explanation;
+
+/// Arguments of the explain subcommand.
+#[derive(Debug, clap::Args)]
+pub(crate) struct ExplainArgs {
+    /// Identifier of the check to explain [code_of_conduct]
+    pub check_id: String,
+
+    /// Repository local path (used for checks that can be done locally). When
+    /// not provided, the repository will be cloned from the url
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+
+    /// Subdirectory of the repository where the project is located, for
+    /// repositories hosting multiple projects [projects/project1]. Files are
+    /// looked for there first, falling back to the repository root
+    #[clap(long)]
+    pub subpath: Option<PathBuf>,
+
+    /// Repository url [https://github.com/org/repo] (used for some GitHub remote checks)
+    #[clap(long)]
+    pub url: String,
+}
+
+/// Print the explanation provided.
+pub(crate) fn display(explanation: &Explanation, w: &mut impl io::Write) -> Result<()> {
+    writeln!(w, "\nCheck: {}", explanation.check_id)?;
+    let result = match &explanation.output {
+        None => "Not applicable".to_string(),
+        Some(output) if output.exempt => format!(
+            "Exempt ({})",
+            output.exemption_reason.as_deref().unwrap_or_default()
+        ),
+        Some(output) if output.failed => format!(
+            "Failed ({})",
+            output.fail_reason.as_deref().unwrap_or_default()
+        ),
+        Some(output) if output.passed => "✓ Passed".to_string(),
+        Some(_) => "✗ Not passed".to_string(),
+    };
+    writeln!(w, "Result: {result}")?;
+    if let Some(url) = explanation.output.as_ref().and_then(|o| o.url.as_ref()) {
+        writeln!(w, "Url: {url}")?;
+    }
+
+    writeln!(w, "\nWhat the check looked for\n")?;
+    if explanation.evidence.is_empty() {
+        writeln!(w, "No details available for this check")?;
+    }
+    for evidence in &explanation.evidence {
+        let symbol = if evidence.found { '✓' } else { '✗' };
+        write!(w, "{symbol} [{}] {}", evidence.source, evidence.target)?;
+        match &evidence.detail {
+            Some(detail) => writeln!(w, " ({detail})")?,
+            None => writeln!(w)?,
+        }
+    }
+
+    if let Some(details) = explanation.output.as_ref().and_then(|o| o.details.as_ref()) {
+        writeln!(w, "\nDetails\n\n{details}")?;
+    }
+    writeln!(w)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+
+    use clomonitor_core::linter::{CheckOutput, Evidence, EvidenceSource};
+
+    use super::*;
+
+    #[test]
+    fn display_prints_explanation() {
+        let explanation = Explanation {
+            check_id: "security_policy",
+            output: Some(CheckOutput::not_passed()),
+            evidence: vec![
+                Evidence {
+                    source: EvidenceSource::File,
+                    target: "security*".to_string(),
+                    found: false,
+                    detail: None,
+                },
+                Evidence {
+                    source: EvidenceSource::Readme,
+                    target: r"(?im)^#+.*security.*$".to_string(),
+                    found: false,
+                    detail: None,
+                },
+                Evidence {
+                    source: EvidenceSource::GitHub,
+                    target: "security policy url".to_string(),
+                    found: false,
+                    detail: None,
+                },
+            ],
+        };
+
+        let mut w = Vec::new();
+        display(&explanation, &mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            r"
+Check: security_policy
+Result: ✗ Not passed
+
+What the check looked for
+
+✗ [File] security*
+✗ [README] (?im)^#+.*security.*$
+✗ [GitHub] security policy url
+
+"
+        );
+    }
+
+    #[test]
+    fn display_prints_explanation_of_scorecard_check() {
+        let explanation = Explanation {
+            check_id: "maintained",
+            output: Some(CheckOutput::passed().details(Some("# Maintained".to_string()))),
+            evidence: vec![Evidence {
+                source: EvidenceSource::Scorecard,
+                target: "Maintained".to_string(),
+                found: true,
+                detail: Some("score: 10, reason: 30 commits".to_string()),
+            }],
+        };
+
+        let mut w = Vec::new();
+        display(&explanation, &mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            r"
+Check: maintained
+Result: ✓ Passed
+
+What the check looked for
+
+✓ [Scorecard] Maintained (score: 10, reason: 30 commits)
+
+Details
+
+# Maintained
+
+"
+        );
+    }
+}
@@ -0,0 +1,504 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use clomonitor_core::linter::{CheckId, CheckSet, Exemption, Metadata, Report};
+
+use crate::{markdown::repository_name, metadata::METADATA_FILE};
+
+/// Placeholder replaced with the project name in the templates.
+const PROJECT_PLACEHOLDER: &str = "{project}";
+
+/// Starter files templates, indexed by check identifier.
+const TEMPLATES: [(&str, &str, &str); 8] = [
+    (
+        "adopters",
+        "ADOPTERS.md",
+        include_str!("../templates/fix/ADOPTERS.md"),
+    ),
+    (
+        "changelog",
+        "CHANGELOG.md",
+        include_str!("../templates/fix/CHANGELOG.md"),
+    ),
+    (
+        "code_of_conduct",
+        "CODE_OF_CONDUCT.md",
+        include_str!("../templates/fix/CODE_OF_CONDUCT.md"),
+    ),
+    (
+        "contributing",
+        "CONTRIBUTING.md",
+        include_str!("../templates/fix/CONTRIBUTING.md"),
+    ),
+    (
+        "governance",
+        "GOVERNANCE.md",
+        include_str!("../templates/fix/GOVERNANCE.md"),
+    ),
+    (
+        "maintainers",
+        "MAINTAINERS.md",
+        include_str!("../templates/fix/MAINTAINERS.md"),
+    ),
+    (
+        "roadmap",
+        "ROADMAP.md",
+        include_str!("../templates/fix/ROADMAP.md"),
+    ),
+    (
+        "security_policy",
+        "SECURITY.md",
+        include_str!("../templates/fix/SECURITY.md"),
+    ),
+];
+
+/// Arguments of the fix subcommand.
+#[derive(Debug, clap::Args)]
+pub(crate) struct FixArgs {
+    /// Repository local path (starter files will be written here)
+    #[clap(long)]
+    pub path: PathBuf,
+
+    /// Subdirectory of the repository where the project is located, for
+    /// repositories hosting multiple projects [projects/project1]. Files are
+    /// looked for there first, falling back to the repository root, and starter
+    /// files are written there
+    #[clap(long)]
+    pub subpath: Option<PathBuf>,
+
+    /// Repository url [https://github.com/org/repo] (used for some GitHub remote checks)
+    #[clap(long)]
+    pub url: String,
+
+    /// Sets of checks to run
+    #[clap(value_enum, long, default_values = &["code", "community"])]
+    pub check_set: Vec<CheckSet>,
+
+    /// Print the changes that would be made without writing any file
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// Add exemption stubs to the metadata file for the checks that did not
+    /// pass and have no starter file
+    #[clap(long)]
+    pub exemption_stubs: bool,
+}
+
+/// Action that fixes (or helps fixing) a check that did not pass.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Action {
+    /// Write a starter file from a template.
+    CreateFile {
+        check_id: CheckId,
+        file: &'static str,
+    },
+
+    /// Add an exemption stub to the metadata file.
+    AddExemption { check_id: CheckId },
+}
+
+/// Return the actions needed to fix the checks that did not pass in the report
+/// provided. Starter files are only written for checks that have a template
+/// when the file does not exist yet. Exemption stubs are added for the rest of
+/// the checks when requested, unless the metadata file already has an entry for
+/// them.
+pub(crate) fn plan(report: &Report, root: &Path, exemption_stubs: bool) -> Result<Vec<Action>> {
+    let exempted = exempted_checks(root)?;
+    let mut actions = Vec::new();
+    for (check_id, output) in report.outputs() {
+        if output.passed || output.exempt || output.failed {
+            continue;
+        }
+        if let Some((_, file, _)) = TEMPLATES.iter().find(|(id, _, _)| *id == check_id) {
+            if !root.join(file).exists() {
+                actions.push(Action::CreateFile { check_id, file });
+            }
+        } else if exemption_stubs && !exempted.iter().any(|id| id == check_id) {
+            actions.push(Action::AddExemption { check_id });
+        }
+    }
+    Ok(actions)
+}
+
+/// Apply the actions provided to the repository located at the path provided.
+/// Existing files are never overwritten (exemption stubs are inserted in the
+/// metadata file). When running in dry-run mode no files are written.
+pub(crate) fn apply(
+    actions: &[Action],
+    root: &Path,
+    url: &str,
+    dry_run: bool,
+    w: &mut impl io::Write,
+) -> Result<()> {
+    if actions.is_empty() {
+        writeln!(w, "Nothing to fix")?;
+        return Ok(());
+    }
+
+    let project = repository_name(url);
+    let (created, added) = if dry_run {
+        ("Would create", "Would add")
+    } else {
+        ("Created", "Added")
+    };
+    let mut exemptions = Vec::new();
+    for action in actions {
+        match action {
+            Action::CreateFile { check_id, file } => {
+                if !dry_run {
+                    create_file(&root.join(file), &template(check_id, &project))?;
+                }
+                writeln!(w, "{created} {file} ({check_id})")?;
+            }
+            Action::AddExemption { check_id } => {
+                exemptions.push(*check_id);
+                writeln!(w, "{added} exemption stub to {METADATA_FILE} ({check_id})")?;
+            }
+        }
+    }
+    if !dry_run && !exemptions.is_empty() {
+        add_exemptions(&root.join(METADATA_FILE), &exemptions)?;
+    }
+    if !exemptions.is_empty() {
+        writeln!(
+            w,
+            "\nExemption stubs only take effect once a reason justifying them is provided"
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Return the starter file content for the check provided.
+fn template(check_id: &str, project: &str) -> String {
+    TEMPLATES
+        .iter()
+        .find(|(id, _, _)| *id == check_id)
+        .map(|(_, _, content)| content.replace(PROJECT_PLACEHOLDER, project))
+        .unwrap_or_default()
+}
+
+/// Create a new file with the content provided. It fails if the file already
+/// exists.
+fn create_file(path: &Path, content: &str) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("error creating {}", path.display()))?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
+/// Return the identifiers of the checks with an entry in the exemptions
+/// section of the metadata file of the repository provided.
+fn exempted_checks(root: &Path) -> Result<Vec<String>> {
+    let md = Metadata::from(root.join(METADATA_FILE)).context("error parsing metadata file")?;
+    Ok(md
+        .and_then(|md| md.exemptions)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|e| e.check)
+        .collect())
+}
+
+/// Add exemption stubs for the checks provided to the metadata file, creating
+/// it if needed. The rest of the file content (including comments) is kept.
+fn add_exemptions(path: &Path, check_ids: &[CheckId]) -> Result<()> {
+    let content = if path.exists() {
+        fs::read_to_string(path).context("error reading metadata file")?
+    } else {
+        String::new()
+    };
+    fs::write(path, insert_exemptions(&content, check_ids)?).context("error writing metadata file")
+}
+
+/// Insert exemption stubs for the checks provided in the metadata file content
+/// provided, returning the updated content.
+fn insert_exemptions(content: &str, check_ids: &[CheckId]) -> Result<String> {
+    let mut lines: Vec<String> = content.lines().map(ToString::to_string).collect();
+    let exemptions: Vec<Exemption> = check_ids
+        .iter()
+        .map(|check_id| Exemption {
+            check: (*check_id).to_string(),
+            ..Exemption::default()
+        })
+        .collect();
+    let entries = serde_yaml::to_string(&exemptions)?;
+    let stubs = |indent: &str| -> Vec<String> {
+        entries
+            .lines()
+            .map(|line| {
+                if line.trim_start().starts_with("reason:") {
+                    format!("{indent}{line} # Justification of this exemption (mandatory)")
+                } else {
+                    format!("{indent}{line}")
+                }
+            })
+            .collect()
+    };
+
+    // Look for an existing exemptions section
+    let section = lines.iter().position(|line| {
+        let line = line.split('#').next().unwrap_or_default().trim_end();
+        line == "exemptions:" || line.replace(' ', "") == "exemptions:[]"
+    });
+    if let Some(i) = section {
+        // Use the same indentation of the existing entries (if any)
+        let indent = lines[i + 1..]
+            .iter()
+            .find(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .filter(|line| line.trim_start().starts_with("- "))
+            .map_or("  ".to_string(), |line| {
+                line[..line.len() - line.trim_start().len()].to_string()
+            });
+        if lines[i].contains("[]") {
+            lines[i] = "exemptions:".to_string();
+        }
+        lines.splice(i + 1..=i, stubs(&indent));
+    } else {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push("# Checks exemptions".to_string());
+        lines.push("exemptions:".to_string());
+        lines.extend(stubs("  "));
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+
+    use clomonitor_core::linter::{
+        BestPractices, CheckOutput, Documentation, Security, check_weight,
+    };
+
+    use super::*;
+
+    fn report() -> Report {
+        Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                changelog: Some(CheckOutput::not_passed()),
+                code_of_conduct: Some(CheckOutput::not_passed()),
+                contributing: Some(CheckOutput::exempt()),
+                governance: Some(CheckOutput::failed()),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                artifacthub_badge: Some(CheckOutput::not_passed()),
+                openssf_badge: Some(CheckOutput::not_passed()),
+                slack_presence: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn plan_returns_actions_for_checks_not_passed() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("SECURITY.md"), "").unwrap();
+        fs::write(
+            root.path().join(METADATA_FILE),
+            "exemptions:\n  - check: openssf_badge\n    reason: \"\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            plan(&report(), root.path(), true).unwrap(),
+            vec![
+                Action::CreateFile {
+                    check_id: "changelog",
+                    file: "CHANGELOG.md"
+                },
+                Action::CreateFile {
+                    check_id: "code_of_conduct",
+                    file: "CODE_OF_CONDUCT.md"
+                },
+                Action::AddExemption {
+                    check_id: "artifacthub_badge"
+                },
+                Action::AddExemption {
+                    check_id: "slack_presence"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_only_adds_exemption_stubs_when_requested() {
+        let root = tempfile::tempdir().unwrap();
+
+        assert_eq!(
+            plan(&report(), root.path(), false).unwrap(),
+            vec![
+                Action::CreateFile {
+                    check_id: "changelog",
+                    file: "CHANGELOG.md",
+                },
+                Action::CreateFile {
+                    check_id: "code_of_conduct",
+                    file: "CODE_OF_CONDUCT.md",
+                },
+                Action::CreateFile {
+                    check_id: "security_policy",
+                    file: "SECURITY.md",
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_adds_exemption_stubs_for_weighted_checks() {
+        let root = tempfile::tempdir().unwrap();
+        let report = Report {
+            best_practices: BestPractices {
+                openssf_badge: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(check_weight("openssf_badge").unwrap() > 0);
+        assert_eq!(
+            plan(&report, root.path(), true).unwrap(),
+            vec![Action::AddExemption {
+                check_id: "openssf_badge"
+            }]
+        );
+    }
+
+    #[test]
+    fn apply_writes_files_and_exemptions() {
+        let root = tempfile::tempdir().unwrap();
+        let actions = plan(&report(), root.path(), true).unwrap();
+
+        let mut w = Vec::new();
+        apply(
+            &actions,
+            root.path(),
+            "https://github.com/org/repo",
+            false,
+            &mut w,
+        )
+        .unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            "Created CHANGELOG.md (changelog)
+Created CODE_OF_CONDUCT.md (code_of_conduct)
+Added exemption stub to .clomonitor.yml (artifacthub_badge)
+Added exemption stub to .clomonitor.yml (openssf_badge)
+Added exemption stub to .clomonitor.yml (slack_presence)
+Created SECURITY.md (security_policy)
+
+Exemption stubs only take effect once a reason justifying them is provided
+"
+        );
+        assert!(
+            fs::read_to_string(root.path().join("CODE_OF_CONDUCT.md"))
+                .unwrap()
+                .starts_with("# repo Code of Conduct")
+        );
+        assert_eq!(
+            fs::read_to_string(root.path().join(METADATA_FILE)).unwrap(),
+            "# Checks exemptions
+exemptions:
+  - check: artifacthub_badge
+    reason: '' # Justification of this exemption (mandatory)
+  - check: openssf_badge
+    reason: '' # Justification of this exemption (mandatory)
+  - check: slack_presence
+    reason: '' # Justification of this exemption (mandatory)
+"
+        );
+        assert_eq!(
+            Metadata::from(root.path().join(METADATA_FILE))
+                .unwrap()
+                .and_then(|md| md.exemptions)
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(plan(&report(), root.path(), true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn apply_dry_run_does_not_write_files() {
+        let root = tempfile::tempdir().unwrap();
+        let actions = plan(&report(), root.path(), true).unwrap();
+
+        let mut w = Vec::new();
+        apply(
+            &actions,
+            root.path(),
+            "https://github.com/org/repo",
+            true,
+            &mut w,
+        )
+        .unwrap();
+
+        assert!(
+            str::from_utf8(w.as_slice())
+                .unwrap()
+                .starts_with("Would create CHANGELOG.md (changelog)\n")
+        );
+        assert_eq!(fs::read_dir(root.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn create_file_never_overwrites_existing_files() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("CHANGELOG.md");
+        fs::write(&path, "existing").unwrap();
+
+        assert!(create_file(&path, "new").is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "existing");
+    }
+
+    #[test]
+    fn insert_exemptions_in_existing_section() {
+        let content = "# Metadata
+exemptions: # Exemptions
+- check: dco
+  reason: not needed
+
+licenseScanning:
+  url: https://license-scanning-results.url
+";
+
+        assert_eq!(
+            insert_exemptions(content, &["cla"]).unwrap(),
+            r"# Metadata
+exemptions: # Exemptions
+- check: cla
+  reason: '' # Justification of this exemption (mandatory)
+- check: dco
+  reason: not needed
+
+licenseScanning:
+  url: https://license-scanning-results.url
+"
+        );
+    }
+
+    #[test]
+    fn insert_exemptions_in_empty_section() {
+        assert_eq!(
+            insert_exemptions("exemptions: []\n", &["cla"]).unwrap(),
+            "exemptions:\n  - check: cla\n    reason: '' # Justification of this exemption (mandatory)\n"
+        );
+    }
+}
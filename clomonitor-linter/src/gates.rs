@@ -0,0 +1,366 @@
+use std::{collections::BTreeMap, io};
+
+use anyhow::{Result, format_err};
+use clomonitor_core::{
+    linter::{self, CheckMap, REPORT_SECTIONS, Report, check_equivalences, check_weight},
+    score::Score,
+};
+
+/// Gates the linter results must satisfy, regardless of the global score.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Gates {
+    pub required_checks: Vec<String>,
+    pub min_section_scores: BTreeMap<String, f64>,
+}
+
+impl Gates {
+    /// Create a new gates instance merging the gates defined in the repository
+    /// metadata file and the ones provided in the command line. Minimum
+    /// section scores provided in the command line take precedence.
+    pub(crate) fn new(
+        md_gates: Option<linter::Gates>,
+        required_checks: &[String],
+        min_section_scores: &[(String, f64)],
+    ) -> Result<Self> {
+        let mut gates = Gates::default();
+        if let Some(md_gates) = md_gates {
+            gates.required_checks = md_gates.required_checks.unwrap_or_default();
+            gates.min_section_scores = md_gates.min_section_scores.unwrap_or_default();
+        }
+        for check_id in required_checks {
+            if !gates.required_checks.contains(check_id) {
+                gates.required_checks.push(check_id.clone());
+            }
+        }
+        gates
+            .min_section_scores
+            .extend(min_section_scores.iter().cloned());
+
+        gates.validate()?;
+        Ok(gates)
+    }
+
+    /// Check if there are no gates defined.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.required_checks.is_empty() && self.min_section_scores.is_empty()
+    }
+
+    /// Verify that the checks and sections used in the gates exist.
+    fn validate(&self) -> Result<()> {
+        if let Some(check_id) = self
+            .required_checks
+            .iter()
+            .find(|check_id| check_weight(check_id).is_none())
+        {
+            return Err(format_err!("invalid gate: unknown check {check_id}"));
+        }
+        if let Some(section) = self
+            .min_section_scores
+            .keys()
+            .find(|section| !is_section(section))
+        {
+            return Err(format_err!("invalid gate: unknown section {section}"));
+        }
+        Ok(())
+    }
+
+    /// Evaluate the gates against the linter results provided. A required
+    /// check exempted because an equivalent check passed satisfies its gate
+    /// (the equivalence applied is noted in the result), whereas any other
+    /// exemption does not.
+    pub(crate) fn evaluate(
+        &self,
+        report: &Report,
+        score: &Score,
+        equivalences: Option<&[Vec<String>]>,
+    ) -> Vec<GateResult> {
+        let checks = report.checks();
+        let mut results = Vec::new();
+
+        // Required checks
+        for check_id in &self.required_checks {
+            let output = checks
+                .get(check_id.as_str())
+                .and_then(|c| c.output.as_ref());
+            let mut note = None;
+            let failure = match output {
+                None => Some("not run".to_string()),
+                Some(output) if output.failed => Some("failed".to_string()),
+                Some(output) if output.exempt => {
+                    match equivalent_check_passed(&checks, check_id, equivalences) {
+                        Some(name) => {
+                            note = Some(format!("exempt: {name} check passed"));
+                            None
+                        }
+                        None => Some("exempt".to_string()),
+                    }
+                }
+                Some(output) if !output.passed => Some("not passed".to_string()),
+                Some(_) => None,
+            };
+            results.push(GateResult {
+                gate: format!("Required check {check_id}"),
+                failure,
+                note,
+            });
+        }
+
+        // Minimum section scores
+        for (section, min_score) in &self.min_section_scores {
+            let failure = match score.section(section) {
+                None => Some("section not scored".to_string()),
+                Some(v) if v < *min_score => Some(format!("score: {}", v.round())),
+                Some(_) => None,
+            };
+            results.push(GateResult {
+                gate: format!("Minimum {section} score {min_score}"),
+                failure,
+                note: None,
+            });
+        }
+
+        results
+    }
+}
+
+/// Result of evaluating a gate.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GateResult {
+    pub gate: String,
+    pub failure: Option<String>,
+    pub note: Option<String>,
+}
+
+/// Print the gates results provided.
+pub(crate) fn display(results: &[GateResult], w: &mut impl io::Write) -> Result<()> {
+    writeln!(w, "Gates\n")?;
+    for result in results {
+        match (&result.failure, &result.note) {
+            (None, None) => writeln!(w, "✓ {}", result.gate)?,
+            (None, Some(note)) => writeln!(w, "✓ {} ({note})", result.gate)?,
+            (Some(failure), _) => writeln!(w, "✗ {} ({failure})", result.gate)?,
+        }
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Parse a minimum section score provided in the command line [section=score].
+pub(crate) fn parse_min_section_score(value: &str) -> Result<(String, f64), String> {
+    let Some((section, min_score)) = value.split_once('=') else {
+        return Err("expected format: section=score".to_string());
+    };
+    if !is_section(section) {
+        let sections: Vec<&str> = REPORT_SECTIONS.iter().map(|s| s.id).collect();
+        return Err(format!(
+            "unknown section {section} (possible values: {})",
+            sections.join(", ")
+        ));
+    }
+    let min_score = min_score
+        .parse::<f64>()
+        .map_err(|err| format!("invalid score: {err}"))?;
+    Ok((section.to_string(), min_score))
+}
+
+/// Check if the section provided is one of the report sections.
+fn is_section(section: &str) -> bool {
+    REPORT_SECTIONS.iter().any(|s| s.id == section)
+}
+
+/// Return the name of the check equivalent to the one provided that passed,
+/// if any, using the equivalence groups applied to the report.
+fn equivalent_check_passed(
+    checks: &CheckMap,
+    check_id: &str,
+    equivalences: Option<&[Vec<String>]>,
+) -> Option<&'static str> {
+    check_equivalences(equivalences)
+        .iter()
+        .filter(|group| group.iter().any(|id| id == check_id))
+        .flatten()
+        .filter(|id| *id != check_id)
+        .filter_map(|id| checks.get(id.as_str()))
+        .find(|check| check.output.as_ref().is_some_and(|o| o.passed && !o.exempt))
+        .map(|check| check.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+
+    use clomonitor_core::linter::{BestPractices, CheckOutput, Documentation, License, Security};
+
+    use super::*;
+
+    #[test]
+    fn parse_min_section_score_success() {
+        assert_eq!(
+            parse_min_section_score("security=80"),
+            Ok(("security".to_string(), 80.0))
+        );
+    }
+
+    #[test]
+    fn parse_min_section_score_invalid_values() {
+        assert!(parse_min_section_score("security").is_err());
+        assert!(parse_min_section_score("unknown=80").is_err());
+        assert!(parse_min_section_score("security=high").is_err());
+    }
+
+    #[test]
+    fn new_merges_metadata_and_args_gates() {
+        let md_gates = linter::Gates {
+            required_checks: Some(vec!["license_approved".to_string()]),
+            min_section_scores: Some(BTreeMap::from([
+                ("legal".to_string(), 100.0),
+                ("security".to_string(), 80.0),
+            ])),
+        };
+
+        assert_eq!(
+            Gates::new(
+                Some(md_gates),
+                &[
+                    "license_approved".to_string(),
+                    "security_policy".to_string()
+                ],
+                &[("security".to_string(), 90.0)],
+            )
+            .unwrap(),
+            Gates {
+                required_checks: vec![
+                    "license_approved".to_string(),
+                    "security_policy".to_string()
+                ],
+                min_section_scores: BTreeMap::from([
+                    ("legal".to_string(), 100.0),
+                    ("security".to_string(), 90.0),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn new_fails_when_gates_are_invalid() {
+        let md_gates = linter::Gates {
+            required_checks: None,
+            min_section_scores: Some(BTreeMap::from([("unknown".to_string(), 80.0)])),
+        };
+
+        assert_eq!(
+            Gates::new(None, &["unknown".to_string()], &[])
+                .unwrap_err()
+                .to_string(),
+            "invalid gate: unknown check unknown"
+        );
+        assert_eq!(
+            Gates::new(Some(md_gates), &[], &[])
+                .unwrap_err()
+                .to_string(),
+            "invalid gate: unknown section unknown"
+        );
+    }
+
+    #[test]
+    fn evaluate_reports_failed_gates() {
+        let gates = Gates {
+            required_checks: vec![
+                "license_approved".to_string(),
+                "security_policy".to_string(),
+                "dangerous_workflow".to_string(),
+            ],
+            min_section_scores: BTreeMap::from([
+                ("legal".to_string(), 50.0),
+                ("license".to_string(), 80.0),
+                ("security".to_string(), 80.0),
+            ]),
+        };
+        let report = Report {
+            license: License {
+                license_approved: Some(CheckOutput::passed()),
+                ..Default::default()
+            },
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let score = Score {
+            license: Some(100.0),
+            security: Some(70.4),
+            ..Score::default()
+        };
+
+        let results = gates.evaluate(&report, &score, None);
+        let mut w = Vec::new();
+        display(&results, &mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            "Gates
+
+✓ Required check license_approved
+✗ Required check security_policy (not passed)
+✗ Required check dangerous_workflow (not run)
+✗ Minimum legal score 50 (section not scored)
+✓ Minimum license score 80
+✗ Minimum security score 80 (score: 70)
+
+"
+        );
+    }
+
+    #[test]
+    fn evaluate_required_check_exempt_by_equivalence_is_satisfied() {
+        let gates = Gates {
+            required_checks: vec![
+                "dco".to_string(),
+                "security_policy".to_string(),
+                "code_of_conduct".to_string(),
+            ],
+            min_section_scores: BTreeMap::new(),
+        };
+        let report = Report {
+            best_practices: BestPractices {
+                cla: Some(CheckOutput::passed()),
+                dco: Some(
+                    CheckOutput::exempt().exemption_reason(Some("CLA check passed".to_string())),
+                ),
+                ..Default::default()
+            },
+            security: Security {
+                security_policy: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            documentation: Documentation {
+                code_of_conduct: Some(
+                    CheckOutput::exempt()
+                        .exemption_reason(Some("Security policy check passed".to_string())),
+                ),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let equivalences = vec![
+            vec!["cla".to_string(), "dco".to_string()],
+            vec!["security_policy".to_string(), "code_of_conduct".to_string()],
+        ];
+
+        let results = gates.evaluate(&report, &Score::default(), Some(&equivalences));
+        let mut w = Vec::new();
+        display(&results, &mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            "Gates
+
+✓ Required check dco (exempt: CLA check passed)
+✗ Required check security_policy (not passed)
+✗ Required check code_of_conduct (exempt)
+
+"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::{fmt::Write as _, io};
+
+use anyhow::Result;
+use clomonitor_core::linter::{CheckId, CheckOutput, Report};
+
+/// Print the linter results provided in JUnit XML format. Each check is
+/// reported as a testcase, grouped in a testsuite per report section. Exempt
+/// checks and checks that were not run are reported as skipped.
+pub(crate) fn display(report: &Report, w: &mut impl io::Write) -> Result<()> {
+    let mut suites = String::new();
+    let mut totals = Counters::default();
+    for (section, outputs) in report.sections_outputs() {
+        let mut cases = String::new();
+        let mut counters = Counters::default();
+        for (check_id, output) in &outputs {
+            counters.add(output.as_ref());
+            write_testcase(&mut cases, section, check_id, output.as_ref())?;
+        }
+        writeln!(
+            suites,
+            r#"  <testsuite name="{section}" {}>"#,
+            counters.attributes()
+        )?;
+        suites.push_str(&cases);
+        writeln!(suites, "  </testsuite>")?;
+        totals.merge(&counters);
+    }
+
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        w,
+        r#"<testsuites name="clomonitor-linter" {}>"#,
+        totals.attributes()
+    )?;
+    write!(w, "{suites}")?;
+    writeln!(w, "</testsuites>")?;
+
+    Ok(())
+}
+
+/// Testcases counters used in the testsuites attributes.
+#[derive(Debug, Default)]
+struct Counters {
+    tests: usize,
+    failures: usize,
+    errors: usize,
+    skipped: usize,
+}
+
+impl Counters {
+    /// Update the counters with the check output provided.
+    fn add(&mut self, output: Option<&CheckOutput>) {
+        self.tests += 1;
+        match output {
+            None => self.skipped += 1,
+            Some(output) if output.exempt => self.skipped += 1,
+            Some(output) if output.failed => self.errors += 1,
+            Some(output) if !output.passed => self.failures += 1,
+            Some(_) => {}
+        }
+    }
+
+    /// Add the counters provided to these ones.
+    fn merge(&mut self, other: &Counters) {
+        self.tests += other.tests;
+        self.failures += other.failures;
+        self.errors += other.errors;
+        self.skipped += other.skipped;
+    }
+
+    /// Return the counters formatted as XML attributes.
+    fn attributes(&self) -> String {
+        format!(
+            r#"tests="{}" failures="{}" errors="{}" skipped="{}""#,
+            self.tests, self.failures, self.errors, self.skipped
+        )
+    }
+}
+
+/// Write the testcase corresponding to the check output provided.
+fn write_testcase(
+    w: &mut String,
+    section: &str,
+    check_id: CheckId,
+    output: Option<&CheckOutput>,
+) -> Result<()> {
+    let testcase = format!(r#"    <testcase classname="{section}" name="{check_id}""#);
+    match output {
+        None => {
+            writeln!(w, "{testcase}>")?;
+            writeln!(w, r#"      <skipped message="Not applicable"/>"#)?;
+            writeln!(w, "    </testcase>")?;
+        }
+        Some(output) if output.exempt => {
+            let reason = output.exemption_reason.as_deref().unwrap_or_default();
+            writeln!(w, "{testcase}>")?;
+            writeln!(
+                w,
+                r#"      <skipped message="Exempt: {}"/>"#,
+                escape(reason)
+            )?;
+            writeln!(w, "    </testcase>")?;
+        }
+        Some(output) if output.failed => {
+            let reason = output.fail_reason.as_deref().unwrap_or_default();
+            writeln!(w, "{testcase}>")?;
+            writeln!(w, r#"      <error message="{}"/>"#, escape(reason))?;
+            writeln!(w, "    </testcase>")?;
+        }
+        Some(output) if !output.passed => {
+            writeln!(w, "{testcase}>")?;
+            match &output.details {
+                Some(details) => writeln!(
+                    w,
+                    r#"      <failure message="Check not passed">{}</failure>"#,
+                    escape(details)
+                )?,
+                None => writeln!(w, r#"      <failure message="Check not passed"/>"#)?,
+            }
+            writeln!(w, "    </testcase>")?;
+        }
+        Some(_) => writeln!(w, "{testcase}/>")?,
+    }
+    Ok(())
+}
+
+/// Escape the text provided so that it can be used in XML content and
+/// attributes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, str};
+
+    use clomonitor_core::linter::{BestPractices, Documentation, Legal, Security};
+
+    use super::*;
+
+    #[test]
+    fn escape_special_characters() {
+        assert_eq!(
+            escape(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn display_prints_junit_results() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(
+                    CheckOutput::not_passed().details(Some("# Issues <found>".to_string())),
+                ),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                artifacthub_badge: Some(
+                    CheckOutput::exempt().exemption_reason(Some("No artifacts".to_string())),
+                ),
+                dco: Some(CheckOutput::failed().fail_reason(Some("error".to_string()))),
+                ..Default::default()
+            },
+            security: Security {
+                binary_artifacts: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            legal: Legal {
+                trademark_disclaimer: Some(CheckOutput::passed()),
+            },
+            ..Default::default()
+        };
+
+        let mut w = Vec::new();
+        display(&report, &mut w).unwrap();
+
+        let golden_path = "src/testdata/junit.golden";
+
+        // Write output to golden file (uncomment line below to update golden)
+        // fs::write(golden_path, &w).unwrap();
+
+        // Check output matches golden file content
+        let output = str::from_utf8(w.as_slice()).unwrap();
+        let golden = fs::read_to_string(golden_path).unwrap();
+        assert_eq!(output, golden);
+    }
+}
@@ -1,16 +1,41 @@
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::doc_markdown, clippy::wildcard_imports)]
 
-use std::{env, io, path::PathBuf};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
 
-use anyhow::{Result, format_err};
-use clap::{Parser, ValueEnum};
+use anyhow::{Context, Result, format_err};
+use clap::{Parser, Subcommand, ValueEnum};
 use clomonitor_core::{
-    linter::{CheckSet, CoreLinter, Linter, LinterInput},
+    git::{Git, GitCLI},
+    linter::{
+        self, ChatPlatform, CheckSet, CoreLinter, Linter, LinterInput, ResponsivenessSlo,
+        check_weight,
+    },
     score,
 };
 use serde_json::json;
+use tempfile::TempDir;
+
+use crate::{
+    baseline::{Baseline, Diff},
+    explain::ExplainArgs,
+    fix::FixArgs,
+    gates::{Gates, parse_min_section_score},
+    metadata::{METADATA_FILE, ValidateArgs},
+};
 
+mod baseline;
+mod batch;
+mod explain;
+mod fix;
+mod gates;
+mod junit;
+mod markdown;
+mod metadata;
+mod sarif;
 mod table;
 
 /// Environment variable containing Github token.
@@ -20,6 +45,9 @@ const GITHUB_TOKEN: &str = "GITHUB_TOKEN";
 #[derive(Debug, Clone, ValueEnum)]
 pub enum Format {
     Json,
+    Junit,
+    Markdown,
+    Sarif,
     Table,
 }
 
@@ -39,21 +67,55 @@ provided, or non-zero otherwise.
 
 This tool uses the Github GraphQL API for some checks, which requires
 authentication. Please make sure you provide a Github token (with public_repo
-scope) by setting the GITHUB_TOKEN environment variable."
+scope) by setting the GITHUB_TOKEN environment variable.",
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true
 )]
 struct Args {
-    /// Repository local path (used for checks that can be done locally)
-    #[clap(long)]
-    path: PathBuf,
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    /// Repository local path (used for checks that can be done locally). When
+    /// not provided, the repository will be cloned from the url
+    #[clap(long, conflicts_with = "data_file")]
+    path: Option<PathBuf>,
+
+    /// Subdirectory of the repository where the project is located, for
+    /// repositories hosting multiple projects [projects/project1]. Files are
+    /// looked for there first, falling back to the repository root
+    #[clap(long, conflicts_with = "data_file")]
+    subpath: Option<PathBuf>,
 
     /// Repository url [https://github.com/org/repo] (used for some GitHub remote checks)
-    #[clap(long)]
-    url: String,
+    #[clap(
+        long,
+        required_unless_present = "data_file",
+        conflicts_with = "data_file"
+    )]
+    url: Option<String>,
 
     /// Sets of checks to run
     #[clap(value_enum, long, default_values = &["code", "community"])]
     check_set: Vec<CheckSet>,
 
+    /// Only run the check provided (can be used multiple times)
+    #[clap(
+        long,
+        value_name = "CHECK_ID",
+        value_parser = parse_check_id,
+        conflicts_with = "data_file"
+    )]
+    check: Vec<String>,
+
+    /// Skip the check provided (can be used multiple times)
+    #[clap(
+        long,
+        value_name = "CHECK_ID",
+        value_parser = parse_check_id,
+        conflicts_with = "data_file"
+    )]
+    skip_check: Vec<String>,
+
     /// Linter pass score
     #[clap(long, default_value = "75")]
     pass_score: f64,
@@ -61,31 +123,218 @@ struct Args {
     /// Output format
     #[clap(value_enum, long, default_value = "table")]
     format: Format,
+
+    /// Maximum median time to first response in issues and pull requests, in hours
+    #[clap(long, default_value = "72")]
+    slo_first_response_hours: u64,
+
+    /// Maximum median time to close issues and pull requests, in hours
+    #[clap(long, default_value = "720")]
+    slo_close_hours: u64,
+
+    /// Checks considered equivalent, comma separated [slack_presence,github_discussions]
+    /// (can be used multiple times). When any of them passes, the others are exempt.
+    /// When provided, the default equivalences are not applied
+    #[clap(long, value_name = "CHECK_IDS", value_parser = parse_check_equivalence)]
+    check_equivalence: Vec<String>,
+
+    /// Chat platform preferred by the project's foundation (can be used
+    /// multiple times). When provided, the community chat check only passes
+    /// if a channel in any of them is found
+    #[clap(value_enum, long, value_name = "PLATFORM")]
+    preferred_chat_platform: Vec<ChatPlatform>,
+
+    /// Foundation data file (same format as data/cncf.yaml). All the
+    /// repositories listed will be linted using their own check sets
+    #[clap(long)]
+    data_file: Option<PathBuf>,
+
+    /// Identifier of the foundation the projects in the data file belong to
+    /// [cncf]
+    #[clap(long, requires = "data_file")]
+    foundation: Option<String>,
+
+    /// Landscape url of the foundation the projects in the data file belong
+    /// to (used by the summary table check)
+    #[clap(long, requires = "foundation")]
+    landscape_url: Option<String>,
+
+    /// Number of repositories linted concurrently when using a data file
+    #[clap(long, default_value = "5")]
+    concurrency: usize,
+
+    /// Previous linter results (json output format) to compare with. When
+    /// provided, the linter only fails on regressions instead of using the
+    /// pass score
+    #[clap(long, conflicts_with = "data_file")]
+    baseline: Option<PathBuf>,
+
+    /// Maximum global score drop allowed when comparing with the baseline
+    #[clap(long, default_value = "0", requires = "baseline")]
+    max_score_drop: f64,
+
+    /// Check that must pass regardless of the score (can be used multiple times)
+    #[clap(long, value_name = "CHECK_ID", conflicts_with = "data_file")]
+    require_check: Vec<String>,
+
+    /// Minimum score required for a section [security=80] (can be used multiple times)
+    #[clap(
+        long,
+        value_name = "SECTION=SCORE",
+        value_parser = parse_min_section_score,
+        conflicts_with = "data_file"
+    )]
+    min_section_score: Vec<(String, f64)>,
+}
+
+/// Linter subcommands.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Write starter files for the checks that did not pass and, optionally,
+    /// exemption stubs for the ones that cannot be fixed that way
+    Fix(FixArgs),
+
+    /// Run a single check and explain how it decided its result
+    Explain(ExplainArgs),
+
+    /// Work with the repository metadata file (.clomonitor.yml)
+    Metadata {
+        #[clap(subcommand)]
+        command: MetadataCommand,
+    },
+
+    /// Work with the checks available
+    Checks {
+        #[clap(subcommand)]
+        command: ChecksCommand,
+    },
+}
+
+/// Metadata subcommands.
+#[derive(Debug, Subcommand)]
+enum MetadataCommand {
+    /// Validate the metadata file, reporting unknown keys, unknown checks and
+    /// exemptions that would be ignored
+    Validate(ValidateArgs),
+}
+
+/// Checks subcommands.
+#[derive(Debug, Subcommand)]
+enum ChecksCommand {
+    /// List all the checks available, including their weight, check sets and
+    /// section
+    List,
+}
+
+impl Args {
+    /// Return the repository url provided (empty when using a data file).
+    fn url(&self) -> &str {
+        self.url.as_deref().unwrap_or_default()
+    }
+
+    /// Return the groups of equivalent checks provided, if any.
+    fn check_equivalences(&self) -> Option<Vec<Vec<String>>> {
+        if self.check_equivalence.is_empty() {
+            return None;
+        }
+        Some(
+            self.check_equivalence
+                .iter()
+                .map(|group| group.split(',').map(ToString::to_string).collect())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+impl Default for Args {
+    fn default() -> Self {
+        Self::parse_from([
+            "clomonitor-linter",
+            "--url",
+            "https://github.com/test-org/test-repo",
+        ])
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
 
+    // Run subcommands that do not require a Github token
+    match &args.command {
+        Some(Command::Metadata {
+            command: MetadataCommand::Validate(validate_args),
+        }) => return run_metadata_validate(validate_args),
+        Some(Command::Checks {
+            command: ChecksCommand::List,
+        }) => return table::display_checks(&linter::registered_checks(), &mut io::stdout()),
+        _ => {}
+    }
+
     // Check if required Github token is present in environment
     let Ok(github_token) = env::var(GITHUB_TOKEN) else {
         return Err(format_err!("{GITHUB_TOKEN} not found in environment"));
     };
 
+    // Run subcommand when provided
+    match &args.command {
+        Some(Command::Fix(fix_args)) => return run_fix(fix_args, github_token).await,
+        Some(Command::Explain(explain_args)) => {
+            return run_explain(explain_args, github_token).await;
+        }
+        _ => {}
+    }
+
+    // Lint all repositories in the data file when provided
+    if let Some(data_file) = &args.data_file {
+        return run_batch(&args, data_file, &github_token).await;
+    }
+
+    // Load baseline when provided
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+
+    // Clone the repository when no local path has been provided
+    let (root, tmp_dir) = checkout(args.path.as_ref(), args.url()).await?;
+
     // Lint repository provided
     let input = LinterInput {
         project: None,
-        root: args.path.clone(),
-        url: args.url.clone(),
+        root,
+        path: args.subpath.clone(),
+        url: args.url().to_string(),
         check_sets: args.check_set.clone(),
+        checks: args.check.clone(),
+        skip_checks: args.skip_check.clone(),
         github_token,
+        responsiveness_slo: ResponsivenessSlo {
+            first_response_hours: args.slo_first_response_hours,
+            close_hours: args.slo_close_hours,
+        },
+        check_equivalences: args.check_equivalences(),
+        preferred_chat_platforms: args.preferred_chat_platform.clone(),
     };
-    let report = CoreLinter::new().lint(&input).await?;
+
+    // Validate the gates provided in the arguments before linting
+    Gates::new(None, &args.require_check, &args.min_section_score)?;
+
+    let (report, md) = CoreLinter::new().lint_with_metadata(&input).await?;
+
+    // Setup gates (from the repository metadata file and the arguments)
+    let gates = Gates::new(
+        md.and_then(|md| md.gates),
+        &args.require_check,
+        &args.min_section_score,
+    )?;
     let score = score::calculate(&report);
+    drop(tmp_dir);
 
     // Display results using the requested format
     match args.format {
         Format::Table => table::display(&report, &score, &args, &mut io::stdout())?,
+        Format::Sarif => sarif::display(&report, &args, &mut io::stdout())?,
+        Format::Junit => junit::display(&report, &mut io::stdout())?,
+        Format::Markdown => markdown::display(&report, &score, &args, &mut io::stdout())?,
         Format::Json => {
             let output = json!({
                 "report": report,
@@ -95,8 +344,179 @@ async fn main() -> Result<()> {
         }
     }
 
-    // Check if the linter succeeded according to the provided pass score
-    if score.global() < args.pass_score {
+    // Check if the linter succeeded. When a baseline is provided, the linter
+    // only fails on regressions. Otherwise the provided pass score is used
+    let mut passed = if let Some(baseline) = baseline {
+        let diff = Diff::new(&report, &score, &baseline);
+        diff.display(&mut info_writer(&args.format))?;
+        !diff.regressed(args.max_score_drop)
+    } else {
+        score.global() >= args.pass_score
+    };
+
+    // Gates must be satisfied in any case
+    if !gates.is_empty() {
+        let results = gates.evaluate(&report, &score, input.check_equivalences.as_deref());
+        gates::display(&results, &mut info_writer(&args.format))?;
+        if results.iter().any(|result| result.failure.is_some()) {
+            passed = false;
+        }
+    }
+
+    if !passed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Return the writer used to print additional information, like the baseline
+/// comparison or the gates results. Stderr is used for all formats but table,
+/// so that the information does not interfere with the results output.
+fn info_writer(format: &Format) -> Box<dyn io::Write> {
+    if let Format::Table = format {
+        Box::new(io::stdout())
+    } else {
+        Box::new(io::stderr())
+    }
+}
+
+/// Clone the repository from the url provided when no local path is given,
+/// returning the path to lint and the temporary directory used, if any.
+async fn checkout(path: Option<&PathBuf>, url: &str) -> Result<(PathBuf, Option<TempDir>)> {
+    if let Some(path) = path {
+        return Ok((path.clone(), None));
+    }
+    let tmp_dir = tempfile::Builder::new()
+        .prefix("clomonitor-linter")
+        .tempdir()?;
+    GitCLI::new()?
+        .clone_repository(url, tmp_dir.path())
+        .await
+        .context("error cloning repository")?;
+    Ok((tmp_dir.path().to_owned(), Some(tmp_dir)))
+}
+
+/// Run the check provided on the repository and explain how it decided its
+/// result.
+async fn run_explain(args: &ExplainArgs, github_token: String) -> Result<()> {
+    let (root, tmp_dir) = checkout(args.path.as_ref(), &args.url).await?;
+    let input = LinterInput {
+        project: None,
+        root,
+        path: args.subpath.clone(),
+        url: args.url.clone(),
+        check_sets: vec![],
+        checks: vec![],
+        skip_checks: vec![],
+        github_token,
+        responsiveness_slo: ResponsivenessSlo::default(),
+        check_equivalences: None,
+        preferred_chat_platforms: vec![],
+    };
+    let explanation = linter::explain(&input, &args.check_id).await?;
+    drop(tmp_dir);
+    explain::display(&explanation, &mut io::stdout())
+}
+
+/// Validate the metadata file of the repository provided, exiting with a
+/// non-zero code when any issue is found.
+fn run_metadata_validate(args: &ValidateArgs) -> Result<()> {
+    let path = args.path.join(METADATA_FILE);
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("error reading metadata file {}", path.display()))?;
+    let issues = linter::validate_metadata(&content);
+    metadata::display(METADATA_FILE, &issues, &mut io::stdout())?;
+    if !issues.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Lint the repository provided and fix the checks that did not pass, writing
+/// starter files and exemption stubs into the project's local path.
+async fn run_fix(args: &FixArgs, github_token: String) -> Result<()> {
+    let input = LinterInput {
+        project: None,
+        root: args.path.clone(),
+        path: args.subpath.clone(),
+        url: args.url.clone(),
+        check_sets: args.check_set.clone(),
+        checks: vec![],
+        skip_checks: vec![],
+        github_token,
+        responsiveness_slo: ResponsivenessSlo::default(),
+        check_equivalences: None,
+        preferred_chat_platforms: vec![],
+    };
+    let report = CoreLinter::new().lint(&input).await?;
+    let project_path = match &args.subpath {
+        Some(subpath) => args.path.join(subpath),
+        None => args.path.clone(),
+    };
+    let actions = fix::plan(&report, &project_path, args.exemption_stubs)?;
+    fix::apply(
+        &actions,
+        &project_path,
+        &args.url,
+        args.dry_run,
+        &mut io::stdout(),
+    )
+}
+
+/// Parse a check identifier provided in the command line, verifying that the
+/// check exists.
+fn parse_check_id(value: &str) -> Result<String, String> {
+    if check_weight(value).is_none() {
+        return Err(format!("unknown check {value}"));
+    }
+    Ok(value.to_string())
+}
+
+/// Parse a group of equivalent checks provided in the command line (comma
+/// separated check identifiers), verifying that all the checks exist.
+fn parse_check_equivalence(value: &str) -> Result<String, String> {
+    let check_ids: Vec<&str> = value.split(',').map(str::trim).collect();
+    if check_ids.len() < 2 {
+        return Err("at least two checks expected".to_string());
+    }
+    for check_id in &check_ids {
+        parse_check_id(check_id)?;
+    }
+    Ok(check_ids.join(","))
+}
+
+/// Lint all the repositories listed in the foundation data file provided. The
+/// Github token may contain multiple comma separated tokens, which will be
+/// used as a pool shared by the repositories linted concurrently.
+async fn run_batch(args: &Args, data_file: &Path, github_token: &str) -> Result<()> {
+    // Check the requested format is supported in batch mode
+    if !matches!(args.format, Format::Json | Format::Table) {
+        return Err(format_err!(
+            "only json and table formats are supported when using a data file"
+        ));
+    }
+
+    // Lint repositories
+    let github_tokens = github_token
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(ToString::to_string)
+        .collect();
+    let results = batch::run(args, data_file, github_tokens).await?;
+
+    // Display results using the requested format
+    if let Format::Table = args.format {
+        table::display_batch(&results, args, &mut io::stdout())?;
+    } else {
+        let output = json!({
+            "projects": results,
+        });
+        println!("{output}");
+    }
+
+    // Check if all projects passed according to the provided pass score
+    if results.iter().any(|p| !p.passed(args.pass_score)) {
         std::process::exit(1);
     }
     Ok(())
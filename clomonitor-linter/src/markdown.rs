@@ -0,0 +1,101 @@
+use std::io;
+
+use anyhow::Result;
+use askama::Template;
+use clomonitor_core::{linter::Report, report_md::RepositoryReportMDTemplate, score::Score};
+
+use crate::Args;
+
+/// Print the linter results provided in markdown format. The report is the
+/// same one CLOMonitor serves for each repository (`/report.md`).
+pub(crate) fn display(
+    report: &Report,
+    score: &Score,
+    args: &Args,
+    w: &mut impl io::Write,
+) -> Result<()> {
+    let report_md = RepositoryReportMDTemplate {
+        name: repository_name(args.url()),
+        url: args.url().to_string(),
+        check_sets: args.check_set.clone(),
+        score: Some(score.clone()),
+        report: Some(report.clone()),
+    };
+    writeln!(w, "{}", report_md.render()?)?;
+
+    Ok(())
+}
+
+/// Return the repository name from the url provided.
+pub(crate) fn repository_name(url: &str) -> String {
+    let url = url.trim_end_matches('/');
+    let name = url.rsplit('/').next().unwrap_or(url);
+    name.trim_end_matches(".git").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, str, str::FromStr};
+
+    use clomonitor_core::linter::{CheckOutput, CheckSet, Documentation};
+
+    use crate::Format;
+
+    use super::*;
+
+    #[test]
+    fn repository_name_extracted() {
+        assert_eq!(repository_name("https://github.com/org/repo"), "repo");
+        assert_eq!(repository_name("https://github.com/org/repo/"), "repo");
+        assert_eq!(repository_name("https://github.com/org/repo.git"), "repo");
+    }
+
+    #[test]
+    fn display_prints_markdown_report() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::not_passed()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let score = Score {
+            global: 50.0,
+            global_weight: 11,
+            documentation: Some(50.0),
+            documentation_weight: Some(11),
+            ..Score::default()
+        };
+        let args = Args {
+            path: Some(PathBuf::from_str("test-repo-path").unwrap()),
+            check_set: vec![CheckSet::Docs],
+            pass_score: 80.0,
+            format: Format::Markdown,
+            ..Args::default()
+        };
+
+        let mut w = Vec::new();
+        display(&report, &score, &args, &mut w).unwrap();
+
+        let output = str::from_utf8(w.as_slice()).unwrap();
+        assert!(output.starts_with(
+            "## CLOMonitor report
+
+### Summary
+
+**Repository**: test-repo
+**URL**: https://github.com/test-org/test-repo
+**Checks sets**:  `DOCS`
+**Score**: 50
+"
+        ));
+        assert!(output.contains(
+            "### Documentation [50%]
+
+  - [x] Adopters ([_docs_](https://clomonitor.io/docs/topics/checks/#adopters))
+  - [ ] Readme ([_docs_](https://clomonitor.io/docs/topics/checks/#readme))
+"
+        ));
+    }
+}
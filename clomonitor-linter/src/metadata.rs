@@ -0,0 +1,72 @@
+use std::{io, path::PathBuf};
+
+use anyhow::Result;
+use clomonitor_core::linter::MetadataIssue;
+
+/// Metadata file name.
+pub(crate) const METADATA_FILE: &str = ".clomonitor.yml";
+
+/// Arguments of the metadata validate subcommand.
+#[derive(Debug, clap::Args)]
+pub(crate) struct ValidateArgs {
+    /// Repository local path
+    #[clap(long, default_value = ".")]
+    pub path: PathBuf,
+}
+
+/// Print the issues found validating the metadata file provided.
+pub(crate) fn display(file: &str, issues: &[MetadataIssue], w: &mut impl io::Write) -> Result<()> {
+    if issues.is_empty() {
+        writeln!(w, "✓ {file} is valid")?;
+        return Ok(());
+    }
+    for issue in issues {
+        writeln!(w, "✗ {file}:{}: {}", issue.line, issue.message)?;
+    }
+    writeln!(w, "\n{} issue(s) found in {file}", issues.len())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+
+    use super::*;
+
+    #[test]
+    fn display_prints_valid_file() {
+        let mut w = Vec::new();
+        display(METADATA_FILE, &[], &mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            "✓ .clomonitor.yml is valid\n"
+        );
+    }
+
+    #[test]
+    fn display_prints_issues() {
+        let issues = vec![
+            MetadataIssue {
+                line: 4,
+                message: "unknown check unknown_check".to_string(),
+            },
+            MetadataIssue {
+                line: 9,
+                message: "unknown key comment (expected one of: check, reason)".to_string(),
+            },
+        ];
+
+        let mut w = Vec::new();
+        display(METADATA_FILE, &issues, &mut w).unwrap();
+
+        assert_eq!(
+            str::from_utf8(w.as_slice()).unwrap(),
+            "✗ .clomonitor.yml:4: unknown check unknown_check
+✗ .clomonitor.yml:9: unknown key comment (expected one of: check, reason)
+
+2 issue(s) found in .clomonitor.yml
+"
+        );
+    }
+}
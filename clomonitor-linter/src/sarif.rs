@@ -0,0 +1,267 @@
+use std::io;
+
+use anyhow::Result;
+use clomonitor_core::linter::{CheckOutput, Report, check_docs_anchor, check_weight};
+use serde_json::{Value, json};
+
+use crate::Args;
+
+/// SARIF schema location.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF version.
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Base URL of the checks documentation.
+const DOCS_URL: &str = "https://clomonitor.io/docs/topics/checks/";
+
+/// Print the linter results provided in SARIF format. Each check that did not
+/// pass (and is not exempt) is reported as a result.
+pub(crate) fn display(report: &Report, args: &Args, w: &mut impl io::Write) -> Result<()> {
+    let outputs = report.outputs();
+
+    // Rules (one per check available in the report)
+    let rules: Vec<Value> = outputs
+        .iter()
+        .map(|(check_id, _)| {
+            json!({
+                "id": check_id,
+                "name": check_id,
+                "helpUri": docs_url(check_id),
+                "defaultConfiguration": {
+                    "level": level(check_id),
+                },
+                "properties": {
+                    "weight": check_weight(check_id).unwrap_or_default(),
+                },
+            })
+        })
+        .collect();
+
+    // Results (one per check not passed)
+    let results: Vec<Value> = outputs
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, output))| !output.passed && !output.exempt)
+        .map(|(rule_index, (check_id, output))| {
+            let mut result = json!({
+                "ruleId": check_id,
+                "ruleIndex": rule_index,
+                "level": level(check_id),
+                "message": {
+                    "text": message(check_id, output),
+                },
+            });
+            if let Some(path) = repository_path(output.url.as_deref(), args.url()) {
+                result["locations"] = json!([{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": path,
+                            "uriBaseId": "%SRCROOT%",
+                        },
+                    },
+                }]);
+            }
+            result
+        })
+        .collect();
+
+    let output = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clomonitor-linter",
+                    "informationUri": "https://clomonitor.io",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+    writeln!(w, "{output}")?;
+
+    Ok(())
+}
+
+/// Return the documentation url of the check provided.
+fn docs_url(check_id: &str) -> String {
+    let anchor = check_docs_anchor(check_id).unwrap_or(check_id);
+    format!("{DOCS_URL}#{anchor}")
+}
+
+/// Return the SARIF level of the check provided, derived from its weight.
+fn level(check_id: &str) -> &'static str {
+    match check_weight(check_id).unwrap_or_default() {
+        5.. => "error",
+        2..=4 => "warning",
+        _ => "note",
+    }
+}
+
+/// Return the message used to report the check output provided.
+fn message(check_id: &str, output: &CheckOutput) -> String {
+    if output.failed {
+        return match &output.fail_reason {
+            Some(reason) => format!("Check {check_id} failed: {reason}"),
+            None => format!("Check {check_id} failed"),
+        };
+    }
+    match &output.details {
+        Some(details) => details.clone(),
+        None => format!("Check {check_id} not passed"),
+    }
+}
+
+/// Return the path of the file in the repository the url provided points to,
+/// if any.
+fn repository_path(url: Option<&str>, repository_url: &str) -> Option<String> {
+    let prefix = format!("{}/blob/", repository_url.trim_end_matches('/'));
+    let (_, path) = url?.strip_prefix(&prefix)?.split_once('/')?;
+    Some(path.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, str::FromStr};
+
+    use clomonitor_core::linter::{
+        BestPractices, CheckSet, Documentation, Security, registered_checks,
+    };
+
+    use crate::Format;
+
+    use super::*;
+
+    #[test]
+    fn repository_path_extracted() {
+        let repository_url = "https://github.com/org/repo";
+        assert_eq!(
+            repository_path(
+                Some("https://github.com/org/repo/blob/main/docs/README.md"),
+                repository_url
+            ),
+            Some("docs/README.md".to_string())
+        );
+        assert_eq!(
+            repository_path(
+                Some("https://github.com/org/other/blob/main/README.md"),
+                repository_url
+            ),
+            None
+        );
+        assert_eq!(repository_path(None, repository_url), None);
+    }
+
+    #[test]
+    fn docs_url_available_for_all_registered_checks() {
+        for check in registered_checks() {
+            let anchor = check_docs_anchor(check.id).unwrap();
+            assert!(!anchor.is_empty(), "check {} has no docs anchor", check.id);
+            assert_eq!(docs_url(check.id), format!("{DOCS_URL}#{anchor}"));
+        }
+    }
+
+    #[test]
+    fn display_prints_sarif_results() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                readme: Some(CheckOutput::not_passed().url(Some(
+                    "https://github.com/test-org/test-repo/blob/main/README.md".to_string(),
+                ))),
+                ..Default::default()
+            },
+            best_practices: BestPractices {
+                artifacthub_badge: Some(CheckOutput::exempt()),
+                dco: Some(CheckOutput::failed().fail_reason(Some("error".to_string()))),
+                ..Default::default()
+            },
+            security: Security {
+                binary_artifacts: Some(
+                    CheckOutput::not_passed().details(Some("# Details".to_string())),
+                ),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let args = Args {
+            path: Some(PathBuf::from_str("test-repo-path").unwrap()),
+            check_set: vec![CheckSet::Code, CheckSet::Community],
+            pass_score: 80.0,
+            format: Format::Sarif,
+            ..Args::default()
+        };
+
+        let mut w = Vec::new();
+        display(&report, &args, &mut w).unwrap();
+
+        let rule = |id: &str, anchor: &str, level: &str, weight: usize| {
+            json!({
+                "id": id,
+                "name": id,
+                "helpUri": format!("https://clomonitor.io/docs/topics/checks/#{anchor}"),
+                "defaultConfiguration": { "level": level },
+                "properties": { "weight": weight },
+            })
+        };
+        assert_eq!(
+            serde_json::from_slice::<Value>(&w).unwrap(),
+            json!({
+                "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+                "version": "2.1.0",
+                "runs": [{
+                    "tool": {
+                        "driver": {
+                            "name": "clomonitor-linter",
+                            "informationUri": "https://clomonitor.io",
+                            "version": env!("CARGO_PKG_VERSION"),
+                            "rules": [
+                                rule("adopters", "adopters", "note", 1),
+                                rule("readme", "readme", "error", 10),
+                                rule("artifacthub_badge", "artifact-hub-badge", "note", 1),
+                                rule("dco", "developer-certificate-of-origin", "note", 1),
+                                rule(
+                                    "binary_artifacts",
+                                    "binary-artifacts-from-openssf-scorecard",
+                                    "warning",
+                                    2
+                                ),
+                            ],
+                        },
+                    },
+                    "results": [
+                        {
+                            "ruleId": "readme",
+                            "ruleIndex": 1,
+                            "level": "error",
+                            "message": { "text": "Check readme not passed" },
+                            "locations": [{
+                                "physicalLocation": {
+                                    "artifactLocation": {
+                                        "uri": "README.md",
+                                        "uriBaseId": "%SRCROOT%",
+                                    },
+                                },
+                            }],
+                        },
+                        {
+                            "ruleId": "dco",
+                            "ruleIndex": 3,
+                            "level": "note",
+                            "message": { "text": "Check dco failed: error" },
+                        },
+                        {
+                            "ruleId": "binary_artifacts",
+                            "ruleIndex": 4,
+                            "level": "warning",
+                            "message": { "text": "# Details" },
+                        },
+                    ],
+                }],
+            })
+        );
+    }
+}
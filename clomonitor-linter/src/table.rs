@@ -2,12 +2,13 @@ use std::{fs, io};
 
 use anyhow::Result;
 use clomonitor_core::{
-    linter::{CheckOutput, Report},
-    score::Score,
+    linter::{CheckId, CheckInfo, CheckOutput, Report},
+    score::{self, Score},
 };
 use comfy_table::{Table, modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, *};
+use serde_json::Value;
 
-use crate::Args;
+use crate::{Args, batch::ProjectResults};
 
 const SUCCESS_SYMBOL: char = '✓';
 const FAILURE_SYMBOL: char = '✗';
@@ -15,6 +16,9 @@ const WARNING_SYMBOL: char = '!';
 const NOT_APPLICABLE_MSG: &str = "n/a";
 const EXEMPT_MSG: &str = "Exempt";
 
+/// Maximum number of checks displayed in the biggest wins table.
+const BIGGEST_WINS_MAX: usize = 5;
+
 /// Print the linter results provided.
 #[allow(clippy::too_many_lines)]
 pub(crate) fn display(
@@ -26,9 +30,12 @@ pub(crate) fn display(
     writeln!(w, "\nCLOMonitor linter results\n")?;
 
     // Repository information
-    let local_path = match fs::canonicalize(&args.path) {
-        Ok(cp) => cp.to_string_lossy().to_string(),
-        Err(_) => args.path.to_string_lossy().to_string(),
+    let local_path = match &args.path {
+        Some(path) => match fs::canonicalize(path) {
+            Ok(cp) => cp.to_string_lossy().to_string(),
+            Err(_) => path.to_string_lossy().to_string(),
+        },
+        None => NOT_APPLICABLE_MSG.to_string(),
     };
     writeln!(w, "Repository information\n")?;
     let mut repo_info = new_table();
@@ -36,7 +43,7 @@ pub(crate) fn display(
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
         .add_row(vec![cell_entry("Local path"), cell_entry(&local_path)])
-        .add_row(vec![cell_entry("Remote url"), cell_entry(&args.url)])
+        .add_row(vec![cell_entry("Remote url"), cell_entry(args.url())])
         .add_row(vec![
             cell_entry("Check sets"),
             cell_entry(&format!("{:?}", args.check_set)),
@@ -70,168 +77,55 @@ pub(crate) fn display(
     checks_summary
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
-        .set_header(vec![cell_header("Check"), cell_header("Passed")])
-        .add_row(vec![
-            cell_entry("Documentation / Adopters"),
-            cell_check(report.documentation.adopters.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Changelog"),
-            cell_check(report.documentation.changelog.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Code of conduct"),
-            cell_check(report.documentation.code_of_conduct.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Contributing"),
-            cell_check(report.documentation.contributing.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Governance"),
-            cell_check(report.documentation.governance.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Maintainers"),
-            cell_check(report.documentation.maintainers.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Readme"),
-            cell_check(report.documentation.readme.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Roadmap"),
-            cell_check(report.documentation.roadmap.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Summary table"),
-            cell_check(report.documentation.summary_table.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Documentation / Website"),
-            cell_check(report.documentation.website.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("License"),
-            Cell::new(match &report.license.license_spdx_id {
-                None => NOT_APPLICABLE_MSG.to_string(),
-                Some(r) => r
-                    .value
-                    .clone()
-                    .unwrap_or_else(|| "Not detected".to_string()),
-            })
-            .set_alignment(CellAlignment::Center)
-            .add_attribute(Attribute::Bold),
-        ])
-        .add_row(vec![
-            cell_entry("License / Approved"),
-            cell_check(report.license.license_approved.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("License / Scanning"),
-            cell_check(report.license.license_scanning.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / Analytics"),
-            if let Some(value) = report
-                .best_practices
-                .analytics
-                .as_ref()
-                .and_then(|analytics| analytics.value.as_ref())
-            {
-                Cell::new(value.join(" · "))
-                    .set_alignment(CellAlignment::Center)
-                    .add_attribute(Attribute::Bold)
-            } else {
-                cell_check(report.best_practices.analytics.as_ref())
-            },
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / Artifact Hub badge"),
-            cell_check(report.best_practices.artifacthub_badge.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / CLA"),
-            cell_check(report.best_practices.cla.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / Community meeting"),
-            cell_check(report.best_practices.community_meeting.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / DCO"),
-            cell_check(report.best_practices.dco.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / GitHub discussions"),
-            cell_check(report.best_practices.github_discussions.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / OpenSSF best practices badge"),
-            cell_check(report.best_practices.openssf_badge.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / OpenSSF Scorecard badge"),
-            cell_check(report.best_practices.openssf_scorecard_badge.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / Recent release"),
-            cell_check(report.best_practices.recent_release.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Best practices / Slack presence"),
-            cell_check(report.best_practices.slack_presence.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Binary artifacts"),
-            cell_check(report.security.binary_artifacts.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Code review"),
-            cell_check(report.security.code_review.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Dangerous workflow"),
-            cell_check(report.security.dangerous_workflow.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Dependencies policy"),
-            cell_check(report.security.dependencies_policy.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Dependency update tool"),
-            cell_check(report.security.dependency_update_tool.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Maintained"),
-            cell_check(report.security.maintained.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / SBOM"),
-            cell_check(report.security.sbom.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Security insights"),
-            cell_check(report.security.security_insights.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Security policy"),
-            cell_check(report.security.security_policy.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Signed release"),
-            cell_check(report.security.signed_releases.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Security / Token permissions"),
-            cell_check(report.security.token_permissions.as_ref()),
-        ])
-        .add_row(vec![
-            cell_entry("Legal / Trademark disclaimer"),
-            cell_check(report.legal.trademark_disclaimer.as_ref()),
+        .set_header(vec![cell_header("Check"), cell_header("Passed")]);
+    for check in report.checks().values() {
+        checks_summary.add_row(vec![
+            cell_entry(&format!("{} / {}", check.section.name, check.name)),
+            cell_check_value(check.output.as_ref()),
         ]);
+    }
     writeln!(w, "{checks_summary}\n")?;
 
+    // Biggest wins (checks that would increase the global score the most)
+    let explanation = score::explain(report);
+    let biggest_wins = explanation.biggest_wins();
+    if !biggest_wins.is_empty() {
+        writeln!(w, "Biggest wins\n")?;
+        let mut biggest_wins_summary = new_table();
+        biggest_wins_summary
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![
+                cell_header("Check"),
+                cell_header("Section"),
+                cell_header("Points"),
+            ]);
+        for check in biggest_wins.iter().take(BIGGEST_WINS_MAX) {
+            biggest_wins_summary.add_row(vec![
+                cell_entry(check.check_id),
+                cell_entry(check.section),
+                cell_entry(&format!("+{:.1}", check.potential_gain))
+                    .set_alignment(CellAlignment::Right),
+            ]);
+        }
+        writeln!(w, "{biggest_wins_summary}\n")?;
+    }
+
+    // Expired exemptions (they no longer apply and should be renewed or removed)
+    let expired: Vec<CheckId> = report
+        .outputs()
+        .into_iter()
+        .filter(|(_, output)| output.exemption_expired)
+        .map(|(check_id, _)| check_id)
+        .collect();
+    if !expired.is_empty() {
+        writeln!(
+            w,
+            "{WARNING_SYMBOL} Exemption expired for some checks, please renew or remove them from the metadata file: {}\n",
+            expired.join(", ")
+        )?;
+    }
+
     // Check if the linter succeeded according to the provided pass score
     if score.global() >= args.pass_score {
         writeln!(
@@ -251,6 +145,124 @@ pub(crate) fn display(
     Ok(())
 }
 
+/// Print the batch linter results provided.
+pub(crate) fn display_batch(
+    results: &[ProjectResults],
+    args: &Args,
+    w: &mut impl io::Write,
+) -> Result<()> {
+    writeln!(w, "\nCLOMonitor linter results\n")?;
+
+    // Projects summary table
+    writeln!(w, "Projects summary\n")?;
+    let mut projects_summary = new_table();
+    projects_summary
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            cell_header("Project"),
+            cell_header("Global"),
+            cell_header("Documentation"),
+            cell_header("License"),
+            cell_header("Best practices"),
+            cell_header("Security"),
+            cell_header("Legal"),
+        ]);
+    for project in results {
+        let score = project.score.as_ref();
+        projects_summary.add_row(vec![
+            cell_entry(&project.name),
+            cell_score(score.map(|s| s.global)),
+            cell_score(score.and_then(|s| s.documentation)),
+            cell_score(score.and_then(|s| s.license)),
+            cell_score(score.and_then(|s| s.best_practices)),
+            cell_score(score.and_then(|s| s.security)),
+            cell_score(score.and_then(|s| s.legal)),
+        ]);
+    }
+    writeln!(w, "{projects_summary}\n")?;
+
+    // Repositories that could not be linted
+    let errors: Vec<(&str, &str, &str)> = results
+        .iter()
+        .flat_map(|p| {
+            p.repositories.iter().filter_map(|r| {
+                r.error
+                    .as_deref()
+                    .map(|err| (p.name.as_str(), r.url.as_str(), err))
+            })
+        })
+        .collect();
+    if !errors.is_empty() {
+        writeln!(w, "Errors\n")?;
+        let mut errors_summary = new_table();
+        errors_summary
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![
+                cell_header("Project"),
+                cell_header("Repository"),
+                cell_header("Error"),
+            ]);
+        for (project, url, err) in errors {
+            errors_summary.add_row(vec![cell_entry(project), cell_entry(url), cell_entry(err)]);
+        }
+        writeln!(w, "{errors_summary}\n")?;
+    }
+
+    // Global result
+    let failed = results
+        .iter()
+        .filter(|p| !p.passed(args.pass_score))
+        .count();
+    if failed == 0 {
+        writeln!(
+            w,
+            "{SUCCESS_SYMBOL} Succeeded: all projects scored {} or higher\n",
+            args.pass_score
+        )?;
+    } else {
+        writeln!(
+            w,
+            "{FAILURE_SYMBOL} Failed: {failed} of {} projects below the pass score ({})\n",
+            results.len(),
+            args.pass_score
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Print the details of the checks provided.
+pub(crate) fn display_checks(checks: &[CheckInfo], w: &mut impl io::Write) -> Result<()> {
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            cell_header("Check"),
+            cell_header("Weight"),
+            cell_header("Check sets"),
+            cell_header("Section"),
+        ]);
+    for check in checks {
+        let check_sets: Vec<String> = check
+            .check_sets
+            .iter()
+            .map(|check_set| check_set.to_string().to_lowercase())
+            .collect();
+        table.add_row(vec![
+            cell_entry(check.id),
+            cell_entry(&check.weight.to_string()).set_alignment(CellAlignment::Right),
+            cell_entry(&check_sets.join(", ")),
+            cell_entry(check.section),
+        ]);
+    }
+    writeln!(w, "{table}")?;
+
+    Ok(())
+}
+
 /// Helper function to create a new table that will be forced to use a non-tty
 /// mode when running tests.
 #[allow(clippy::let_and_return, unused_mut)]
@@ -294,6 +306,28 @@ fn cell_score(score: Option<f64>) -> Cell {
         .fg(color)
 }
 
+/// Build a cell used for checks output, displaying the check's value instead
+/// when it's a text or a list of texts (i.e. license or analytics providers).
+fn cell_check_value(output: Option<&CheckOutput<Value>>) -> Cell {
+    let value = match output.and_then(|output| output.value.as_ref()) {
+        Some(Value::String(value)) => Some(value.clone()),
+        Some(Value::Array(values)) if values.iter().all(Value::is_string) => Some(
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(" · "),
+        ),
+        _ => None,
+    };
+    match value {
+        Some(value) => Cell::new(value)
+            .set_alignment(CellAlignment::Center)
+            .add_attribute(Attribute::Bold),
+        None => cell_check(output),
+    }
+}
+
 /// Build a cell used for checks output.
 fn cell_check<T>(output: Option<&CheckOutput<T>>) -> Cell {
     let (content, color) = match output {
@@ -317,30 +351,37 @@ mod tests {
 
     use clomonitor_core::{
         linter::{
-            BestPractices, CheckOutput, CheckSet, Documentation, Legal, License, Report, Security,
+            BestPractices, CheckOutput, CheckSet, Documentation, Legal, License,
+            REPORT_SCHEMA_VERSION, Report, Security, registered_checks,
         },
-        score::Score,
+        score::{self, Score},
     };
 
-    use crate::{Args, Format};
+    use crate::{
+        Args, Format,
+        batch::{ProjectResults, RepositoryResults},
+    };
 
-    use super::display;
+    use super::{display, display_batch, display_checks};
 
     #[test]
     fn display_prints_results() {
         // Setup test linter results
         let report = Report {
+            schema_version: REPORT_SCHEMA_VERSION,
             documentation: Documentation {
                 adopters: Some(CheckOutput::passed()),
+                broken_links: Some(CheckOutput::passed()),
                 code_of_conduct: Some(CheckOutput::passed()),
                 contributing: Some(CheckOutput::passed()),
                 changelog: Some(CheckOutput::passed()),
                 governance: Some(CheckOutput::passed()),
                 maintainers: Some(CheckOutput::passed()),
                 readme: Some(CheckOutput::passed()),
-                roadmap: Some(CheckOutput::passed()),
+                roadmap: Some(CheckOutput::passed().exemption_expired(true)),
                 summary_table: Some(CheckOutput::passed()),
                 website: Some(CheckOutput::passed()),
+                website_hygiene: Some(CheckOutput::passed()),
             },
             license: License {
                 license_approved: Some(CheckOutput::passed()),
@@ -353,12 +394,16 @@ mod tests {
                 analytics: Some(CheckOutput::passed().value(Some(vec!["GA4".to_string()]))),
                 artifacthub_badge: Some(CheckOutput::exempt()),
                 cla: Some(CheckOutput::passed()),
+                community_chat: Some(CheckOutput::passed()),
                 community_meeting: Some(CheckOutput::passed()),
+                contributor_diversity: Some(CheckOutput::passed()),
                 dco: Some(CheckOutput::passed()),
                 github_discussions: Some(CheckOutput::passed()),
+                issue_pr_templates: Some(CheckOutput::passed()),
                 openssf_badge: Some(CheckOutput::passed()),
                 openssf_scorecard_badge: Some(CheckOutput::passed()),
                 recent_release: Some(CheckOutput::passed()),
+                responsiveness: Some(CheckOutput::passed()),
                 slack_presence: Some(CheckOutput::passed()),
             },
             security: Security {
@@ -393,11 +438,11 @@ mod tests {
             legal_weight: Some(1),
         };
         let args = Args {
-            path: PathBuf::from_str("test-repo-path").unwrap(),
-            url: "https://github.com/test-org/test-repo".to_string(),
+            path: Some(PathBuf::from_str("test-repo-path").unwrap()),
             check_set: vec![CheckSet::Code, CheckSet::Community],
             pass_score: 80.0,
             format: Format::Table,
+            ..Args::default()
         };
 
         // Display linter results using a vector as output
@@ -414,4 +459,113 @@ mod tests {
         let golden = fs::read_to_string(golden_path).unwrap();
         assert_eq!(output, golden);
     }
+
+    #[test]
+    fn display_prints_biggest_wins() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::not_passed()),
+                readme: Some(CheckOutput::not_passed()),
+                ..Documentation::default()
+            },
+            legal: Legal {
+                trademark_disclaimer: Some(CheckOutput::passed()),
+            },
+            ..Report::default()
+        };
+        let score = score::calculate(&report);
+
+        let mut w = Vec::new();
+        display(&report, &score, &Args::default(), &mut w).unwrap();
+
+        let output = str::from_utf8(w.as_slice()).unwrap();
+        assert!(output.contains("Biggest wins"));
+        let readme_row = output
+            .find("│ readme   ┆ documentation ┆  +62.5 │")
+            .unwrap();
+        let adopters_row = output
+            .find("│ adopters ┆ documentation ┆   +6.2 │")
+            .unwrap();
+        assert!(readme_row < adopters_row);
+        assert!(!output.contains("│ trademark_disclaimer"));
+    }
+
+    #[test]
+    fn display_batch_prints_results() {
+        // Setup test batch linter results
+        let repository =
+            |name: &str, score: Option<Score>, error: Option<&str>| RepositoryResults {
+                name: name.to_string(),
+                url: format!("https://github.com/test-org/{name}"),
+                report: None,
+                score,
+                error: error.map(ToString::to_string),
+            };
+        let score = Score {
+            global: 85.0,
+            global_weight: 95,
+            documentation: Some(80.0),
+            documentation_weight: Some(30),
+            license: Some(100.0),
+            license_weight: Some(20),
+            best_practices: Some(75.0),
+            best_practices_weight: Some(20),
+            security: Some(90.0),
+            security_weight: Some(20),
+            legal: Some(100.0),
+            legal_weight: Some(5),
+        };
+        let results = vec![
+            ProjectResults {
+                name: "project1".to_string(),
+                score: Some(score.clone()),
+                repositories: vec![repository("repo1", Some(score), None)],
+            },
+            ProjectResults {
+                name: "project2".to_string(),
+                score: None,
+                repositories: vec![repository("repo2", None, Some("error cloning repository"))],
+            },
+        ];
+        let args = Args {
+            path: None,
+            url: None,
+            check_set: vec![CheckSet::Code, CheckSet::Community],
+            pass_score: 80.0,
+            format: Format::Table,
+            data_file: Some(PathBuf::from_str("test-data-file").unwrap()),
+            ..Args::default()
+        };
+
+        // Display batch linter results using a vector as output
+        let mut w = Vec::new();
+        display_batch(&results, &args, &mut w).unwrap();
+
+        let golden_path = "src/testdata/display_batch.golden";
+
+        // Write output to golden file (uncomment line below to update golden)
+        // fs::write(golden_path, &w).unwrap();
+
+        // Check output matches golden file content
+        let output = str::from_utf8(w.as_slice()).unwrap();
+        let golden = fs::read_to_string(golden_path).unwrap();
+        assert_eq!(output, golden);
+    }
+
+    #[test]
+    fn display_checks_prints_registered_checks() {
+        // Display registered checks using a vector as output
+        let mut w = Vec::new();
+        display_checks(&registered_checks(), &mut w).unwrap();
+
+        let golden_path = "src/testdata/display_checks.golden";
+
+        // Write output to golden file (uncomment line below to update golden)
+        // fs::write(golden_path, &w).unwrap();
+
+        // Check output matches golden file content
+        let output = str::from_utf8(w.as_slice()).unwrap();
+        let golden = fs::read_to_string(golden_path).unwrap();
+        assert_eq!(output, golden);
+    }
 }
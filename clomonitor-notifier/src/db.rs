@@ -1,30 +1,40 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use clomonitor_core::linter::Report;
 use deadpool_postgres::Pool;
 #[cfg(test)]
 use mockall::automock;
+use serde_json::Value;
+use tracing::warn;
 use uuid::Uuid;
 
-use crate::notifier::AnnualReviewNotification;
+use crate::notifier::{NotificationsSent, RepositoryReport};
 
 /// Trait that defines some operations a DB implementation must support.
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub(crate) trait DB {
-    /// Returns the pending annual review notifications.
-    async fn get_pending_annual_review_notifications(
+    /// Returns the repositories that have a report, along with it.
+    async fn get_repositories_reports(&self) -> Result<Vec<RepositoryReport>>;
+
+    /// Returns some details about the notifications of the kind provided
+    /// already sent to the given repository.
+    async fn get_notifications_sent(
         &self,
-    ) -> Result<Vec<AnnualReviewNotification>>;
+        repository_url: &str,
+        kind: &str,
+    ) -> Result<NotificationsSent>;
 
-    /// Pre-register annual review notification.
-    async fn pre_register_annual_review_notification(
+    /// Pre-register notification.
+    async fn pre_register_notification(
         &self,
         project_id: &Uuid,
         repository_url: &str,
+        kind: &str,
     ) -> Result<Uuid>;
 
-    /// Update annual review notification details.
-    async fn update_annual_review_notification(
+    /// Update notification details.
+    async fn update_notification(
         &self,
         notification_id: &Uuid,
         issue_number: Option<i64>,
@@ -49,54 +59,114 @@ impl PgDB {
 
 #[async_trait]
 impl DB for PgDB {
-    /// [DB::get_pending_annual_review_notifications]
-    async fn get_pending_annual_review_notifications(
-        &self,
-    ) -> Result<Vec<AnnualReviewNotification>> {
+    /// [DB::get_repositories_reports]
+    async fn get_repositories_reports(&self) -> Result<Vec<RepositoryReport>> {
         let db = self.pool.get().await?;
-        let pending_notifications = db
+        let repositories = db
             .query(
-                "select * from get_pending_annual_review_notifications()",
+                "
+                select
+                    r.project_id,
+                    r.url as repository_url,
+                    rp.data
+                from repository r
+                join report rp using (repository_id)
+                where rp.data is not null
+                order by r.url asc;
+                ",
                 &[],
             )
             .await?
             .iter()
-            .map(|row| AnnualReviewNotification {
-                project_id: row.get("project_id"),
-                community_repo_url: row.get("community_repo_url"),
-                issue_number: row.get("issue_number"),
+            .filter_map(|row| {
+                // Stored reports are upgraded to the current schema when
+                // deserialized (reports that cannot be loaded are skipped)
+                let repository_url: String = row.get("repository_url");
+                match Report::try_from(row.get::<_, Value>("data")) {
+                    Ok(report) => Some(RepositoryReport {
+                        project_id: row.get("project_id"),
+                        repository_url,
+                        report,
+                    }),
+                    Err(err) => {
+                        warn!(?err, ?repository_url, "error loading repository report");
+                        None
+                    }
+                }
             })
             .collect();
-        Ok(pending_notifications)
+        Ok(repositories)
+    }
+
+    /// [DB::get_notifications_sent]
+    async fn get_notifications_sent(
+        &self,
+        repository_url: &str,
+        kind: &str,
+    ) -> Result<NotificationsSent> {
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "
+                select
+                    exists (
+                        select 1
+                        from notification
+                        where repository_url = $1::text
+                        and kind = $2::text
+                        and current_timestamp - created_at < '1 month'::interval
+                    ) as recently_notified,
+                    (
+                        select issue_number
+                        from notification
+                        where repository_url = $1::text
+                        and kind = $2::text
+                        and issue_number is not null
+                        and comment_id is null
+                        and current_timestamp - created_at < '1 year'::interval
+                        order by created_at desc
+                        limit 1
+                    ) as issue_number;
+                ",
+                &[&repository_url, &kind],
+            )
+            .await?;
+        Ok(NotificationsSent {
+            recently_notified: row.get("recently_notified"),
+            issue_number: row.get("issue_number"),
+        })
     }
 
-    /// [DB::pre_register_annual_review_notification]
-    async fn pre_register_annual_review_notification(
+    /// [DB::pre_register_notification]
+    async fn pre_register_notification(
         &self,
         project_id: &Uuid,
         repository_url: &str,
+        kind: &str,
     ) -> Result<Uuid> {
         let db = self.pool.get().await?;
         let notification_id = db
             .query_one(
                 "
-                insert into annual_review_notification (
+                insert into notification (
                     project_id,
-                    repository_url
+                    repository_url,
+                    kind
                 ) values (
                     $1::uuid,
-                    $2::text
-                ) returning annual_review_notification_id;
+                    $2::text,
+                    $3::text
+                ) returning notification_id;
                 ",
-                &[&project_id, &repository_url],
+                &[&project_id, &repository_url, &kind],
             )
             .await?
-            .get("annual_review_notification_id");
+            .get("notification_id");
         Ok(notification_id)
     }
 
-    /// [DB::update_annual_review_notification]
-    async fn update_annual_review_notification(
+    /// [DB::update_notification]
+    async fn update_notification(
         &self,
         notification_id: &Uuid,
         issue_number: Option<i64>,
@@ -105,10 +175,10 @@ impl DB for PgDB {
         let db = self.pool.get().await?;
         db.execute(
             "
-            update annual_review_notification set
+            update notification set
                 issue_number = $1::bigint,
                 comment_id = $2::bigint
-            where annual_review_notification_id = $3::uuid;
+            where notification_id = $3::uuid;
             ",
             &[&issue_number, &comment_id, &notification_id],
         )
@@ -0,0 +1,122 @@
+use askama::Template;
+use clomonitor_core::linter::CheckMap;
+
+use crate::{
+    notifier::Notification,
+    tmpl::{self, ExpiredExemption},
+};
+
+/// Notification sent to the repositories with expired exemptions, prompting
+/// maintainers to renew them.
+pub(crate) struct ExemptionRenewal;
+
+impl Notification for ExemptionRenewal {
+    fn kind(&self) -> &'static str {
+        "exemption_renewal"
+    }
+
+    fn title(&self) -> &'static str {
+        "CLOMonitor exemptions expired"
+    }
+
+    fn pending(&self, checks: &CheckMap) -> bool {
+        !expired_exemptions(checks).is_empty()
+    }
+
+    fn body(&self, checks: &CheckMap, reminder: bool) -> String {
+        tmpl::ExemptionRenewal {
+            exemptions: expired_exemptions(checks),
+            reminder,
+        }
+        .render()
+        .expect("exemption renewal template to render")
+    }
+}
+
+/// Return the exemptions that have expired in the checks provided.
+fn expired_exemptions(checks: &CheckMap) -> Vec<ExpiredExemption<'_>> {
+    checks
+        .iter()
+        .filter_map(|(check_id, check)| {
+            let output = check.output.as_ref()?;
+            output.exemption_expired.then_some(ExpiredExemption {
+                check_id,
+                check_name: check.name,
+                approved_by: output.exemption_approved_by.as_deref(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use clomonitor_core::linter::{CheckOutput, Documentation, Report, Security};
+
+    use super::*;
+
+    fn report() -> Report {
+        Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                roadmap: Some(
+                    CheckOutput::not_passed()
+                        .exemption_expired(true)
+                        .exemption_approved_by(Some(
+                            "https://github.com/cncf/toc/issues/1".to_string(),
+                        )),
+                ),
+                ..Default::default()
+            },
+            security: Security {
+                sbom: Some(CheckOutput::not_passed().exemption_expired(true)),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pending_when_some_exemption_expired() {
+        assert!(ExemptionRenewal.pending(&report().checks()));
+    }
+
+    #[test]
+    fn not_pending_when_no_exemption_expired() {
+        let report = Report {
+            documentation: Documentation {
+                adopters: Some(CheckOutput::passed()),
+                roadmap: Some(CheckOutput::exempt()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(!ExemptionRenewal.pending(&report.checks()));
+    }
+
+    #[test]
+    fn body_lists_expired_exemptions() {
+        assert_eq!(
+            ExemptionRenewal.body(&report().checks(), false),
+            "Some of the exemptions declared in the [CLOMonitor metadata file](https://github.com/cncf/clomonitor/blob/main/docs/metadata/.clomonitor.yml) of this repository have expired, so they no longer apply:
+
+- Roadmap (`roadmap`), approved by https://github.com/cncf/toc/issues/1
+- SBOM (`sbom`)
+
+Please renew them (setting a new expiration date once they have been approved again) or remove them from the metadata file."
+        );
+    }
+
+    #[test]
+    fn reminder_body_lists_expired_exemptions() {
+        assert_eq!(
+            ExemptionRenewal.body(&report().checks(), true),
+            "**Reminder**: some of the exemptions declared in the CLOMonitor metadata file of this repository are still expired:
+
+- Roadmap (`roadmap`), approved by https://github.com/cncf/toc/issues/1
+- SBOM (`sbom`)
+
+Please renew them (setting a new expiration date once they have been approved again) or remove them from the metadata file."
+        );
+    }
+}
@@ -15,6 +15,7 @@ use tracing_subscriber::EnvFilter;
 use crate::{db::PgDB, github::GHApi};
 
 mod db;
+mod exemption_renewal;
 mod github;
 mod notifier;
 mod tmpl;
@@ -1,135 +1,186 @@
 use std::{sync::LazyLock, time::Duration};
 
 use anyhow::{Result, format_err};
-use askama::Template;
+use clomonitor_core::linter::{CheckMap, Report};
 use config::Config;
 use regex::Regex;
 use tokio::time::sleep;
 use tracing::{error, info, instrument};
 use uuid::Uuid;
 
-use crate::{db::DynDB, github::DynGH, tmpl};
+use crate::{db::DynDB, exemption_renewal::ExemptionRenewal, github::DynGH};
+
+/// Notifications sent to the repositories whose report requires it.
+const NOTIFICATIONS: &[&dyn Notification] = &[&ExemptionRenewal];
 
 /// Process pending notifications.
 #[instrument(skip_all, err)]
 pub(crate) async fn run(cfg: &Config, db: DynDB, gh: DynGH) -> Result<()> {
     info!("started");
 
-    process_annual_review_notifications(cfg, db, gh).await?;
+    process_notifications(cfg, &db, &gh, NOTIFICATIONS).await?;
 
     info!("finished");
     Ok(())
 }
 
-/// Title used in the issues created to notify that the annual review is due.
-const ANNUAL_REVIEW_DUE_TITLE: &str = "CNCF TOC annual review due";
+/// Notification sent to a repository when its report requires some action
+/// from the project's maintainers. Notifications are sent by creating an
+/// issue in the repository, or by posting a reminder comment in the issue
+/// created previously when it is still open.
+pub(crate) trait Notification: Send + Sync {
+    /// Kind of the notification, stored along with the notifications sent.
+    fn kind(&self) -> &'static str;
+
+    /// Title of the issue created to send the notification.
+    fn title(&self) -> &'static str;
+
+    /// Check if the repository needs to be notified, based on the checks of
+    /// its report.
+    fn pending(&self, checks: &CheckMap) -> bool;
+
+    /// Body of the issue (or of the reminder comment) used to send the
+    /// notification.
+    fn body(&self, checks: &CheckMap, reminder: bool) -> String;
+}
 
-/// Information needed to send an annual review notification.
-pub(crate) struct AnnualReviewNotification {
+/// Repository along with its report, upgraded to the current schema.
+pub(crate) struct RepositoryReport {
     pub project_id: Uuid,
-    pub community_repo_url: String,
+    pub repository_url: String,
+    pub report: Report,
+}
+
+/// Some details about the notifications of a given kind already sent to a
+/// repository.
+#[derive(Debug, Default)]
+pub(crate) struct NotificationsSent {
+    /// Whether a notification was sent during the last month.
+    pub recently_notified: bool,
+
+    /// Issue created by the last notification sent, if it was sent during
+    /// the last year.
     pub issue_number: Option<i64>,
 }
 
-/// Process annual review notifications.
+/// Process the notifications provided for all the repositories.
 #[instrument(skip_all, err)]
-async fn process_annual_review_notifications(cfg: &Config, db: DynDB, gh: DynGH) -> Result<()> {
-    match db.get_pending_annual_review_notifications().await {
-        Ok(mut notifications) => {
-            // If a list of allowed repositories is provided, filter out
-            // notifications whose repository isn't listed on it
-            if let Ok(allowed_repos) = cfg.get::<Vec<String>>("notifier.allowedRepositories") {
-                notifications.retain(|n| allowed_repos.contains(&n.community_repo_url));
-            }
+async fn process_notifications(
+    cfg: &Config,
+    db: &DynDB,
+    gh: &DynGH,
+    notifications: &[&dyn Notification],
+) -> Result<()> {
+    let mut repositories = db.get_repositories_reports().await?;
+
+    // If a list of allowed repositories is provided, filter out repositories
+    // not listed on it
+    if let Ok(allowed_repos) = cfg.get::<Vec<String>>("notifier.allowedRepositories") {
+        repositories.retain(|r| allowed_repos.contains(&r.repository_url));
+    }
 
-            // Process pending notifications
-            for (i, n) in notifications.iter().enumerate() {
-                info!(?n.project_id, ?n.community_repo_url, "processing pending annual review notification");
-
-                // Extract owner and repo from url
-                let Ok((owner, repo)) = get_owner_and_repo(&n.community_repo_url) else {
-                    continue;
-                };
-
-                // Pre-register notification in database
-                // (to avoid sending multiple notifications if registration failed after sending)
-                let notification_id = db
-                    .pre_register_annual_review_notification(&n.project_id, &n.community_repo_url)
-                    .await?;
-
-                // If the notification contains an issue number, check if it's closed
-                let mut is_issue_closed = false;
-                if let Some(issue_number) = n.issue_number {
-                    is_issue_closed = gh.is_issue_closed(&owner, &repo, issue_number).await?;
-                }
-
-                // Send notification
-                let issue_number;
-                let mut comment_id = None;
-                if let Some(existing_issue_number) = n.issue_number
-                    && !is_issue_closed
-                {
-                    // Post comment in existing issue
-                    issue_number = Some(existing_issue_number);
-                    let body = tmpl::AnnualReviewDueReminder {}.render().unwrap();
-                    match gh
-                        .create_comment(&owner, &repo, existing_issue_number, &body)
-                        .await
-                    {
-                        Ok(v) => {
-                            comment_id = Some(v);
-                            info!(
-                                ?owner,
-                                ?repo,
-                                ?issue_number,
-                                ?comment_id,
-                                "annual review due reminder notification sent"
-                            );
-                        }
-                        Err(err) => {
-                            error!(?err, ?owner, ?repo, ?issue_number, "error creating comment");
-                            continue;
-                        }
-                    }
-                } else {
-                    // Create new issue
-                    let body = tmpl::AnnualReviewDue {}.render().unwrap();
-                    match gh
-                        .create_issue(&owner, &repo, ANNUAL_REVIEW_DUE_TITLE, &body)
-                        .await
-                    {
-                        Ok(v) => {
-                            issue_number = Some(v);
-                            info!(
-                                ?owner,
-                                ?repo,
-                                ?issue_number,
-                                "annual review due notification sent"
-                            );
-                        }
-                        Err(err) => {
-                            error!(?err, ?owner, ?repo, "error creating issue");
-                            continue;
-                        }
-                    }
-                }
-
-                // Update notification details in database
-                db.update_annual_review_notification(&notification_id, issue_number, comment_id)
-                    .await?;
-
-                // If there are more notifications to process, pause before the
-                // next one to avoid hitting GitHub secondary rate limits
-                // https://docs.github.com/en/rest/guides/best-practices-for-integrators?apiVersion=2022-11-28#dealing-with-secondary-rate-limits
-                if i < notifications.len() - 1 {
-                    sleep(Duration::from_secs(10)).await;
-                }
+    let mut sent = false;
+    for r in &repositories {
+        let checks = r.report.checks();
+        for n in notifications.iter().filter(|n| n.pending(&checks)) {
+            // If a notification has already been sent, pause before the next
+            // one to avoid hitting GitHub secondary rate limits
+            // https://docs.github.com/en/rest/guides/best-practices-for-integrators?apiVersion=2022-11-28#dealing-with-secondary-rate-limits
+            if sent {
+                sleep(Duration::from_secs(10)).await;
             }
+            sent = process_notification(db, gh, r, &checks, *n).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Send the notification provided to the repository, unless it was notified
+/// recently. Returns whether the notification was sent or not.
+async fn process_notification(
+    db: &DynDB,
+    gh: &DynGH,
+    r: &RepositoryReport,
+    checks: &CheckMap,
+    n: &dyn Notification,
+) -> Result<bool> {
+    // Extract owner and repo from url
+    let Ok((owner, repo)) = get_owner_and_repo(&r.repository_url) else {
+        return Ok(false);
+    };
+
+    // Skip repositories notified recently
+    let notifications_sent = db
+        .get_notifications_sent(&r.repository_url, n.kind())
+        .await?;
+    if notifications_sent.recently_notified {
+        return Ok(false);
+    }
+    let kind = n.kind();
+    info!(?r.project_id, ?r.repository_url, kind, "processing pending notification");
+
+    // Pre-register notification in database
+    // (to avoid sending multiple notifications if registration failed after sending)
+    let notification_id = db
+        .pre_register_notification(&r.project_id, &r.repository_url, kind)
+        .await?;
+
+    // If a notification was sent recently, check if its issue is closed
+    let mut is_issue_closed = false;
+    if let Some(issue_number) = notifications_sent.issue_number {
+        is_issue_closed = gh.is_issue_closed(&owner, &repo, issue_number).await?;
+    }
 
-            Ok(())
+    // Send notification
+    let issue_number;
+    let mut comment_id = None;
+    if let Some(existing_issue_number) = notifications_sent.issue_number
+        && !is_issue_closed
+    {
+        // Post comment in existing issue
+        issue_number = Some(existing_issue_number);
+        let body = n.body(checks, true);
+        match gh
+            .create_comment(&owner, &repo, existing_issue_number, &body)
+            .await
+        {
+            Ok(v) => {
+                comment_id = Some(v);
+                info!(
+                    ?owner,
+                    ?repo,
+                    ?issue_number,
+                    ?comment_id,
+                    kind,
+                    "reminder notification sent"
+                );
+            }
+            Err(err) => {
+                error!(?err, ?owner, ?repo, ?issue_number, "error creating comment");
+                return Ok(false);
+            }
+        }
+    } else {
+        // Create new issue
+        let body = n.body(checks, false);
+        match gh.create_issue(&owner, &repo, n.title(), &body).await {
+            Ok(v) => {
+                issue_number = Some(v);
+                info!(?owner, ?repo, ?issue_number, kind, "notification sent");
+            }
+            Err(err) => {
+                error!(?err, ?owner, ?repo, "error creating issue");
+                return Ok(false);
+            }
         }
-        Err(err) => Err(err),
     }
+
+    // Update notification details in database
+    db.update_notification(&notification_id, issue_number, comment_id)
+        .await?;
+
+    Ok(true)
 }
 
 static GITHUB_REPO_URL: LazyLock<Regex> = LazyLock::new(|| {
@@ -147,6 +198,7 @@ fn get_owner_and_repo(repo_url: &str) -> Result<(String, String)> {
 
 #[cfg(test)]
 mod tests {
+    use clomonitor_core::linter::{CheckOutput, Documentation};
     use futures::future;
     use mockall::predicate::eq;
 
@@ -159,39 +211,94 @@ mod tests {
     const REPO2_URL: &str = "https://github.com/owner/repo2";
     const ISSUE_NUMBER: i64 = 1;
     const COMMENT_ID: i64 = 1234;
+    const KIND: &str = "roadmap_missing";
+    const TITLE: &str = "Roadmap missing";
 
     static PROJECT_ID: LazyLock<Uuid> =
         LazyLock::new(|| Uuid::parse_str("00000000-0001-0000-0000-000000000000").unwrap());
     static NOTIFICATION_ID: LazyLock<Uuid> =
         LazyLock::new(|| Uuid::parse_str("00000000-0001-0000-0000-000000000000").unwrap());
 
+    /// Notification sent to repositories whose roadmap check did not pass.
+    struct RoadmapMissing;
+
+    impl Notification for RoadmapMissing {
+        fn kind(&self) -> &'static str {
+            KIND
+        }
+
+        fn title(&self) -> &'static str {
+            TITLE
+        }
+
+        fn pending(&self, checks: &CheckMap) -> bool {
+            checks
+                .get("roadmap")
+                .and_then(|check| check.output.as_ref())
+                .is_some_and(|output| !output.passed && !output.exempt)
+        }
+
+        fn body(&self, _checks: &CheckMap, reminder: bool) -> String {
+            if reminder {
+                "reminder".to_string()
+            } else {
+                "body".to_string()
+            }
+        }
+    }
+
+    fn repository_report(url: &str, roadmap_passed: bool) -> RepositoryReport {
+        let roadmap = if roadmap_passed {
+            CheckOutput::passed()
+        } else {
+            CheckOutput::not_passed()
+        };
+        RepositoryReport {
+            project_id: *PROJECT_ID,
+            repository_url: url.to_string(),
+            report: Report {
+                documentation: Documentation {
+                    roadmap: Some(roadmap),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+
+    async fn process(cfg: &Config, db: MockDB, gh: MockGH) -> Result<()> {
+        let db: DynDB = Box::new(db);
+        let gh: DynGH = Box::new(gh);
+        process_notifications(cfg, &db, &gh, &[&RoadmapMissing]).await
+    }
+
     #[tokio::test]
-    async fn error_getting_pending_annual_review_notifications() {
+    async fn error_getting_repositories_reports() {
         let cfg = Config::builder().build().unwrap();
 
         let mut db = MockDB::new();
-        db.expect_get_pending_annual_review_notifications()
+        db.expect_get_repositories_reports()
             .times(1)
             .returning(|| Box::pin(future::ready(Err(format_err!(FAKE_ERROR)))));
 
         let gh = MockGH::new();
 
-        let result = run(&cfg, Box::new(db), Box::new(gh)).await;
+        let result = process(&cfg, db, gh).await;
         assert_eq!(result.unwrap_err().root_cause().to_string(), FAKE_ERROR);
     }
 
     #[tokio::test]
-    async fn no_pending_annual_review_notifications() {
+    async fn no_pending_notifications() {
         let cfg = Config::builder().build().unwrap();
 
         let mut db = MockDB::new();
-        db.expect_get_pending_annual_review_notifications()
+        db.expect_get_repositories_reports()
             .times(1)
-            .returning(|| Box::pin(future::ready(Ok(vec![]))));
+            .returning(|| Box::pin(future::ready(Ok(vec![repository_report(REPO1_URL, true)]))));
 
         let gh = MockGH::new();
 
-        run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
+        process(&cfg, db, gh).await.unwrap();
     }
 
     #[tokio::test]
@@ -203,19 +310,36 @@ mod tests {
             .unwrap();
 
         let mut db = MockDB::new();
-        db.expect_get_pending_annual_review_notifications()
+        db.expect_get_repositories_reports()
             .times(1)
-            .returning(|| {
-                Box::pin(future::ready(Ok(vec![AnnualReviewNotification {
-                    project_id: *PROJECT_ID,
-                    community_repo_url: REPO1_URL.to_string(),
-                    issue_number: None,
-                }])))
+            .returning(|| Box::pin(future::ready(Ok(vec![repository_report(REPO1_URL, false)]))));
+
+        let gh = MockGH::new();
+
+        process(&cfg, db, gh).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn skip_repo_notified_recently() {
+        let cfg = Config::builder().build().unwrap();
+
+        let mut db = MockDB::new();
+        db.expect_get_repositories_reports()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(vec![repository_report(REPO1_URL, false)]))));
+        db.expect_get_notifications_sent()
+            .with(eq(REPO1_URL), eq(KIND))
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(NotificationsSent {
+                    recently_notified: true,
+                    issue_number: Some(ISSUE_NUMBER),
+                })))
             });
 
         let gh = MockGH::new();
 
-        run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
+        process(&cfg, db, gh).await.unwrap();
     }
 
     #[tokio::test]
@@ -223,36 +347,29 @@ mod tests {
         let cfg = Config::builder().build().unwrap();
 
         let mut db = MockDB::new();
-        db.expect_get_pending_annual_review_notifications()
+        db.expect_get_repositories_reports()
             .times(1)
-            .returning(|| {
-                Box::pin(future::ready(Ok(vec![AnnualReviewNotification {
-                    project_id: *PROJECT_ID,
-                    community_repo_url: REPO1_URL.to_string(),
-                    issue_number: None,
-                }])))
-            });
-        db.expect_pre_register_annual_review_notification()
-            .with(eq(*PROJECT_ID), eq(REPO1_URL))
+            .returning(|| Box::pin(future::ready(Ok(vec![repository_report(REPO1_URL, false)]))));
+        db.expect_get_notifications_sent()
+            .with(eq(REPO1_URL), eq(KIND))
             .times(1)
-            .returning(|_, _| Box::pin(future::ready(Ok(*NOTIFICATION_ID))));
-        db.expect_update_annual_review_notification()
+            .returning(|_, _| Box::pin(future::ready(Ok(NotificationsSent::default()))));
+        db.expect_pre_register_notification()
+            .with(eq(*PROJECT_ID), eq(REPO1_URL), eq(KIND))
+            .times(1)
+            .returning(|_, _, _| Box::pin(future::ready(Ok(*NOTIFICATION_ID))));
+        db.expect_update_notification()
             .with(eq(*NOTIFICATION_ID), eq(Some(ISSUE_NUMBER)), eq(None))
             .times(1)
             .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
 
         let mut gh = MockGH::new();
         gh.expect_create_issue()
-            .with(
-                eq("owner"),
-                eq("repo1"),
-                eq(ANNUAL_REVIEW_DUE_TITLE),
-                eq(tmpl::AnnualReviewDue {}.render().unwrap()),
-            )
+            .with(eq("owner"), eq("repo1"), eq(TITLE), eq("body"))
             .times(1)
             .returning(|_, _, _, _| Box::pin(future::ready(Ok(ISSUE_NUMBER))));
 
-        run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
+        process(&cfg, db, gh).await.unwrap();
     }
 
     #[tokio::test]
@@ -260,20 +377,23 @@ mod tests {
         let cfg = Config::builder().build().unwrap();
 
         let mut db = MockDB::new();
-        db.expect_get_pending_annual_review_notifications()
+        db.expect_get_repositories_reports()
+            .times(1)
+            .returning(|| Box::pin(future::ready(Ok(vec![repository_report(REPO1_URL, false)]))));
+        db.expect_get_notifications_sent()
+            .with(eq(REPO1_URL), eq(KIND))
             .times(1)
-            .returning(|| {
-                Box::pin(future::ready(Ok(vec![AnnualReviewNotification {
-                    project_id: *PROJECT_ID,
-                    community_repo_url: REPO1_URL.to_string(),
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(NotificationsSent {
+                    recently_notified: false,
                     issue_number: Some(ISSUE_NUMBER),
-                }])))
+                })))
             });
-        db.expect_pre_register_annual_review_notification()
-            .with(eq(*PROJECT_ID), eq(REPO1_URL))
+        db.expect_pre_register_notification()
+            .with(eq(*PROJECT_ID), eq(REPO1_URL), eq(KIND))
             .times(1)
-            .returning(|_, _| Box::pin(future::ready(Ok(*NOTIFICATION_ID))));
-        db.expect_update_annual_review_notification()
+            .returning(|_, _, _| Box::pin(future::ready(Ok(*NOTIFICATION_ID))));
+        db.expect_update_notification()
             .with(eq(*NOTIFICATION_ID), eq(Some(ISSUE_NUMBER)), eq(None))
             .times(1)
             .returning(|_, _, _| Box::pin(future::ready(Ok(()))));
@@ -284,16 +404,11 @@ mod tests {
             .times(1)
             .returning(|_, _, _| Box::pin(future::ready(Ok(true))));
         gh.expect_create_issue()
-            .with(
-                eq("owner"),
-                eq("repo1"),
-                eq(ANNUAL_REVIEW_DUE_TITLE),
-                eq(tmpl::AnnualReviewDue {}.render().unwrap()),
-            )
+            .with(eq("owner"), eq("repo1"), eq(TITLE), eq("body"))
             .times(1)
             .returning(|_, _, _, _| Box::pin(future::ready(Ok(ISSUE_NUMBER))));
 
-        run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
+        process(&cfg, db, gh).await.unwrap();
     }
 
     #[tokio::test]
@@ -301,20 +416,23 @@ mod tests {
         let cfg = Config::builder().build().unwrap();
 
         let mut db = MockDB::new();
-        db.expect_get_pending_annual_review_notifications()
+        db.expect_get_repositories_reports()
             .times(1)
-            .returning(|| {
-                Box::pin(future::ready(Ok(vec![AnnualReviewNotification {
-                    project_id: *PROJECT_ID,
-                    community_repo_url: REPO1_URL.to_string(),
+            .returning(|| Box::pin(future::ready(Ok(vec![repository_report(REPO1_URL, false)]))));
+        db.expect_get_notifications_sent()
+            .with(eq(REPO1_URL), eq(KIND))
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(future::ready(Ok(NotificationsSent {
+                    recently_notified: false,
                     issue_number: Some(ISSUE_NUMBER),
-                }])))
+                })))
             });
-        db.expect_pre_register_annual_review_notification()
-            .with(eq(*PROJECT_ID), eq(REPO1_URL))
+        db.expect_pre_register_notification()
+            .with(eq(*PROJECT_ID), eq(REPO1_URL), eq(KIND))
             .times(1)
-            .returning(|_, _| Box::pin(future::ready(Ok(*NOTIFICATION_ID))));
-        db.expect_update_annual_review_notification()
+            .returning(|_, _, _| Box::pin(future::ready(Ok(*NOTIFICATION_ID))));
+        db.expect_update_notification()
             .with(
                 eq(*NOTIFICATION_ID),
                 eq(Some(ISSUE_NUMBER)),
@@ -329,15 +447,10 @@ mod tests {
             .times(1)
             .returning(|_, _, _| Box::pin(future::ready(Ok(false))));
         gh.expect_create_comment()
-            .with(
-                eq("owner"),
-                eq("repo1"),
-                eq(ISSUE_NUMBER),
-                eq(tmpl::AnnualReviewDueReminder {}.render().unwrap()),
-            )
+            .with(eq("owner"), eq("repo1"), eq(ISSUE_NUMBER), eq("reminder"))
             .times(1)
             .returning(|_, _, _, _| Box::pin(future::ready(Ok(COMMENT_ID))));
 
-        run(&cfg, Box::new(db), Box::new(gh)).await.unwrap();
+        process(&cfg, db, gh).await.unwrap();
     }
 }
@@ -1,11 +1,16 @@
 use askama::Template;
 
-/// Template for the annual review due comment.
+/// Template for the exemption renewal issue and reminder comment.
 #[derive(Template)]
-#[template(path = "annual-review-due.md")]
-pub(crate) struct AnnualReviewDue {}
+#[template(path = "exemption-renewal.md")]
+pub(crate) struct ExemptionRenewal<'a> {
+    pub exemptions: Vec<ExpiredExemption<'a>>,
+    pub reminder: bool,
+}
 
-/// Template for the annual review due reminder comment.
-#[derive(Template)]
-#[template(path = "annual-review-due-reminder.md")]
-pub(crate) struct AnnualReviewDueReminder {}
+/// Exemption that has expired.
+pub(crate) struct ExpiredExemption<'a> {
+    pub check_id: &'a str,
+    pub check_name: &'a str,
+    pub approved_by: Option<&'a str>,
+}
@@ -2,12 +2,13 @@ use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
 use async_trait::async_trait;
+use clomonitor_core::data::Project;
 use deadpool_postgres::Pool;
 #[cfg(test)]
 use mockall::automock;
 use tokio_postgres::types::Json;
 
-use crate::registrar::{Foundation, Project};
+use crate::registrar::Foundation;
 
 /// Type alias to represent a DB trait object.
 pub(crate) type DynDB = Arc<dyn DB + Send + Sync>;
@@ -1,6 +1,7 @@
 use std::{collections::HashMap, time::Duration};
 
 use anyhow::{Context, Error, Result, format_err};
+use clomonitor_core::data::Project;
 use config::Config;
 use futures::stream::{self, StreamExt};
 use reqwest::StatusCode;
@@ -21,63 +22,13 @@ pub(crate) struct Foundation {
     pub data_url: String,
 }
 
-/// Represents a project to be registered or updated.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub(crate) struct Project {
-    pub name: String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
-
-    pub description: String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub home_url: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub logo_url: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub logo_dark_url: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub devstats_url: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub accepted_at: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub maturity: Option<String>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub digest: Option<String>,
-
-    pub repositories: Vec<Repository>,
-}
-
-impl Project {
-    fn set_digest(&mut self) -> Result<()> {
-        let data = bincode::serde::encode_to_vec(&self, bincode::config::legacy())?;
-        let digest = hex::encode(Sha256::digest(data));
-        self.digest = Some(digest);
-        Ok(())
-    }
-}
-
-/// Represents a project's repository.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub(crate) struct Repository {
-    pub name: String,
-    pub url: String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub check_sets: Option<Vec<String>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub exclude: Option<Vec<String>>,
+/// Set the digest of the project provided, used to detect changes in the
+/// project's data since it was last registered.
+fn set_digest(project: &mut Project) -> Result<()> {
+    let data = bincode::serde::encode_to_vec(&*project, bincode::config::legacy())?;
+    let digest = hex::encode(Sha256::digest(data));
+    project.digest = Some(digest);
+    Ok(())
 }
 
 /// Process foundations registered in the database.
@@ -152,14 +103,9 @@ async fn process_foundation(
     let mut projects_available: HashMap<String, Project> = HashMap::with_capacity(tmp.len());
     for mut project in tmp {
         // Do not include repositories that have been excluded for this service
-        project.repositories.retain(|r| {
-            if let Some(exclude) = &r.exclude {
-                return !exclude.contains(&"clomonitor".to_string());
-            }
-            true
-        });
+        project.remove_excluded_repositories();
 
-        project.set_digest()?;
+        set_digest(&mut project)?;
         projects_available.insert(project.name.clone(), project);
     }
 
@@ -201,6 +147,7 @@ async fn process_foundation(
 
 #[cfg(test)]
 mod tests {
+    use clomonitor_core::data::Repository;
     use futures::future;
     use mockall::predicate::eq;
     use std::sync::Arc;
@@ -404,6 +351,7 @@ mod tests {
                     repositories: vec![Repository{
                         name: "artifact-hub".to_string(),
                         url: "https://github.com/artifacthub/hub".to_string(),
+                        path: None,
                         check_sets: Some(vec!["community".to_string(), "code".to_string()]),
                         exclude: None,
                     }]
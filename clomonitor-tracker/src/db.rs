@@ -4,7 +4,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use clomonitor_core::{
     linter::{CheckSet, Foundation, Project, Report},
-    score::{self, Score},
+    score::{DynScoringModel, RatingInput, Score},
 };
 use deadpool_postgres::{Pool, Transaction};
 #[cfg(test)]
@@ -33,6 +33,7 @@ pub(crate) trait DB {
         report: Option<&Report>,
         errors: Option<&String>,
         remote_digest: &str,
+        scoring_model: &DynScoringModel,
     ) -> Result<()>;
 }
 
@@ -51,6 +52,7 @@ impl DB for PgDB {
                 select
                     r.repository_id,
                     r.url,
+                    r.path,
                     r.digest,
                     to_json(r.check_sets) as check_sets,
                     r.updated_at,
@@ -74,6 +76,7 @@ impl DB for PgDB {
                 Repository {
                     repository_id: row.get("repository_id"),
                     url: row.get("url"),
+                    path: row.get("path"),
                     check_sets,
                     digest: row.get("digest"),
                     updated_at: row.get("updated_at"),
@@ -99,12 +102,13 @@ impl DB for PgDB {
         report: Option<&Report>,
         errors: Option<&String>,
         remote_digest: &str,
+        scoring_model: &DynScoringModel,
     ) -> Result<()> {
         let mut db = self.pool.get().await?;
         let tx = db.transaction().await?;
         PgDB::store_report(&tx, repository_id, check_sets, report, errors).await?;
-        PgDB::update_repository_score(&tx, repository_id, report).await?;
-        PgDB::update_project_score(&tx, repository_id).await?;
+        PgDB::update_repository_score(&tx, repository_id, report, scoring_model).await?;
+        PgDB::update_project_score(&tx, repository_id, scoring_model).await?;
         PgDB::update_repository_digest(&tx, repository_id, remote_digest).await?;
         tx.commit().await?;
         Ok(())
@@ -162,20 +166,25 @@ impl PgDB {
     }
 
     /// Update the score of the project the repository provided belongs to.
-    async fn update_project_score(tx: &Transaction<'_>, repository_id: &Uuid) -> Result<()> {
-        // Get project's id and lock project's row
-        let project_id: Uuid = tx
+    async fn update_project_score(
+        tx: &Transaction<'_>,
+        repository_id: &Uuid,
+        scoring_model: &DynScoringModel,
+    ) -> Result<()> {
+        // Get project's id and maturity and lock project's row
+        let row = tx
             .query_one(
                 "
-                select project_id from project
+                select project_id, maturity from project
                 where project_id in (
                     select project_id from repository where repository_id = $1::uuid
                 ) for update;
                 ",
                 &[&repository_id],
             )
-            .await?
-            .get("project_id");
+            .await?;
+        let project_id: Uuid = row.get("project_id");
+        let maturity: Option<String> = row.get("maturity");
 
         // Calculate project's score from the repositories' scores
         let repositories_scores: Vec<Score> = tx
@@ -196,21 +205,34 @@ impl PgDB {
             })
             .collect();
 
-        // Update project's score and rating
+        // Update project's score, rating and passed checks
         if !repositories_scores.is_empty() {
-            let project_score = score::merge(&repositories_scores[..]);
+            let project_score = scoring_model.merge(&repositories_scores[..]);
+            let passed_checks: Vec<String> = tx
+                .query_one(
+                    "select get_project_passed_checks($1::uuid) as passed_checks;",
+                    &[&project_id],
+                )
+                .await?
+                .get("passed_checks");
+            let rating_input = RatingInput {
+                maturity,
+                passed_checks,
+            };
+            let rating = scoring_model.rating(&project_score, &rating_input);
             tx.execute(
                 "
                 update project set
                     score = $1::jsonb,
                     rating = $2::text,
-                    passed_checks = (select get_project_passed_checks($3::uuid)),
+                    passed_checks = $3::text[],
                     updated_at = current_timestamp
-                where project_id = $3::uuid;
+                where project_id = $4::uuid;
                 ",
                 &[
                     &Json(&project_score),
-                    &project_score.rating().to_string(),
+                    &rating.to_string(),
+                    &rating_input.passed_checks,
                     &project_id,
                 ],
             )
@@ -234,14 +256,18 @@ impl PgDB {
         Ok(())
     }
 
-    /// Update the score of the provided repository.
+    /// Update the score of the provided repository. Repositories are not
+    /// rated: the scoring model's rating (which may depend on the project's
+    /// maturity and passed checks) is only applied to the project's score (see
+    /// `update_project_score`).
     async fn update_repository_score(
         tx: &Transaction<'_>,
         repository_id: &Uuid,
         report: Option<&Report>,
+        scoring_model: &DynScoringModel,
     ) -> Result<()> {
         if let Some(report) = report {
-            let score = score::calculate(report);
+            let score = scoring_model.calculate(report);
             tx.execute(
                 "
                 update repository set
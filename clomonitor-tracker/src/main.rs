@@ -5,7 +5,7 @@ use std::{path::PathBuf, sync::Arc};
 
 use anyhow::{Context, Result};
 use clap::Parser;
-use clomonitor_core::linter::CoreLinter;
+use clomonitor_core::{git::GitCLI, linter::CoreLinter};
 use config::{Config, File};
 use deadpool_postgres::{Config as DbConfig, Runtime};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
@@ -13,10 +13,9 @@ use postgres_openssl::MakeTlsConnector;
 use tracing::debug;
 use tracing_subscriber::EnvFilter;
 
-use crate::{db::PgDB, git::GitCLI};
+use crate::db::PgDB;
 
 mod db;
-mod git;
 mod tracker;
 
 #[derive(Debug, Parser)]
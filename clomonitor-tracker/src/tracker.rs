@@ -1,10 +1,21 @@
-use std::time::{Duration, Instant};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::{Error, Result, format_err};
 #[cfg(not(test))]
 use clomonitor_core::linter::setup_github_http_client;
-use clomonitor_core::linter::{CheckSet, DynLinter, LinterInput, Project};
-use config::Config;
+use clomonitor_core::{
+    git::DynGit,
+    linter::{
+        ChatPlatform, CheckSet, DynLinter, LinterInput, Project, ResponsivenessSlo, check_weight,
+    },
+    score::{DynScoringModel, ScoringModelConfig, WeightedAverage},
+};
+use config::{Config, ConfigError};
 use deadpool::unmanaged::{Object, Pool};
 use futures::stream::{self, StreamExt};
 #[cfg(not(test))]
@@ -15,7 +26,7 @@ use tokio::{task::JoinError, time::timeout};
 use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
-use crate::{db::DynDB, git::DynGit};
+use crate::db::DynDB;
 
 /// Maximum time that can take tracking a single repository.
 const REPOSITORY_TRACK_TIMEOUT: u64 = 600;
@@ -26,12 +37,74 @@ const REPOSITORY_TRACK_TIMEOUT: u64 = 600;
 pub(crate) struct Repository {
     pub repository_id: Uuid,
     pub url: String,
+    pub path: Option<String>,
     pub check_sets: Vec<CheckSet>,
     pub digest: Option<String>,
     pub updated_at: OffsetDateTime,
     pub project: Project,
 }
 
+/// Linter options used for all the repositories tracked.
+#[derive(Debug, Clone)]
+struct LintOptions {
+    responsiveness_slo: ResponsivenessSlo,
+    check_equivalences: HashMap<String, Vec<Vec<String>>>,
+    preferred_chat_platforms: HashMap<String, Vec<ChatPlatform>>,
+}
+
+impl LintOptions {
+    /// Load the linter options from the configuration provided, using the
+    /// default values for the ones not found.
+    fn from_config(cfg: &Config) -> Result<Self> {
+        let responsiveness_slo = match cfg.get::<ResponsivenessSlo>("tracker.responsivenessSlo") {
+            Ok(slo) => slo,
+            Err(ConfigError::NotFound(_)) => ResponsivenessSlo::default(),
+            Err(err) => return Err(err.into()),
+        };
+
+        // Checks equivalences are defined per foundation (they replace the
+        // default ones when provided)
+        let check_equivalences =
+            match cfg.get::<HashMap<String, Vec<Vec<String>>>>("tracker.checkEquivalences") {
+                Ok(check_equivalences) => check_equivalences,
+                Err(ConfigError::NotFound(_)) => HashMap::new(),
+                Err(err) => return Err(err.into()),
+            };
+
+        // Preferred chat platforms are defined per foundation
+        let preferred_chat_platforms =
+            match cfg.get::<HashMap<String, Vec<ChatPlatform>>>("tracker.preferredChatPlatforms") {
+                Ok(preferred_chat_platforms) => preferred_chat_platforms,
+                Err(ConfigError::NotFound(_)) => HashMap::new(),
+                Err(err) => return Err(err.into()),
+            };
+
+        let lint_options = Self {
+            responsiveness_slo,
+            check_equivalences,
+            preferred_chat_platforms,
+        };
+        lint_options.validate()?;
+        Ok(lint_options)
+    }
+
+    /// Verify that the checks used in the checks equivalences exist.
+    fn validate(&self) -> Result<()> {
+        for (foundation_id, groups) in &self.check_equivalences {
+            if let Some(check_id) = groups
+                .iter()
+                .flatten()
+                .find(|check_id| check_weight(check_id).is_none())
+            {
+                return Err(format_err!(
+                    "invalid check equivalence ({foundation_id}): unknown check {check_id}"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Track all repositories registered in the database.
 #[instrument(skip_all, err)]
 pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter) -> Result<()> {
@@ -46,6 +119,13 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
     }
     let gh_tokens_pool = Pool::from(gh_tokens.clone());
 
+    // Setup linter options
+    let lint_options = LintOptions::from_config(cfg)?;
+
+    // Setup scoring models
+    let scoring_models = setup_scoring_models(cfg)?;
+    let default_scoring_model: DynScoringModel = Arc::new(WeightedAverage);
+
     // Get repositories to process
     debug!("getting repositories");
     let repositories = db.repositories().await?;
@@ -63,12 +143,25 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
             let git = git.clone();
             let linter = linter.clone();
             let github_token = gh_tokens_pool.get().await.expect("token -when available-");
+            let lint_options = lint_options.clone();
+            let scoring_model = scoring_models
+                .get(&repository.project.foundation.foundation_id)
+                .unwrap_or(&default_scoring_model)
+                .clone();
             let url = repository.url.clone();
 
             tokio::spawn(async move {
                 match timeout(
                     Duration::from_secs(REPOSITORY_TRACK_TIMEOUT),
-                    track_repository(db, git, linter, github_token, repository),
+                    track_repository(
+                        db,
+                        git,
+                        linter,
+                        github_token,
+                        lint_options,
+                        scoring_model,
+                        repository,
+                    ),
                 )
                 .await
                 {
@@ -122,6 +215,19 @@ pub(crate) async fn run(cfg: &Config, db: DynDB, git: DynGit, linter: DynLinter)
     result
 }
 
+/// Setup the scoring models defined in the configuration for each foundation.
+/// Foundations without a scoring model defined will use the default one.
+fn setup_scoring_models(cfg: &Config) -> Result<HashMap<String, DynScoringModel>> {
+    match cfg.get::<HashMap<String, ScoringModelConfig>>("tracker.scoringModels") {
+        Ok(models) => Ok(models
+            .into_iter()
+            .map(|(foundation_id, model)| (foundation_id, model.build()))
+            .collect()),
+        Err(ConfigError::NotFound(_)) => Ok(HashMap::new()),
+        Err(err) => Err(err.into()),
+    }
+}
+
 /// Track repository if it has changed since the last time it was tracked.
 /// This involves cloning the repository, linting it and storing the results.
 #[instrument(fields(url = repository.url), skip_all, err)]
@@ -130,6 +236,8 @@ async fn track_repository(
     git: DynGit,
     linter: DynLinter,
     github_token: Object<String>,
+    lint_options: LintOptions,
+    scoring_model: DynScoringModel,
     repository: Repository,
 ) -> Result<()> {
     let start = Instant::now();
@@ -153,12 +261,25 @@ async fn track_repository(
 
     // Lint repository
     let mut errors: Option<String> = None;
+    let foundation_id = &repository.project.foundation.foundation_id;
+    let check_equivalences = lint_options.check_equivalences.get(foundation_id).cloned();
+    let preferred_chat_platforms = lint_options
+        .preferred_chat_platforms
+        .get(foundation_id)
+        .cloned()
+        .unwrap_or_default();
     let input = LinterInput {
         project: Some(repository.project),
         root: tmp_dir.keep(),
+        path: repository.path.as_ref().map(PathBuf::from),
         url: repository.url.clone(),
         check_sets: repository.check_sets.clone(),
+        checks: vec![],
+        skip_checks: vec![],
         github_token: github_token.to_owned(),
+        responsiveness_slo: lint_options.responsiveness_slo,
+        check_equivalences,
+        preferred_chat_platforms,
     };
     let report = match linter.lint(&input).await {
         Ok(report) => Some(report),
@@ -176,6 +297,7 @@ async fn track_repository(
         report.as_ref(),
         errors.as_ref(),
         &remote_digest,
+        &scoring_model,
     )
     .await?;
 
@@ -185,16 +307,18 @@ async fn track_repository(
 
 #[cfg(test)]
 mod tests {
-    use std::{
-        path::Path,
-        sync::{Arc, LazyLock},
-    };
+    use std::{path::Path, sync::LazyLock};
 
-    use clomonitor_core::linter::{MockLinter, Report};
+    use clomonitor_core::{
+        git::MockGit,
+        linter::{Foundation, MockLinter, Report},
+        score::{RatingInput, Score},
+    };
+    use config::{File, FileFormat};
     use futures::future;
     use predicates::prelude::{predicate::*, *};
 
-    use crate::{db::MockDB, git::MockGit};
+    use crate::db::MockDB;
 
     use super::*;
 
@@ -210,6 +334,10 @@ mod tests {
         LazyLock::new(|| Uuid::parse_str("00000000-0001-0000-0000-000000000000").unwrap());
     static REPOSITORY2_ID: LazyLock<Uuid> =
         LazyLock::new(|| Uuid::parse_str("00000000-0002-0000-0000-000000000000").unwrap());
+    static SCORE_80: LazyLock<Score> = LazyLock::new(|| Score {
+        global: 80.0,
+        ..Score::default()
+    });
 
     #[tokio::test]
     async fn error_getting_github_tokens() {
@@ -239,6 +367,32 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn unknown_check_in_check_equivalences() {
+        let cfg = Config::builder()
+            .add_source(setup_test_config(1, &[TOKEN1]))
+            .add_source(File::from_str(
+                r"
+tracker:
+  checkEquivalences:
+    foundation1:
+      - [slack_presence, unknown_check]
+",
+                FileFormat::Yaml,
+            ))
+            .build()
+            .unwrap();
+        let db = MockDB::new();
+        let git = MockGit::new();
+        let linter = MockLinter::new();
+
+        let result = run(&cfg, Arc::new(db), Arc::new(git), Arc::new(linter)).await;
+        assert_eq!(
+            result.unwrap_err().root_cause().to_string(),
+            "invalid check equivalence (foundation1): unknown check unknown_check"
+        );
+    }
+
     #[tokio::test]
     async fn error_getting_repositories() {
         let cfg = setup_test_config(1, &[TOKEN1]);
@@ -281,6 +435,7 @@ mod tests {
             Box::pin(future::ready(Ok(vec![Repository {
                 repository_id: *REPOSITORY1_ID,
                 url: REPOSITORY1_URL.to_string(),
+                path: None,
                 check_sets: vec![CheckSet::Code],
                 digest: None,
                 updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
@@ -308,6 +463,7 @@ mod tests {
             Box::pin(future::ready(Ok(vec![Repository {
                 repository_id: *REPOSITORY1_ID,
                 url: REPOSITORY1_URL.to_string(),
+                path: None,
                 check_sets: vec![CheckSet::Code],
                 digest: Some(REPOSITORY1_DIGEST.to_string()),
                 updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
@@ -335,6 +491,7 @@ mod tests {
             Box::pin(future::ready(Ok(vec![Repository {
                 repository_id: *REPOSITORY1_ID,
                 url: REPOSITORY1_URL.to_string(),
+                path: None,
                 check_sets: vec![CheckSet::Code],
                 digest: None,
                 updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
@@ -366,6 +523,7 @@ mod tests {
             Box::pin(future::ready(Ok(vec![Repository {
                 repository_id: *REPOSITORY1_ID,
                 url: REPOSITORY1_URL.to_string(),
+                path: None,
                 check_sets: vec![CheckSet::Code],
                 digest: None,
                 updated_at: OffsetDateTime::now_utc() - time::Duration::hours(6),
@@ -397,9 +555,32 @@ mod tests {
     }
 
     #[tokio::test]
+    #[allow(clippy::too_many_lines)]
     async fn two_repos_tracked_successfully() {
-        // Setup config
-        let cfg = setup_test_config(2, &[TOKEN1, TOKEN2]);
+        // Setup config (repository 2 belongs to a foundation with custom
+        // check equivalences, scoring model and preferred chat platforms)
+        let cfg = Config::builder()
+            .add_source(setup_test_config(2, &[TOKEN1, TOKEN2]))
+            .add_source(File::from_str(
+                r"
+tracker:
+  checkEquivalences:
+    foundation2:
+      - [openssf_badge, security_policy]
+  preferredChatPlatforms:
+    foundation2: [slack, mailing-list]
+  scoringModels:
+    foundation2:
+      model: policy
+      thresholds:
+        a: 90
+        b: 70
+        c: 50
+",
+                FileFormat::Yaml,
+            ))
+            .build()
+            .unwrap();
 
         // Setup mocks and expectations
         let mut db = MockDB::new();
@@ -412,6 +593,7 @@ mod tests {
                 Repository {
                     repository_id: *REPOSITORY1_ID,
                     url: REPOSITORY1_URL.to_string(),
+                    path: None,
                     check_sets: vec![CheckSet::Code],
                     digest: None,
                     updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
@@ -420,10 +602,17 @@ mod tests {
                 Repository {
                     repository_id: *REPOSITORY2_ID,
                     url: REPOSITORY2_URL.to_string(),
+                    path: Some("projects/project2".to_string()),
                     check_sets: vec![CheckSet::Code],
                     digest: None,
                     updated_at: OffsetDateTime::now_utc() - time::Duration::days(7),
-                    project: Project::default(),
+                    project: Project {
+                        foundation: Foundation {
+                            foundation_id: "foundation2".to_string(),
+                            ..Foundation::default()
+                        },
+                        ..Project::default()
+                    },
                 },
             ])))
         });
@@ -442,24 +631,33 @@ mod tests {
             .withf(move |input: &LinterInput| {
                 path::exists().and(path::is_dir()).eval(&input.root)
                     && input.url == REPOSITORY1_URL
+                    && input.path.is_none()
+                    && input.check_equivalences.is_none()
+                    && input.preferred_chat_platforms.is_empty()
                     && input.check_sets == vec![CheckSet::Code]
                     && [TOKEN1, TOKEN2].contains(&&input.github_token[..])
             })
             .times(1)
             .returning(|_: &LinterInput| Box::pin(future::ready(Ok(Report::default()))));
         db.expect_store_results()
-            .withf(|repository_id, check_sets, report, errors, digest| {
-                *repository_id == *REPOSITORY1_ID
-                    && check_sets == [CheckSet::Code]
-                    && *report == Some(&Report::default())
-                    && errors.is_none()
-                    && digest == REPOSITORY1_DIGEST
-            })
+            .withf(
+                |repository_id, check_sets, report, errors, digest, scoring_model| {
+                    *repository_id == *REPOSITORY1_ID
+                        && check_sets == [CheckSet::Code]
+                        && *report == Some(&Report::default())
+                        && errors.is_none()
+                        && digest == REPOSITORY1_DIGEST
+                        && scoring_model.rating(&SCORE_80, &RatingInput::default()) == 'a'
+                },
+            )
             .times(1)
             .returning(
-                |_: &Uuid, _: &[CheckSet], _: Option<&Report>, _: Option<&String>, _: &str| {
-                    Box::pin(future::ready(Ok(())))
-                },
+                |_: &Uuid,
+                 _: &[CheckSet],
+                 _: Option<&Report>,
+                 _: Option<&String>,
+                 _: &str,
+                 _: &DynScoringModel| Box::pin(future::ready(Ok(()))),
             );
 
         // Track repository 2
@@ -476,24 +674,38 @@ mod tests {
             .withf(move |input: &LinterInput| {
                 path::exists().and(path::is_dir()).eval(&input.root)
                     && input.url == REPOSITORY2_URL
+                    && input.path == Some(PathBuf::from("projects/project2"))
+                    && input.check_equivalences
+                        == Some(vec![vec![
+                            "openssf_badge".to_string(),
+                            "security_policy".to_string(),
+                        ]])
+                    && input.preferred_chat_platforms
+                        == vec![ChatPlatform::Slack, ChatPlatform::MailingList]
                     && input.check_sets == vec![CheckSet::Code]
                     && [TOKEN1, TOKEN2].contains(&&input.github_token[..])
             })
             .times(1)
             .returning(|_: &LinterInput| Box::pin(future::ready(Err(format_err!(FAKE_ERROR)))));
         db.expect_store_results()
-            .withf(|repository_id, check_sets, report, errors, digest| {
-                *repository_id == *REPOSITORY2_ID
-                    && check_sets == [CheckSet::Code]
-                    && report.is_none()
-                    && *errors == Some(&format!("error linting repository: {FAKE_ERROR}"))
-                    && digest == REPOSITORY2_DIGEST
-            })
+            .withf(
+                |repository_id, check_sets, report, errors, digest, scoring_model| {
+                    *repository_id == *REPOSITORY2_ID
+                        && check_sets == [CheckSet::Code]
+                        && report.is_none()
+                        && *errors == Some(&format!("error linting repository: {FAKE_ERROR}"))
+                        && digest == REPOSITORY2_DIGEST
+                        && scoring_model.rating(&SCORE_80, &RatingInput::default()) == 'b'
+                },
+            )
             .times(1)
             .returning(
-                |_: &Uuid, _: &[CheckSet], _: Option<&Report>, _: Option<&String>, _: &str| {
-                    Box::pin(future::ready(Ok(())))
-                },
+                |_: &Uuid,
+                 _: &[CheckSet],
+                 _: Option<&Report>,
+                 _: Option<&String>,
+                 _: &str,
+                 _: &DynScoringModel| Box::pin(future::ready(Ok(()))),
             );
 
         // Run tracker
@@ -10,7 +10,10 @@ begin
     loop
         select data into report from report where repository_id = repository;
 
-        for category in select jsonb_object_keys(report) loop
+        -- Only sections are considered (report's schema version is skipped)
+        for category in
+            select key from jsonb_each(report) where jsonb_typeof(value) = 'object'
+        loop
             for check_id in select jsonb_object_keys(report->category) loop
                 if report->category->check_id <> 'null' then
                     select (report->category->check_id->>'passed')::boolean into passed;
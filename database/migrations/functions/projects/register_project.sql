@@ -59,17 +59,20 @@ begin
         insert into repository (
             name,
             url,
+            path,
             check_sets,
             project_id
         ) values (
             v_repository->>'name',
             v_repository->>'url',
+            v_repository->>'path',
             v_check_sets,
             v_project_id
         )
         on conflict (project_id, url) do update
         set
             name = excluded.name,
+            path = excluded.path,
             check_sets = excluded.check_sets,
             digest = null;
     end loop;
@@ -153,6 +153,7 @@ returns json as $$
             'passing_check', json_build_object(
                 'documentation', json_build_object(
                     'adopters', repositories_passing_check(p_foundation, 'documentation', 'adopters'),
+                    'broken_links', repositories_passing_check(p_foundation, 'documentation', 'broken_links'),
                     'changelog', repositories_passing_check(p_foundation, 'documentation', 'changelog'),
                     'code_of_conduct', repositories_passing_check(p_foundation, 'documentation', 'code_of_conduct'),
                     'contributing', repositories_passing_check(p_foundation, 'documentation', 'contributing'),
@@ -161,7 +162,8 @@ returns json as $$
                     'readme', repositories_passing_check(p_foundation, 'documentation', 'readme'),
                     'roadmap', repositories_passing_check(p_foundation, 'documentation', 'roadmap'),
                     'summary_table', repositories_passing_check(p_foundation, 'documentation', 'summary_table'),
-                    'website', repositories_passing_check(p_foundation, 'documentation', 'website')
+                    'website', repositories_passing_check(p_foundation, 'documentation', 'website'),
+                    'website_hygiene', repositories_passing_check(p_foundation, 'documentation', 'website_hygiene')
                 ),
                 'license', json_build_object(
                     'license_approved', repositories_passing_check(p_foundation, 'license', 'license_approved'),
@@ -172,12 +174,16 @@ returns json as $$
                     'analytics', repositories_passing_check(p_foundation, 'best_practices', 'analytics'),
                     'artifacthub_badge', repositories_passing_check(p_foundation, 'best_practices', 'artifacthub_badge'),
                     'cla', repositories_passing_check(p_foundation, 'best_practices', 'cla'),
+                    'community_chat', repositories_passing_check(p_foundation, 'best_practices', 'community_chat'),
                     'community_meeting', repositories_passing_check(p_foundation, 'best_practices', 'community_meeting'),
+                    'contributor_diversity', repositories_passing_check(p_foundation, 'best_practices', 'contributor_diversity'),
                     'dco', repositories_passing_check(p_foundation, 'best_practices', 'dco'),
                     'github_discussions', repositories_passing_check(p_foundation, 'best_practices', 'github_discussions'),
+                    'issue_pr_templates', repositories_passing_check(p_foundation, 'best_practices', 'issue_pr_templates'),
                     'openssf_badge', repositories_passing_check(p_foundation, 'best_practices', 'openssf_badge'),
                     'openssf_scorecard_badge', repositories_passing_check(p_foundation, 'best_practices', 'openssf_scorecard_badge'),
                     'recent_release', repositories_passing_check(p_foundation, 'best_practices', 'recent_release'),
+                    'responsiveness', repositories_passing_check(p_foundation, 'best_practices', 'responsiveness'),
                     'slack_presence', repositories_passing_check(p_foundation, 'best_practices', 'slack_presence')
                 ),
                 'security', json_build_object(
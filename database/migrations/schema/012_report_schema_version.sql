@@ -0,0 +1,13 @@
+-- Upgrade stored reports to the schema version 2 (annual review check removed)
+update report set data = jsonb_set(
+    data #- '{documentation,annual_review}',
+    '{schema_version}',
+    '2'
+)
+where data is not null
+and not data ? 'schema_version';
+
+---- create above / drop below ----
+
+update report set data = data - 'schema_version'
+where data is not null;
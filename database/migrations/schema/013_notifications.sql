@@ -0,0 +1,21 @@
+-- Notifications are now derived from the repositories' reports, so the
+-- annual review one (whose check was removed) is not sent anymore. The
+-- annual review notifications already sent are kept as history.
+drop function if exists get_pending_annual_review_notifications;
+
+create table if not exists notification (
+    notification_id uuid primary key default gen_random_uuid(),
+    kind text not null,
+    repository_url text not null,
+    issue_number bigint,
+    comment_id bigint,
+    created_at timestamptz default current_timestamp not null,
+    project_id uuid references project on delete cascade
+);
+
+create index notification_project_id_idx on notification (project_id);
+create index notification_repository_url_kind_idx on notification (repository_url, kind);
+
+---- create above / drop below ----
+
+drop table if exists notification;
@@ -57,6 +57,7 @@ insert into report (
 ) values (
     '00000000-0000-0000-0001-000000000000',
     '{
+        "schema_version": 2,
         "legal": {
             "trademark_disclaimer": {
                 "passed": false
@@ -223,15 +223,23 @@ insert into report (
 -- Run some tests
 select results_eq(
     $$
-        select * from get_repositories_with_checks()
+        select
+            r->>'foundation',
+            r->>'project',
+            r->>'repository_url',
+            r->'check_sets',
+            r->'report'->'license'->'license_spdx_id'->>'value'
+        from (
+            select repository::jsonb as r
+            from get_repositories_with_checks() repository
+        ) repositories
     $$,
     $$
         values
-            ('Foundation,Project,Repository URL,Check Sets,Adopters,Changelog,Code of Conduct,Contributing,Governance,Maintainers,Readme,Roadmap,Summary Table,Website,License Approved,License Scanning,License SPDX ID,Analytics,ArtifactHub Badge,CLA,Community Meeting,DCO,GitHub discussions,OpenSSF best practices badge,OpenSSF Scorecard badge,Recent Release,Slack Presence,Binary Artifacts,Code Review,Dangerous Workflow,Dependencies Policy,Dependency Update Tool,Maintained,SBOM,Security Insights,Security Policy,Signed Releases,Token Permissions,Trademark Disclaimer'),
-            ('cncf,project1,https://repo1.url,"{code,community}",t,t,t,t,t,t,t,f,f,t,t,f,Apache-2.0,GA4,f,t,f,t,t,t,t,t,f,t,t,t,t,f,t,f,t,t,f,f,f'),
-            ('cncf,project1,https://repo2.url,{docs},,,,,,,f,,,,t,,Apache-2.0,,,,,,,,,,,,,,,,,,,,,,')
+            ('cncf', 'project1', 'https://repo1.url', '["code", "community"]'::jsonb, 'Apache-2.0'),
+            ('cncf', 'project1', 'https://repo2.url', '["docs"]'::jsonb, 'Apache-2.0')
     $$,
-    'Return all repositories with all checks'
+    'Return all repositories with their reports'
 );
 
 -- Finish tests and rollback transaction
@@ -584,6 +584,7 @@ select is(
             "passing_check": {
                 "documentation": {
                     "adopters": 67,
+                    "broken_links": 0,
                     "changelog": 67,
                     "code_of_conduct": 67,
                     "contributing": 67,
@@ -592,7 +593,8 @@ select is(
                     "readme": 67,
                     "roadmap": 0,
                     "summary_table": 0,
-                    "website": 67
+                    "website": 67,
+                    "website_hygiene": 0
                 },
                 "license": {
                     "license_approved": 67,
@@ -602,13 +604,17 @@ select is(
                 "best_practices": {
                     "artifacthub_badge": 0,
                     "cla": 67,
+                    "community_chat": 0,
                     "community_meeting": 0,
+                    "contributor_diversity": 0,
                     "dco": 67,
                     "analytics": 67,
                     "github_discussions": 67,
+                    "issue_pr_templates": 0,
                     "openssf_badge": 67,
                     "openssf_scorecard_badge": 67,
                     "recent_release": 67,
+                    "responsiveness": 0,
                     "slack_presence": 0
                 },
                 "security": {
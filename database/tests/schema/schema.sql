@@ -1,6 +1,6 @@
 -- Start transaction and plan tests
 begin;
-select plan(32);
+select plan(31);
 
 -- Check expected extension exist
 select has_extension('pgcrypto');
@@ -68,7 +68,8 @@ select columns_are('repository', array[
     'created_at',
     'updated_at',
     'check_sets',
-    'project_id'
+    'project_id',
+    'path'
 ]);
 
 -- Check tables have expected indexes
@@ -98,8 +99,6 @@ select indexes_are('repository', array[
 ]);
 
 -- Check expected functions exist
--- Notifications
-select has_function('get_pending_annual_review_notifications');
 -- Projects
 select has_function('get_project_by_id');
 select has_function('get_project_by_name');
@@ -3,7 +3,7 @@ import { Foundation } from 'clo-ui/components/Foundation';
 import { Maturity } from 'clo-ui/components/Maturity';
 import { SampleQuery } from 'clo-ui/components/SampleQueries';
 import { BiLock, BiMedal, BiShieldQuarter, BiTable, BiTrophy, BiWorld } from 'react-icons/bi';
-import { BsCalendar3, BsUiChecks } from 'react-icons/bs';
+import { BsCalendar3, BsChatDots, BsUiChecks } from 'react-icons/bs';
 import { CgFileDocument, CgReadme } from 'react-icons/cg';
 import {
   FaBalanceScale,
@@ -12,17 +12,20 @@ import {
   FaExclamationTriangle,
   FaFileContract,
   FaFileSignature,
+  FaRegClock,
   FaRobot,
   FaSignature,
   FaSlack,
   FaTools,
   FaTrademark,
+  FaUnlink,
   FaUserCog,
   FaUserSecret,
+  FaUsers,
 } from 'react-icons/fa';
 import { FiHexagon } from 'react-icons/fi';
 import { GiFountainPen, GiStamper, GiTiedScroll } from 'react-icons/gi';
-import { GoCommentDiscussion, GoFileBinary, GoLaw } from 'react-icons/go';
+import { GoCommentDiscussion, GoFileBinary, GoIssueOpened, GoLaw } from 'react-icons/go';
 import { GrDocumentLocked, GrDocumentText } from 'react-icons/gr';
 import { HiOutlinePencilAlt, HiTerminal } from 'react-icons/hi';
 import { ImOffice } from 'react-icons/im';
@@ -268,6 +271,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>List of organizations using this project in production or at stages of testing</span>,
     reference: '/docs/topics/checks/#adopters',
   },
+  [ReportOption.BrokenLinks]: {
+    icon: <FaUnlink />,
+    name: 'Broken links',
+    legend: <span>Links in the README and core community files should not be broken</span>,
+    reference: '/docs/topics/checks/#broken-links',
+  },
   [ReportOption.Analytics]: {
     icon: <FaChartBar />,
     name: 'Analytics',
@@ -330,6 +339,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>The project requires code review before pull requests (merge requests) are merged</span>,
     reference: '/docs/topics/checks/#code-review-from-openssf-scorecard',
   },
+  [ReportOption.CommunityChat]: {
+    icon: <BsChatDots />,
+    name: 'Community chat',
+    legend: <span>Projects should provide a chat channel or mailing list for their community</span>,
+    reference: '/docs/topics/checks/#community-chat',
+  },
   [ReportOption.CommunityMeeting]: {
     icon: <IoIosPeople />,
     name: 'Community meeting',
@@ -340,6 +355,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     ),
     reference: '/docs/topics/checks/#community-meeting',
   },
+  [ReportOption.ContributorDiversity]: {
+    icon: <FaUsers />,
+    name: 'Contributor diversity',
+    legend: <span>Projects should not depend on a single person or organization</span>,
+    reference: '/docs/topics/checks/#contributor-diversity',
+  },
   [ReportOption.Contributing]: {
     icon: <HiTerminal />,
     name: 'Contributing',
@@ -386,6 +407,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>Projects should enable discussions in their repositories</span>,
     reference: '/docs/topics/checks/#github-discussions',
   },
+  [ReportOption.IssuePRTemplates]: {
+    icon: <GoIssueOpened />,
+    name: 'Issue and PR templates',
+    legend: <span>Projects should provide issue and pull request templates</span>,
+    reference: '/docs/topics/checks/#issue-and-pr-templates',
+  },
   [ReportOption.Governance]: {
     icon: <GiTiedScroll />,
     name: 'Governance',
@@ -456,6 +483,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>The project should have released at least one version in the last year</span>,
     reference: '/docs/topics/checks/#recent-release',
   },
+  [ReportOption.Responsiveness]: {
+    icon: <FaRegClock />,
+    name: 'Responsiveness',
+    legend: <span>Projects should respond to issues and pull requests in a timely manner</span>,
+    reference: '/docs/topics/checks/#responsiveness',
+  },
   [ReportOption.Roadmap]: {
     icon: <RiRoadMapLine />,
     name: 'Roadmap',
@@ -551,6 +584,12 @@ export const REPORT_OPTIONS: ReportOptionInfo = {
     legend: <span>A url that users can visit to learn more about your project</span>,
     reference: '/docs/topics/checks/#website',
   },
+  [ReportOption.WebsiteHygiene]: {
+    icon: <BiWorld />,
+    name: 'Website hygiene',
+    legend: <span>Projects websites should follow some basic accessibility and hygiene practices</span>,
+    reference: '/docs/topics/checks/#website-hygiene',
+  },
 };
 
 export type FoundationInfo = {
@@ -562,6 +601,7 @@ export type FoundationInfo = {
 export const CHECKS_PER_CATEGORY: ChecksPerCategory = {
   [ScoreType.Documentation]: [
     ReportOption.Adopters,
+    ReportOption.BrokenLinks,
     ReportOption.Changelog,
     ReportOption.CodeOfConduct,
     ReportOption.Contributing,
@@ -571,18 +611,23 @@ export const CHECKS_PER_CATEGORY: ChecksPerCategory = {
     ReportOption.Roadmap,
     ReportOption.SummaryTable,
     ReportOption.Website,
+    ReportOption.WebsiteHygiene,
   ],
   [ScoreType.License]: [ReportOption.SPDX, ReportOption.ApprovedLicense, ReportOption.LicenseScanning],
   [ScoreType.BestPractices]: [
     ReportOption.Analytics,
     ReportOption.ArtifactHubBadge,
     ReportOption.CLA,
+    ReportOption.CommunityChat,
     ReportOption.CommunityMeeting,
+    ReportOption.ContributorDiversity,
     ReportOption.DCO,
     ReportOption.GithubDiscussions,
+    ReportOption.IssuePRTemplates,
     ReportOption.OpenSSFBadge,
     ReportOption.OpenSSFScorecardBadge,
     ReportOption.RecentRelease,
+    ReportOption.Responsiveness,
     ReportOption.SlackPresence,
   ],
   [ScoreType.Security]: [
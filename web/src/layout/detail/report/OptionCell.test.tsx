@@ -246,6 +246,99 @@ describe('OptionCell', () => {
       });
     });
 
+    describe('exemption approved by', () => {
+      it('displays who approved the exemption in reason tooltip', async () => {
+        vi.useFakeTimers();
+
+        render(
+          <table>
+            <tbody>
+              <OptionCell
+                label={ReportOption.Adopters}
+                check={{
+                  passed: false,
+                  exempt: true,
+                  exemption_reason: 'this is a sample reason',
+                  exemption_approved_by: 'https://github.com/cncf/toc/issues/1',
+                }}
+              />
+            </tbody>
+          </table>
+        );
+
+        const icon = screen.getByTestId('elementWithTooltip');
+        await user.hover(icon);
+
+        expect(await screen.findByRole('tooltip')).toBeInTheDocument();
+        expect(screen.getByText('Approved by:')).toBeInTheDocument();
+        expect(screen.getByText(/cncf\/toc\/issues\/1/)).toBeInTheDocument();
+
+        vi.useRealTimers();
+      });
+    });
+
+    describe('exemption expired', () => {
+      it('renders expired badge', () => {
+        render(
+          <table>
+            <tbody>
+              <OptionCell
+                label={ReportOption.Adopters}
+                check={{
+                  passed: false,
+                  exemption_expired: true,
+                }}
+              />
+            </tbody>
+          </table>
+        );
+
+        expect(screen.getByTestId('exemption-expired-badge')).toHaveTextContent('Exemption expired');
+        expect(screen.getByTestId('error-icon')).toBeInTheDocument();
+      });
+
+      it('displays who approved the expired exemption', async () => {
+        vi.useFakeTimers();
+
+        render(
+          <table>
+            <tbody>
+              <OptionCell
+                label={ReportOption.Adopters}
+                check={{
+                  passed: false,
+                  exemption_expired: true,
+                  exemption_approved_by: 'https://github.com/cncf/toc/issues/1',
+                }}
+              />
+            </tbody>
+          </table>
+        );
+
+        const badge = screen.getByTestId('elementWithTooltip');
+        await user.hover(badge);
+
+        expect(await screen.findByRole('tooltip')).toBeInTheDocument();
+        expect(screen.getByText('The exemption declared for this check has expired')).toBeInTheDocument();
+        expect(screen.getByText('Approved by:')).toBeInTheDocument();
+        expect(screen.getByText(/cncf\/toc\/issues\/1/)).toBeInTheDocument();
+
+        vi.useRealTimers();
+      });
+
+      it('is not rendered when the exemption has not expired', () => {
+        render(
+          <table>
+            <tbody>
+              <OptionCell {...defaultProps} />
+            </tbody>
+          </table>
+        );
+
+        expect(screen.queryByTestId('exemption-expired-badge')).toBeNull();
+      });
+    });
+
     describe('failed', () => {
       it('when true', () => {
         render(
@@ -202,6 +202,11 @@ const OptionCell = (props: Props) => {
                     <div className={`text-break ${styles.reason}`}>
                       <span className="fw-bold">Reason:</span> {props.check.exemption_reason}
                     </div>
+                    {props.check.exemption_approved_by && (
+                      <div className={`text-break mt-2 ${styles.reason}`}>
+                        <span className="fw-bold">Approved by:</span> {props.check.exemption_approved_by}
+                      </div>
+                    )}
                   </div>
                 }
                 alignmentTooltip="left"
@@ -300,6 +305,40 @@ const OptionCell = (props: Props) => {
                     <small className="fw-bold text-truncate">{getCheckValue()}</small>
                   </>
                 )}
+                {props.check.exemption_expired && (
+                  <ElementWithTooltip
+                    element={
+                      <span
+                        data-testid="exemption-expired-badge"
+                        className={`badge rounded-0 border border-warning text-warning ms-2 ${styles.expiredBadge}`}
+                      >
+                        Exemption expired
+                      </span>
+                    }
+                    tooltipWidth={500}
+                    tooltipClassName={styles.reasonTooltipMessage}
+                    tooltipArrowClassName={styles.reasonTooltipArrow}
+                    tooltipMessage={
+                      <div className="text-start p-2">
+                        <div className="border-bottom border-1 pb-2 mb-3 fw-bold">
+                          The exemption declared for this check has expired
+                        </div>
+                        <div className={`text-break ${styles.reason}`}>
+                          It no longer applies, please renew it or remove it from the metadata file.
+                        </div>
+                        {props.check.exemption_approved_by && (
+                          <div className={`text-break mt-2 ${styles.reason}`}>
+                            <span className="fw-bold">Approved by:</span> {props.check.exemption_approved_by}
+                          </div>
+                        )}
+                      </div>
+                    }
+                    alignmentTooltip="left"
+                    forceAlignment
+                    visibleTooltip
+                    active
+                  />
+                )}
               </div>
               <div className={`d-none d-md-flex flex-row text-muted w-100 ${styles.legend}`}>
                 <div className="text-truncate">{opt.legend}</div>
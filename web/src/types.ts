@@ -63,6 +63,8 @@ export interface ReportCheck {
   passed: boolean;
   exempt?: boolean;
   exemption_reason?: string;
+  exemption_expired?: boolean;
+  exemption_approved_by?: string;
   failed?: boolean;
   fail_reason?: string;
   value?: string | string[];
@@ -146,6 +148,7 @@ export enum SortBy {
 
 export enum ReportOption {
   Adopters = 'adopters',
+  BrokenLinks = 'broken_links',
   Analytics = 'analytics',
   ApprovedLicense = 'license_approved',
   ArtifactHubBadge = 'artifacthub_badge',
@@ -154,13 +157,16 @@ export enum ReportOption {
   CLA = 'cla',
   CodeOfConduct = 'code_of_conduct',
   CodeReview = 'code_review',
+  CommunityChat = 'community_chat',
   CommunityMeeting = 'community_meeting',
+  ContributorDiversity = 'contributor_diversity',
   Contributing = 'contributing',
   DangerousWorkflow = 'dangerous_workflow',
   DependenciesPolicy = 'dependencies_policy',
   DependencyUpdateTool = 'dependency_update_tool',
   DCO = 'dco',
   GithubDiscussions = 'github_discussions',
+  IssuePRTemplates = 'issue_pr_templates',
   Governance = 'governance',
   LicenseScanning = 'license_scanning',
   Maintained = 'maintained',
@@ -169,6 +175,7 @@ export enum ReportOption {
   OpenSSFScorecardBadge = 'openssf_scorecard_badge',
   Readme = 'readme',
   RecentRelease = 'recent_release',
+  Responsiveness = 'responsiveness',
   Roadmap = 'roadmap',
   SBOM = 'sbom',
   SecurityInsights = 'security_insights',
@@ -180,6 +187,7 @@ export enum ReportOption {
   TokenPermissions = 'token_permissions',
   TrademarkDisclaimer = 'trademark_disclaimer',
   Website = 'website',
+  WebsiteHygiene = 'website_hygiene',
 }
 
 export interface SearchFiltersURL extends BasicQuery {